@@ -5,26 +5,99 @@ use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
-/// Return a list of all .proto files in the given directory, recursively.
-fn get_protobuf_paths<P: AsRef<Path>>(directory: P) -> std::io::Result<Vec<PathBuf>> {
+/// Return a list of all .proto files in the given directory, recursively,
+/// skipping those whose file stem matches any of `exclude_patterns`.
+fn get_protobuf_paths<P: AsRef<Path>>(
+    directory: P,
+    exclude_patterns: &[glob::Pattern],
+) -> std::io::Result<Vec<PathBuf>> {
     let mut paths: Vec<PathBuf> = vec![];
     for entry in WalkDir::new(directory) {
         let path = entry?.into_path();
-        if path.extension() == Some(OsStr::new("proto")) {
-            paths.push(path.to_path_buf());
+        if path.extension() != Some(OsStr::new("proto")) {
+            continue;
         }
+        let stem = path.file_stem().and_then(OsStr::to_str).unwrap_or_default();
+        if exclude_patterns.iter().any(|pattern| pattern.matches(stem)) {
+            println!("cargo:warning=skipping excluded proto {}", path.display());
+            continue;
+        }
+        paths.push(path.to_path_buf());
     }
     Ok(paths)
 }
 
+/// Generated-code patterns the pyo3 bindings do not handle, with the reason reported.
+const UNSUPPORTED_PATTERNS: &[(&str, &str)] = &[
+    (
+        "::prost_types::Any",
+        "google.protobuf.Any fields have no Python binding",
+    ),
+    (
+        "::prost_types::",
+        "well-known protobuf types are not pyclasses and cannot be exposed to Python",
+    ),
+    (
+        "::prost::alloc::boxed::Box<",
+        "boxed (recursive) message fields cannot be used with get_all/set_all",
+    ),
+];
+
+/// Emit a `cargo:warning` for every line of the prost-generated files in `dir` matching an
+/// [`UNSUPPORTED_PATTERNS`] entry. Only reports; the build continues.
+fn warn_unsupported_patterns(dir: &Path) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension() != Some(OsStr::new("rs")) {
+            continue;
+        }
+        let source = std::fs::read_to_string(&path)?;
+        if !source.starts_with("// This file is @generated by prost-build.") {
+            continue;
+        }
+        for (number, line) in source.lines().enumerate() {
+            // 只报告每行第一个匹配的模式（`::prost_types::Any` 也匹配 `::prost_types::`）
+            if let Some((_, reason)) = UNSUPPORTED_PATTERNS
+                .iter()
+                .find(|(pattern, _)| line.contains(pattern))
+            {
+                println!(
+                    "cargo:warning={}:{}: {}",
+                    path.display(),
+                    number + 1,
+                    reason
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Fingerprint of the proto files: 64-bit FNV-1a over their names and contents, in path order.
+///
+/// Stable across toolchains, unlike `DefaultHasher`.
+fn proto_fingerprint(paths: &[PathBuf]) -> Result<String> {
+    let mut paths = paths.to_vec();
+    paths.sort();
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for path in &paths {
+        let name = path.file_name().and_then(OsStr::to_str).unwrap_or_default();
+        let contents = std::fs::read(path)?;
+        for byte in name.bytes().chain(contents) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    Ok(format!("{:016x}", hash))
+}
+
 fn main() -> Result<()> {
     let protoc_bin = match protoc_prebuilt::init("33.0") {
         Ok(a) => a.0,
         Err(e) => {
             println!("cargo:msg={}", e);
             let out_dir = env::var("OUT_DIR").unwrap();
-            let protoc_path = protoc_fetcher::protoc("33.0", Path::new(&out_dir)).unwrap();
-            protoc_path
+            protoc_fetcher::protoc("33.0", Path::new(&out_dir)).unwrap()
         }
     };
     unsafe { env::set_var("PROTOC", protoc_bin) };
@@ -36,7 +109,19 @@ fn main() -> Result<()> {
 
     println!("cargo:rerun-if-changed={}", &pb_dir.display());
 
-    let protobuf_paths = get_protobuf_paths(&pb_dir).context(format!(
+    // Comma-separated glob patterns of proto file stems to skip, e.g. `health,vendor_*`.
+    println!("cargo:rerun-if-env-changed=TRITON_PROTOBUF_EXCLUDE");
+    let exclude = env::var("TRITON_PROTOBUF_EXCLUDE").unwrap_or_default();
+    let exclude_patterns = exclude
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            glob::Pattern::new(p).context(format!("invalid TRITON_PROTOBUF_EXCLUDE pattern {}", p))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let protobuf_paths = get_protobuf_paths(&pb_dir, &exclude_patterns).context(format!(
         "failed to find Protocol Buffers paths for {}",
         pb_dir.display()
     ))?;
@@ -45,16 +130,112 @@ fn main() -> Result<()> {
         println!("cargo:rerun-if-changed={}", path.display());
     }
 
-    tonic_prost_build::configure()
+    // Schema version reported by `triton_proto_version()`: `TRITON_PROTO_VERSION` when set,
+    // e.g. the Triton release the protos come from, otherwise a fingerprint of the files.
+    println!("cargo:rerun-if-env-changed=TRITON_PROTO_VERSION");
+    let proto_version = match env::var("TRITON_PROTO_VERSION") {
+        Ok(version) if !version.trim().is_empty() => version.trim().to_string(),
+        _ => proto_fingerprint(&protobuf_paths).context("failed to read Protocol Buffers")?,
+    };
+    println!("cargo:rustc-env=TRITON_PROTO_VERSION={}", proto_version);
+
+    let mut config = tonic_prost_build::configure();
+    // 为常在发送前复制的可变类型添加 copy / deepcopy 支持
+    // （路径按前缀匹配，ModelInferRequest 已包含其嵌套的 InferInputTensor 等类型）
+    for path in [
+        ".inference.ModelInferRequest",
+        ".inference.InferTensorContents",
+        ".inference.InferParameter",
+        ".inference.ModelConfig",
+    ] {
+        config = config.type_attribute(path, "#[derive(::triton_client_macros::ImplPyCopy)]");
+    }
+    // 不可变的响应类型可哈希，可变的请求类型显式不可哈希
+    for (path, mode) in [
+        (".inference.ServerLiveResponse", "immutable"),
+        (".inference.ServerReadyResponse", "immutable"),
+        (".inference.ModelReadyResponse", "immutable"),
+        (".inference.ServerMetadataResponse", "immutable"),
+        (".inference.ModelInferRequest", "mutable"),
+    ] {
+        config = config.type_attribute(
+            path,
+            format!(
+                "#[derive(::triton_client_macros::ImplPyHash)]\n#[hash = \"{}\"]",
+                mode
+            ),
+        );
+    }
+
+    // InferTensorContents 手写了基于 numpy 的 `replace_*_contents`，不生成同名的 snake_case 方法
+    for field in [
+        "bool_contents",
+        "int_contents",
+        "int64_contents",
+        "uint_contents",
+        "uint64_contents",
+        "fp32_contents",
+        "fp64_contents",
+    ] {
+        config = config.field_attribute(
+            format!(".inference.InferTensorContents.{}", field),
+            "#[pyclass_accessors(skip = \"replace\")]",
+        );
+    }
+
+    config
         .build_server(true)
+        // gRPC 客户端与服务端代码仅在 `client` feature 下编译，消息类型始终可用
+        .client_mod_attribute(".", "#[cfg(feature = \"client\")]")
+        .server_mod_attribute(".", "#[cfg(feature = \"client\")]")
         .out_dir("src")
         // 为所有类型添加 pyclass 属性
-        .type_attribute(".", "#[::pyo3::pyclass(get_all, set_all, module = \"triton_client\")]")
+        .type_attribute(
+            ".",
+            "#[::pyo3::pyclass(get_all, set_all, module = \"triton_client\")]",
+        )
         // 为所有类型添加我们的宏（实现带可选参数的构造函数）
         .type_attribute(".", "#[derive(::triton_client_macros::ImplPyNew)]")
         .type_attribute(".", "#[derive(::triton_client_macros::ImplPyVecAccessors)]")
+        .type_attribute(".", "#[derive(::triton_client_macros::ImplPyProtoBytes)]")
+        // 消息类型的 Vec 字段使用 snake_case 访问器，旧的 PascalCase 名称保留为弃用别名
+        .message_attribute(".", "#[pyclass_accessors(naming = \"snake_case\")]")
+        // JSON (de)serialisation; bytes fields are base64-encoded
+        .message_attribute(
+            ".",
+            "#[derive(::serde::Serialize, ::serde::Deserialize)]\n#[serde(default)]",
+        )
+        .enum_attribute(".", "#[derive(::serde::Serialize, ::serde::Deserialize)]")
+        .field_attribute(
+            ".inference.InferTensorContents.bytes_contents",
+            "#[serde(with = \"crate::json::base64_list\")]",
+        )
+        .field_attribute(
+            ".inference.ModelInferRequest.raw_input_contents",
+            "#[serde(with = \"crate::json::base64_list\")]",
+        )
+        .field_attribute(
+            ".inference.ModelInferResponse.raw_output_contents",
+            "#[serde(with = \"crate::json::base64_list\")]",
+        )
+        .field_attribute(
+            ".inference.ModelRepositoryParameter.parameter_choice.bytes_param",
+            "#[serde(with = \"crate::json::base64_bytes\")]",
+        )
+        .field_attribute(
+            ".inference.CudaSharedMemoryRegisterRequest.raw_handle",
+            "#[serde(with = \"crate::json::base64_bytes\")]",
+        )
+        // `settings` is exposed as a dict of `list[str]` by a getter in py_types.rs
+        .field_attribute(
+            ".inference.TraceSettingResponse.settings",
+            "#[pyo3(name = \"setting_values\")]",
+        )
         .compile_protos(&protobuf_paths, &[pb_dir])
         .context("unable to compile Protocol Buffers for the Triton client")?;
 
+    warn_unsupported_patterns(Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src")))
+        .context("failed to inspect generated sources")?;
+
     Ok(())
 }
@@ -140,40 +140,40 @@ class InferInput:
         # 根据数据类型设置内容
         if dtype == np.bool_:
             data = triton_client.ListBool.from_array(flat_data)
-            contents.Replace_bool_contents(data)
+            contents.set_bool_contents(data)
         elif dtype == np.int8:
             data = triton_client.ListI32.from_array(flat_data.astype(np.int32))
-            contents.Replace_int_contents(data)
+            contents.set_int_contents(data)
         elif dtype == np.int16:
             data = triton_client.ListI32.from_array(flat_data.astype(np.int32))
-            contents.Replace_int_contents(data)
+            contents.set_int_contents(data)
         elif dtype == np.int32:
             data = triton_client.ListI32.from_array(flat_data)
-            contents.Replace_int_contents(data)
+            contents.set_int_contents(data)
         elif dtype == np.int64:
             data = triton_client.ListI64.from_array(flat_data)
-            contents.Replace_int64_contents(data)
+            contents.set_int64_contents(data)
         elif dtype == np.uint8:
             data = triton_client.ListU32.from_array(flat_data.astype(np.uint32))
-            contents.Replace_uint_contents(data)
+            contents.set_uint_contents(data)
         elif dtype == np.uint16:
             data = triton_client.ListU32.from_array(flat_data.astype(np.uint32))
-            contents.Replace_uint_contents(data)
+            contents.set_uint_contents(data)
         elif dtype == np.uint32:
             data = triton_client.ListU32.from_array(flat_data)
-            contents.Replace_uint_contents(data)
+            contents.set_uint_contents(data)
         elif dtype == np.uint64:
             data = triton_client.ListU64.from_array(flat_data)
-            contents.Replace_uint64_contents(data)
+            contents.set_uint64_contents(data)
         elif dtype == np.float16:
             data = triton_client.ListF32.from_array(flat_data.astype(np.float32))
-            contents.Replace_fp32_contents(data)
+            contents.set_fp32_contents(data)
         elif dtype == np.float32:
             data = triton_client.ListF32.from_array(flat_data)
-            contents.Replace_fp32_contents(data)
+            contents.set_fp32_contents(data)
         elif dtype == np.float64:
             data = triton_client.ListF64.from_array(flat_data)
-            contents.Replace_fp64_contents(data)
+            contents.set_fp64_contents(data)
         else:
             raise ValueError(f"Unsupported data type: {self._datatype}")
 
@@ -350,24 +350,24 @@ class InferOutput:
         """将 InferTensorContents 转换为 numpy 数组."""
         dtype = triton_to_np_dtype(datatype)
         if dtype == np.bool_:
-            vec = contents.Take_bool_contents()
+            vec = contents.take_bool_contents()
         elif dtype == np.int32:
-            vec = contents.Take_int_contents()
+            vec = contents.take_int_contents()
         elif dtype == np.int64:
-            vec = contents.Take_int64_contents()
+            vec = contents.take_int64_contents()
         elif dtype == np.uint32:
-            vec = contents.Take_uint_contents()
+            vec = contents.take_uint_contents()
         elif dtype == np.uint64:
-            vec = contents.Take_uint64_contents()
+            vec = contents.take_uint64_contents()
         elif dtype == np.float32:
-            vec = contents.Take_fp32_contents()
+            vec = contents.take_fp32_contents()
         elif dtype == np.float64:
-            vec = contents.Take_fp64_contents()
+            vec = contents.take_fp64_contents()
         else:
             # 对于其他类型，尝试使用 int32
-            vec = contents.Take_int_contents()
+            vec = contents.take_int_contents()
         vec: triton_client.ListF32
-        return vec.into_array().reshape(shape)
+        return vec.move_to_array().reshape(shape)
 
 
 class InferResult:
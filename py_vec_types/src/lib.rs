@@ -1,9 +1,32 @@
 //! Python-bound Vec type wrappers provide List<T> types for easier manipulation of Vec fields in Python.
 
-use numpy::{PyArray1, PyArrayMethods, PyReadonlyArray1};
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use numpy::{PyArray1, PyArray2, PyArrayMethods, PyReadonlyArray1, PyReadonlyArray2};
 use pyo3::prelude::*;
-use pyo3::types::PyList;
+use pyo3::types::{PyFrozenSet, PyIterator, PyList, PySet, PySlice, PyTuple};
+use std::ffi::CStr;
 use std::ops::{Deref, DerefMut};
+use std::os::raw::c_int;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Position of the Python index `index` in a list of length `len`, counting negative indices
+/// from the end.
+///
+/// Accepts any object implementing `__index__`, e.g. `numpy.intp`; raises `IndexError` when
+/// out of range.
+fn resolve_index(index: &Bound<'_, PyAny>, len: usize) -> PyResult<usize> {
+    let index: isize = index.extract()?;
+    let resolved = if index < 0 {
+        index.checked_add_unsigned(len)
+    } else {
+        Some(index)
+    };
+    match resolved {
+        Some(i) if i >= 0 && (i as usize) < len => Ok(i as usize),
+        _ => Err(pyo3::exceptions::PyIndexError::new_err("Index out of range")),
+    }
+}
 
 /// A mutable list type used to set Vec fields.
 ///
@@ -11,22 +34,46 @@ use std::ops::{Deref, DerefMut};
 ///
 /// Note: Since pyo3 does not support generic pyclass, we have created concrete types for each type.
 ///
+/// Python buffer protocol exports are 1-D and read-only, using the `struct` format `$fmt`;
+/// in-place methods such as `sort` or `__setitem__` stay allowed and are visible through them.
+/// While a buffer (e.g. a `memoryview` or `np.frombuffer` view) is alive, methods that may
+/// reallocate raise `BufferError`, like `bytearray`.
 macro_rules! define_list_type {
-    ($name:ident, $t:ty, $py_array:ty, $py_readonly:ty) => {
-        #[pyclass(module="triton_client")]
-        #[derive(Debug, Clone)]
+    ($name:ident, $t:ty, $py_array:ty, $py_readonly:ty, $fmt:literal) => {
+        // 自定义 FromPyObject，替代 pyo3 为 Clone 类型生成的实现
+        #[pyclass(module="triton_client", skip_from_py_object)]
+        #[derive(Debug)]
         pub struct $name {
             inner: Vec<$t>,
+            /// 当前导出的 buffer 数量
+            exports: AtomicUsize,
+        }
+
+        impl Clone for $name {
+            fn clone(&self) -> Self {
+                Self::new(self.inner.clone())
+            }
         }
 
         impl $name {
             pub fn new(inner: Vec<$t>) -> Self {
-                Self { inner }
+                Self { inner, exports: AtomicUsize::new(0) }
             }
 
             pub fn into_vec(self) -> Vec<$t> {
                 self.inner
             }
+
+            /// Fail if a buffer export would be invalidated by reallocating `inner`.
+            fn check_resizable(&self) -> PyResult<()> {
+                if self.exports.load(Ordering::Acquire) > 0 {
+                    Err(pyo3::exceptions::PyBufferError::new_err(
+                        "cannot resize a list with exported buffers",
+                    ))
+                } else {
+                    Ok(())
+                }
+            }
         }
 
         impl Deref for $name {
@@ -49,32 +96,71 @@ macro_rules! define_list_type {
             }
         }
 
+        /// Copies the array, which may be non-contiguous.
+        impl<'py> From<PyReadonlyArray1<'py, $t>> for $name {
+            fn from(arr: PyReadonlyArray1<'py, $t>) -> Self {
+                Self::new(arr.as_array().iter().copied().collect())
+            }
+        }
+
+        /// Extracts a list of this type (copied), a 1-D numpy array of the item type or any
+        /// sequence of items, so pymethods can take either a list or an array.
+        impl<'a, 'py> FromPyObject<'a, 'py> for $name {
+            type Error = PyErr;
+
+            fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+                if let Ok(list) = obj.cast::<Self>() {
+                    return Ok(list.try_borrow()?.clone());
+                }
+                if let Ok(arr) = obj.extract::<PyReadonlyArray1<'py, $t>>() {
+                    return Ok(arr.into());
+                }
+                Ok(Self::new(obj.extract()?))
+            }
+        }
+
         #[pymethods]
         impl $name {
             /// from Python list construct
             #[new]
             fn new_py(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
-                if let Ok(list) = obj.cast::<PyList>() {
+                if let Ok(other) = obj.cast::<Self>() {
+                    // 同类型直接克隆内部 Vec，不经过 Python list
+                    Ok(Self::new(other.borrow().inner.clone()))
+                } else if let Ok(list) = obj.cast::<PyList>() {
                     let vec: Vec<$t> = list.extract()?;
-                    Ok(Self { inner: vec })
+                    Ok(Self::new(vec))
                 } else {
                     // 尝试直接提取
                     let vec: Vec<$t> = obj.extract()?;
-                    Ok(Self { inner: vec })
+                    Ok(Self::new(vec))
                 }
             }
 
             /// from numpy array construct
             #[staticmethod]
             fn from_array(arr: $py_readonly) -> PyResult<Self> {
-                Ok(Self {
-                    inner: arr.to_vec()?,
-                })
+                Ok(Self::new(arr.to_vec()?))
+            }
+
+            /// `length` copies of `value`, e.g. zeros or ones, without a Python list in between.
+            #[staticmethod]
+            fn from_scalar(value: $t, length: usize) -> PyResult<Self> {
+                // Vec 的容量上限为 isize::MAX 字节，超出时 vec! 会 panic
+                if length > isize::MAX as usize / std::mem::size_of::<$t>() {
+                    return Err(pyo3::exceptions::PyOverflowError::new_err(format!(
+                        "length {} is too large",
+                        length
+                    )));
+                }
+                Ok(Self::new(vec![value; length]))
             }
 
             /// push item
-            fn append(&mut self, item: $t) {
+            fn append(&mut self, item: $t) -> PyResult<()> {
+                self.check_resizable()?;
                 self.inner.push(item);
+                Ok(())
             }
 
             /// len
@@ -82,45 +168,107 @@ macro_rules! define_list_type {
                 self.inner.len()
             }
 
-            fn __getitem__(&self, index: usize) -> PyResult<$t> {
-                self.inner
-                    .get(index)
-                    .copied()
-                    .ok_or_else(|| pyo3::exceptions::PyIndexError::new_err("Index out of range"))
+            /// Empty lists are falsy, like `list`.
+            fn __bool__(&self) -> bool {
+                !self.inner.is_empty()
             }
 
-            fn __setitem__(&mut self, index: usize, value: $t) -> PyResult<()> {
-                if let Some(elem) = self.inner.get_mut(index) {
-                    *elem = value;
-                    Ok(())
-                } else {
-                    Err(pyo3::exceptions::PyIndexError::new_err(
-                        "Index out of range",
-                    ))
+            fn __getitem__(&self, index: &Bound<'_, PyAny>) -> PyResult<$t> {
+                Ok(self.inner[resolve_index(index, self.inner.len())?])
+            }
+
+            /// Set an item, or splice a slice like `list`; the replacement may be any sequence,
+            /// including another list of this type, and may change the length for simple slices.
+            fn __setitem__(&mut self, key: &Bound<'_, PyAny>, value: &Bound<'_, PyAny>) -> PyResult<()> {
+                if let Ok(slice) = key.cast::<PySlice>() {
+                    let values: Vec<$t> = match value.cast::<Self>() {
+                        // 借用失败说明右侧就是 self，如 `lst[1:3] = lst`
+                        Ok(other) => match other.try_borrow() {
+                            Ok(other) => other.inner.clone(),
+                            Err(_) => self.inner.clone(),
+                        },
+                        Err(_) => value.extract()?,
+                    };
+                    let idx = slice.indices(self.inner.len() as isize)?;
+                    if idx.step == 1 {
+                        if values.len() != idx.slicelength {
+                            self.check_resizable()?;
+                        }
+                        let start = idx.start as usize;
+                        self.inner.splice(start..start + idx.slicelength, values);
+                    } else {
+                        if values.len() != idx.slicelength {
+                            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                                "attempt to assign sequence of size {} to extended slice of size {}",
+                                values.len(),
+                                idx.slicelength
+                            )));
+                        }
+                        for (i, v) in values.into_iter().enumerate() {
+                            self.inner[(idx.start + i as isize * idx.step) as usize] = v;
+                        }
+                    }
+                    return Ok(());
                 }
+                let index = resolve_index(key, self.inner.len())?;
+                self.inner[index] = value.extract()?;
+                Ok(())
             }
 
-            /// remove item
-            fn remove(&mut self, index: usize) -> PyResult<$t> {
-                if index < self.inner.len() {
-                    Ok(self.inner.remove(index))
-                } else {
-                    Err(pyo3::exceptions::PyIndexError::new_err(
-                        "Index out of range",
-                    ))
+            /// Delete an item or a slice, including extended slices.
+            fn __delitem__(&mut self, key: &Bound<'_, PyAny>) -> PyResult<()> {
+                self.check_resizable()?;
+                if let Ok(slice) = key.cast::<PySlice>() {
+                    let idx = slice.indices(self.inner.len() as isize)?;
+                    if idx.step == 1 {
+                        let start = idx.start as usize;
+                        self.inner.drain(start..start + idx.slicelength);
+                    } else {
+                        let mut removed = vec![false; self.inner.len()];
+                        for i in 0..idx.slicelength {
+                            removed[(idx.start + i as isize * idx.step) as usize] = true;
+                        }
+                        let mut flags = removed.into_iter();
+                        self.inner.retain(|_| !flags.next().unwrap_or(false));
+                    }
+                    return Ok(());
                 }
+                let index = resolve_index(key, self.inner.len())?;
+                self.inner.remove(index);
+                Ok(())
+            }
+
+            /// remove item
+            fn remove(&mut self, index: &Bound<'_, PyAny>) -> PyResult<$t> {
+                self.check_resizable()?;
+                let index = resolve_index(index, self.inner.len())?;
+                Ok(self.inner.remove(index))
+            }
+
+            /// Remove and return the item at `index`, the last one by default.
+            #[pyo3(signature = (index=None))]
+            fn pop(&mut self, index: Option<&Bound<'_, PyAny>>) -> PyResult<$t> {
+                self.check_resizable()?;
+                let index = match index {
+                    Some(index) => resolve_index(index, self.inner.len())?,
+                    None => self.inner.len().checked_sub(1).ok_or_else(|| {
+                        pyo3::exceptions::PyIndexError::new_err("pop from empty list")
+                    })?,
+                };
+                Ok(self.inner.remove(index))
             }
 
             /// insert item
-            fn insert(&mut self, index: usize, value: $t) -> PyResult<()> {
-                if index <= self.inner.len() {
-                    self.inner.insert(index, value);
-                    Ok(())
-                } else {
-                    Err(pyo3::exceptions::PyIndexError::new_err(
-                        "Index out of range",
-                    ))
-                }
+            fn insert(&mut self, index: &Bound<'_, PyAny>, value: $t) -> PyResult<()> {
+                self.check_resizable()?;
+                // 与 `len()` 相等的位置表示追加到末尾
+                let len = self.inner.len();
+                let index = match index.extract::<isize>()? {
+                    i if i >= 0 && i as usize == len => len,
+                    _ => resolve_index(index, len)?,
+                };
+                self.inner.insert(index, value);
+                Ok(())
             }
 
             /// to Python list
@@ -128,38 +276,830 @@ macro_rules! define_list_type {
                 PyList::new(py, &self.inner)
             }
 
+            /// Pickle support, also used by cloudpickle (Dask, Ray): rebuilt from a Python list.
+            fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyTuple>> {
+                let items = slf.borrow().to_list(slf.py())?;
+                (slf.get_type(), (items,)).into_pyobject(slf.py())
+            }
+
+            /// Format each item with `format_spec`, e.g. `f"{lst:.4f}"` gives `[1.0000, 2.5000]`.
+            fn __format__(&self, py: Python<'_>, format_spec: &str) -> PyResult<String> {
+                let format = py.import("builtins")?.getattr("format")?;
+                let items = self
+                    .inner
+                    .iter()
+                    .map(|v| format.call1((*v, format_spec))?.extract::<String>())
+                    .collect::<PyResult<Vec<_>>>()?;
+                Ok(format!("[{}]", items.join(", ")))
+            }
+
             /// to numpy array
             fn to_array<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, $py_array>> {
                 Ok(<$py_array>::from_vec(py, self.inner.clone()))
             }
 
-            /// move to numpy array
-            fn into_array<'py>(&mut self, py: Python<'py>) -> PyResult<Bound<'py, $py_array>> {
+            /// Move the items into a numpy array without copying, leaving this list empty.
+            ///
+            /// Use `to_array` to keep the list intact.
+            fn move_to_array<'py>(&mut self, py: Python<'py>) -> PyResult<Bound<'py, $py_array>> {
+                self.check_resizable()?;
                 Ok(<$py_array>::from_vec(py, std::mem::take(&mut self.inner)))
             }
 
+            /// Deprecated alias of `move_to_array`; like it, this leaves the list empty.
+            fn into_array<'py>(&mut self, py: Python<'py>) -> PyResult<Bound<'py, $py_array>> {
+                PyErr::warn(
+                    py,
+                    &py.get_type::<pyo3::exceptions::PyDeprecationWarning>(),
+                    c"into_array() is deprecated and empties the list; use move_to_array() or to_array()",
+                    1,
+                )?;
+                self.move_to_array(py)
+            }
+
             /// clear items
-            fn clear(&mut self) {
+            fn clear(&mut self) -> PyResult<()> {
+                self.check_resizable()?;
                 self.inner.clear();
+                Ok(())
+            }
+
+            /// Reserve room for at least `additional` more items, e.g. before an `append` loop.
+            ///
+            /// Capacity is a hint: appending past it still works and reallocates.
+            fn reserve(&mut self, additional: usize) -> PyResult<()> {
+                self.check_resizable()?;
+                self.inner.reserve(additional);
+                Ok(())
+            }
+
+            /// Number of items the list can hold without reallocating.
+            fn capacity(&self) -> usize {
+                self.inner.capacity()
+            }
+
+            /// Release capacity beyond the current length.
+            fn shrink_to_fit(&mut self) -> PyResult<()> {
+                self.check_resizable()?;
+                self.inner.shrink_to_fit();
+                Ok(())
             }
 
             /// deep copy
             fn copy(&self) ->Self {
                 self.clone()
             }
+
+            /// Concatenate lists into a new one, allocating once; `[]` gives an empty list.
+            #[staticmethod]
+            fn concat(lists: Vec<PyRef<'_, $name>>) -> Self {
+                let mut inner = Vec::with_capacity(lists.iter().map(|l| l.inner.len()).sum());
+                for list in &lists {
+                    inner.extend_from_slice(&list.inner);
+                }
+                Self::new(inner)
+            }
+        }
+
+        // pyo3 为 `__getbuffer__` / `__releasebuffer__` 生成的包装代码在 edition 2024 下会触发此 lint
+        #[allow(unsafe_op_in_unsafe_fn)]
+        const _: () = {
+            #[pymethods]
+            impl $name {
+                /// Export the list as a read-only buffer; the list cannot be resized while exported.
+                unsafe fn __getbuffer__(
+                    slf: Bound<'_, Self>,
+                    view: *mut pyo3::ffi::Py_buffer,
+                    flags: c_int,
+                ) -> PyResult<()> {
+                    const FORMAT: &CStr = $fmt;
+                    // 可变方法（sort、__setitem__ 等）会直接写入 inner，因此只导出只读 buffer
+                    if flags & pyo3::ffi::PyBUF_WRITABLE == pyo3::ffi::PyBUF_WRITABLE {
+                        return Err(pyo3::exceptions::PyBufferError::new_err(
+                            "list buffers are read-only",
+                        ));
+                    }
+                    let this = slf.borrow();
+                    let itemsize = std::mem::size_of::<$t>() as isize;
+                    // shape 与 strides 需在 view 存活期间有效，存放于 internal 中，释放时回收
+                    let dims = Box::into_raw(Box::new([this.inner.len() as isize, itemsize]));
+                    unsafe {
+                        (*view).obj = slf.clone().into_any().into_ptr();
+                        (*view).buf = this.inner.as_ptr() as *mut std::os::raw::c_void;
+                        (*view).len = this.inner.len() as isize * itemsize;
+                        (*view).itemsize = itemsize;
+                        (*view).readonly = 1;
+                        (*view).ndim = 1;
+                        (*view).format = if flags & pyo3::ffi::PyBUF_FORMAT == pyo3::ffi::PyBUF_FORMAT {
+                            FORMAT.as_ptr() as *mut _
+                        } else {
+                            std::ptr::null_mut()
+                        };
+                        (*view).shape = if flags & pyo3::ffi::PyBUF_ND == pyo3::ffi::PyBUF_ND {
+                            dims as *mut isize
+                        } else {
+                            std::ptr::null_mut()
+                        };
+                        (*view).strides = if flags & pyo3::ffi::PyBUF_STRIDES == pyo3::ffi::PyBUF_STRIDES {
+                            (dims as *mut isize).add(1)
+                        } else {
+                            std::ptr::null_mut()
+                        };
+                        (*view).suboffsets = std::ptr::null_mut();
+                        (*view).internal = dims as *mut std::os::raw::c_void;
+                    }
+                    this.exports.fetch_add(1, Ordering::AcqRel);
+                    Ok(())
+                }
+
+                unsafe fn __releasebuffer__(&self, view: *mut pyo3::ffi::Py_buffer) {
+                    unsafe {
+                        drop(Box::from_raw((*view).internal as *mut [isize; 2]));
+                    }
+                    self.exports.fetch_sub(1, Ordering::AcqRel);
+                }
+            }
+        };
+    };
+}
+
+/// A read-only list view returned by the `Get_*` accessors of Vec fields.
+///
+/// It holds a clone of the field data (cheapest safe approach given the GIL), and only
+/// exposes read methods, to make immutability visible at the type level.
+macro_rules! define_vec_ref_type {
+    ($name:ident, $t:ty, $py_array:ty) => {
+        #[pyclass(module="triton_client")]
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            inner: Vec<$t>,
+        }
+
+        impl $name {
+            pub fn new(inner: Vec<$t>) -> Self {
+                Self { inner }
+            }
+
+            pub fn into_vec(self) -> Vec<$t> {
+                self.inner
+            }
+        }
+
+        impl Deref for $name {
+            type Target = Vec<$t>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.inner
+            }
+        }
+
+        impl AsRef<Vec<$t>> for $name {
+            fn as_ref(&self) -> &Vec<$t> {
+                &self.inner
+            }
+        }
+
+        #[pymethods]
+        impl $name {
+            /// len
+            fn __len__(&self) -> usize {
+                self.inner.len()
+            }
+
+            fn __getitem__(&self, index: &Bound<'_, PyAny>) -> PyResult<$t> {
+                Ok(self.inner[resolve_index(index, self.inner.len())?])
+            }
+
+            fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+                PyList::new(py, &self.inner)?.try_iter()
+            }
+
+            /// to Python list
+            fn to_list<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+                PyList::new(py, &self.inner)
+            }
+
+            /// to numpy array
+            fn to_array<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, $py_array>> {
+                Ok(<$py_array>::from_vec(py, self.inner.clone()))
+            }
         }
     };
 }
 
 // 为支持的数值类型定义 List 类型
-define_list_type!(ListBool, bool, PyArray1<bool>, PyReadonlyArray1<bool>);
-define_list_type!(ListI8, i8, PyArray1<i8>, PyReadonlyArray1<i8>);
-define_list_type!(ListI16, i16, PyArray1<i16>, PyReadonlyArray1<i16>);
-define_list_type!(ListI32, i32, PyArray1<i32>, PyReadonlyArray1<i32>);
-define_list_type!(ListI64, i64, PyArray1<i64>, PyReadonlyArray1<i64>);
-define_list_type!(ListU8, u8, PyArray1<u8>, PyReadonlyArray1<u8>);
-define_list_type!(ListU16, u16, PyArray1<u16>, PyReadonlyArray1<u16>);
-define_list_type!(ListU32, u32, PyArray1<u32>, PyReadonlyArray1<u32>);
-define_list_type!(ListU64, u64, PyArray1<u64>, PyReadonlyArray1<u64>);
-define_list_type!(ListF32, f32, PyArray1<f32>, PyReadonlyArray1<f32>);
-define_list_type!(ListF64, f64, PyArray1<f64>, PyReadonlyArray1<f64>);
+define_list_type!(ListBool, bool, PyArray1<bool>, PyReadonlyArray1<bool>, c"?");
+define_list_type!(ListI8, i8, PyArray1<i8>, PyReadonlyArray1<i8>, c"b");
+define_list_type!(ListI16, i16, PyArray1<i16>, PyReadonlyArray1<i16>, c"h");
+define_list_type!(ListI32, i32, PyArray1<i32>, PyReadonlyArray1<i32>, c"i");
+define_list_type!(ListI64, i64, PyArray1<i64>, PyReadonlyArray1<i64>, c"q");
+define_list_type!(ListU8, u8, PyArray1<u8>, PyReadonlyArray1<u8>, c"B");
+define_list_type!(ListU16, u16, PyArray1<u16>, PyReadonlyArray1<u16>, c"H");
+define_list_type!(ListU32, u32, PyArray1<u32>, PyReadonlyArray1<u32>, c"I");
+define_list_type!(ListU64, u64, PyArray1<u64>, PyReadonlyArray1<u64>, c"Q");
+define_list_type!(ListF32, f32, PyArray1<f32>, PyReadonlyArray1<f32>, c"f");
+define_list_type!(ListF64, f64, PyArray1<f64>, PyReadonlyArray1<f64>, c"d");
+
+/// 数值类型的排序，`$cmp` 为自然顺序，`$cmp_abs` 为按绝对值的顺序
+macro_rules! impl_sort {
+    ($name:ident, $t:ty, $cmp:expr, $cmp_abs:expr) => {
+        #[pymethods]
+        impl $name {
+            /// Stable in-place sort, like `list.sort`.
+            ///
+            /// `key` is one of `None`, `"abs"` or `"neg"`; arbitrary callables are not supported.
+            #[pyo3(signature = (reverse=false, key=None))]
+            fn sort(&mut self, reverse: bool, key: Option<&str>) -> PyResult<()> {
+                let natural: fn(&$t, &$t) -> std::cmp::Ordering = $cmp;
+                let by_abs: fn(&$t, &$t) -> std::cmp::Ordering = $cmp_abs;
+                let (cmp, reverse) = match key {
+                    None => (natural, reverse),
+                    Some("abs") => (by_abs, reverse),
+                    // -x 的顺序即 x 的逆序，避免整数取负溢出
+                    Some("neg") => (natural, !reverse),
+                    Some(other) => {
+                        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                            "unsupported sort key: {:?}, expected None, 'abs' or 'neg'",
+                            other
+                        )));
+                    }
+                };
+                if reverse {
+                    self.inner.sort_by(|a, b| cmp(b, a));
+                } else {
+                    self.inner.sort_by(cmp);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_sort!(ListI8, i8, |a, b| a.cmp(b), |a, b| a.unsigned_abs().cmp(&b.unsigned_abs()));
+impl_sort!(ListI16, i16, |a, b| a.cmp(b), |a, b| a.unsigned_abs().cmp(&b.unsigned_abs()));
+impl_sort!(ListI32, i32, |a, b| a.cmp(b), |a, b| a.unsigned_abs().cmp(&b.unsigned_abs()));
+impl_sort!(ListI64, i64, |a, b| a.cmp(b), |a, b| a.unsigned_abs().cmp(&b.unsigned_abs()));
+impl_sort!(ListU8, u8, |a, b| a.cmp(b), |a, b| a.cmp(b));
+impl_sort!(ListU16, u16, |a, b| a.cmp(b), |a, b| a.cmp(b));
+impl_sort!(ListU32, u32, |a, b| a.cmp(b), |a, b| a.cmp(b));
+impl_sort!(ListU64, u64, |a, b| a.cmp(b), |a, b| a.cmp(b));
+impl_sort!(ListF32, f32, |a, b| a.total_cmp(b), |a, b| a.abs().total_cmp(&b.abs()));
+impl_sort!(ListF64, f64, |a, b| a.total_cmp(b), |a, b| a.abs().total_cmp(&b.abs()));
+
+/// 数值类型的裁剪
+macro_rules! impl_clip {
+    ($name:ident, $t:ty) => {
+        #[pymethods]
+        impl $name {
+            /// Clamp every element to `[min, max]` in place.
+            fn clip(&mut self, min: $t, max: $t) -> PyResult<()> {
+                check_clip_bounds(min, max)?;
+                self.inner.iter_mut().for_each(|v| *v = (*v).clamp(min, max));
+                Ok(())
+            }
+
+            /// Clipped copy; the list itself is unchanged.
+            fn clip_new(&self, min: $t, max: $t) -> PyResult<$name> {
+                let mut out = self.clone();
+                out.clip(min, max)?;
+                Ok(out)
+            }
+        }
+    };
+}
+
+// `clamp` 在 min > max 或 NaN 时会 panic
+fn check_clip_bounds<T: PartialOrd>(min: T, max: T) -> PyResult<()> {
+    if !matches!(
+        min.partial_cmp(&max),
+        Some(std::cmp::Ordering::Less | std::cmp::Ordering::Equal)
+    ) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "clip requires min <= max",
+        ));
+    }
+    Ok(())
+}
+
+impl_clip!(ListI8, i8);
+impl_clip!(ListI16, i16);
+impl_clip!(ListI32, i32);
+impl_clip!(ListI64, i64);
+impl_clip!(ListU8, u8);
+impl_clip!(ListU16, u16);
+impl_clip!(ListU32, u32);
+impl_clip!(ListU64, u64);
+impl_clip!(ListF32, f32);
+impl_clip!(ListF64, f64);
+
+/// `from_range` 生成的最大元素个数
+pub const MAX_RANGE_LEN: usize = 10_000_000;
+
+fn check_range_step(is_zero: bool) -> PyResult<()> {
+    if is_zero {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "from_range step must not be zero",
+        ));
+    }
+    Ok(())
+}
+
+fn check_range_len(len: u128) -> PyResult<usize> {
+    if len > MAX_RANGE_LEN as u128 {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "from_range would produce {} elements, more than {}",
+            len, MAX_RANGE_LEN
+        )));
+    }
+    Ok(len as usize)
+}
+
+/// 等差序列构造，类似 `np.arange`
+macro_rules! impl_from_range {
+    (int: $name:ident, $t:ty) => {
+        #[pymethods]
+        impl $name {
+            /// Elements `start, start + step, ...` up to but excluding `stop`, like `np.arange`.
+            #[staticmethod]
+            fn from_range(start: $t, stop: $t, step: $t) -> PyResult<Self> {
+                check_range_step(step == 0)?;
+                // 以 i128 计算，避免溢出
+                let (start, stop, step) = (start as i128, stop as i128, step as i128);
+                let span = stop - start;
+                let len = if span != 0 && (span > 0) == (step > 0) {
+                    span.unsigned_abs().div_ceil(step.unsigned_abs())
+                } else {
+                    0
+                };
+                let len = check_range_len(len)?;
+                Ok(Self::new(
+                    (0..len as i128).map(|i| (start + i * step) as $t).collect(),
+                ))
+            }
+        }
+    };
+    (float: $name:ident, $t:ty) => {
+        #[pymethods]
+        impl $name {
+            /// Elements `start, start + step, ...` up to but excluding `stop`, like `np.arange`.
+            ///
+            /// Element `i` is computed as `start + i * step` rather than by accumulation.
+            #[staticmethod]
+            fn from_range(start: $t, stop: $t, step: $t) -> PyResult<Self> {
+                check_range_step(step == 0.0)?;
+                if !(start.is_finite() && stop.is_finite() && step.is_finite()) {
+                    return Err(pyo3::exceptions::PyValueError::new_err(
+                        "from_range bounds and step must be finite",
+                    ));
+                }
+                let count = ((stop as f64 - start as f64) / step as f64).ceil();
+                let len = if count > 0.0 {
+                    check_range_len(count.min(u128::MAX as f64) as u128)?
+                } else {
+                    0
+                };
+                Ok(Self::new(
+                    (0..len).map(|i| start + i as $t * step).collect(),
+                ))
+            }
+        }
+    };
+}
+
+impl_from_range!(int: ListI8, i8);
+impl_from_range!(int: ListI16, i16);
+impl_from_range!(int: ListI32, i32);
+impl_from_range!(int: ListI64, i64);
+impl_from_range!(int: ListU8, u8);
+impl_from_range!(int: ListU16, u16);
+impl_from_range!(int: ListU32, u32);
+impl_from_range!(int: ListU64, u64);
+impl_from_range!(float: ListF32, f32);
+impl_from_range!(float: ListF64, f64);
+
+/// 与标量逐元素相乘，类似 numpy 广播；整数按 numpy 的方式溢出回绕
+///
+/// 参数类型为元素类型 `$t`，因此 `ListI32([1, 2]) * 2` 是逐元素乘法而不是序列重复
+macro_rules! impl_mul {
+    ($name:ident, $t:ty, $mul:expr) => {
+        #[pymethods]
+        impl $name {
+            /// Element-wise product with a scalar, as a new list.
+            fn __mul__(&self, factor: $t) -> $name {
+                let mul: fn($t, $t) -> $t = $mul;
+                $name::new(self.inner.iter().map(|&v| mul(v, factor)).collect())
+            }
+
+            fn __rmul__(&self, factor: $t) -> $name {
+                self.__mul__(factor)
+            }
+        }
+    };
+}
+
+impl_mul!(ListI8, i8, |a, b| a.wrapping_mul(b));
+impl_mul!(ListI16, i16, |a, b| a.wrapping_mul(b));
+impl_mul!(ListI32, i32, |a, b| a.wrapping_mul(b));
+impl_mul!(ListI64, i64, |a, b| a.wrapping_mul(b));
+impl_mul!(ListU8, u8, |a, b| a.wrapping_mul(b));
+impl_mul!(ListU16, u16, |a, b| a.wrapping_mul(b));
+impl_mul!(ListU32, u32, |a, b| a.wrapping_mul(b));
+impl_mul!(ListU64, u64, |a, b| a.wrapping_mul(b));
+impl_mul!(ListF32, f32, |a, b| a * b);
+impl_mul!(ListF64, f64, |a, b| a * b);
+
+/// 浮点类型的 L2 归一化
+macro_rules! impl_normalize {
+    ($name:ident, $t:ty) => {
+        #[pymethods]
+        impl $name {
+            /// Divide every element by the L2 norm in place; raises `ValueError` if it is zero.
+            fn normalize(&mut self) -> PyResult<()> {
+                let norm = self
+                    .inner
+                    .iter()
+                    .map(|&v| v as f64 * v as f64)
+                    .sum::<f64>()
+                    .sqrt();
+                if norm == 0.0 {
+                    return Err(pyo3::exceptions::PyValueError::new_err(
+                        "cannot normalize a zero vector",
+                    ));
+                }
+                self.inner
+                    .iter_mut()
+                    .for_each(|v| *v = (*v as f64 / norm) as $t);
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_normalize!(ListF32, f32);
+impl_normalize!(ListF64, f64);
+
+// 向量运算，用于 embedding 等输出的后处理，以 f64 累加
+#[pymethods]
+impl ListF32 {
+    /// Dot product; raises `ValueError` if the lengths differ.
+    fn dot(&self, other: &ListF32) -> PyResult<f64> {
+        if self.inner.len() != other.inner.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "length mismatch: {} != {}",
+                self.inner.len(),
+                other.inner.len()
+            )));
+        }
+        Ok(self
+            .inner
+            .iter()
+            .zip(&other.inner)
+            .map(|(&a, &b)| a as f64 * b as f64)
+            .sum())
+    }
+
+    /// Euclidean norm.
+    fn l2_norm(&self) -> f64 {
+        self.inner
+            .iter()
+            .map(|&v| v as f64 * v as f64)
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Cosine similarity; raises `ValueError` if the lengths differ or either norm is zero.
+    fn cosine_similarity(&self, other: &ListF32) -> PyResult<f64> {
+        let dot = self.dot(other)?;
+        let norms = self.l2_norm() * other.l2_norm();
+        if norms == 0.0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "cosine similarity is undefined for zero vectors",
+            ));
+        }
+        Ok(dot / norms)
+    }
+}
+
+// 二维数组（batch × features）与扁平列表互转
+#[pymethods]
+impl ListF32 {
+    /// Copy a 2-D numpy array in row-major order, flattening it.
+    #[staticmethod]
+    fn from_numpy_2d(arr: PyReadonlyArray2<f32>) -> PyResult<Self> {
+        let inner = match arr.as_slice() {
+            Ok(slice) => slice.to_vec(),
+            // 非 C 连续（如转置视图）时按行优先顺序复制
+            Err(_) => arr.to_owned_array().into_iter().collect(),
+        };
+        Ok(Self::new(inner))
+    }
+
+    /// Copy into a 2-D numpy array with `rows` rows; the inverse of `from_numpy_2d`.
+    ///
+    /// Raises `ValueError` if the length is not a multiple of `rows`.
+    fn to_numpy_2d<'py>(
+        &self,
+        rows: usize,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyArray2<f32>>> {
+        if rows == 0 || !self.inner.len().is_multiple_of(rows) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "cannot split {} items into {} rows",
+                self.inner.len(),
+                rows
+            )));
+        }
+        let cols = self.inner.len() / rows;
+        PyArray1::from_vec(py, self.inner.clone()).reshape([rows, cols])
+    }
+}
+
+// 非对称整数量化（TensorRT 的 INT8 方案）及其逆运算
+fn check_scale(scale: f64) -> PyResult<()> {
+    if !scale.is_finite() || scale == 0.0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "scale must be finite and non-zero, got {}",
+            scale
+        )));
+    }
+    Ok(())
+}
+
+#[pymethods]
+impl ListF32 {
+    /// Quantize to INT8 as `round(x / scale + zero_point)`, clamped to the `int8` range.
+    #[pyo3(signature = (scale, zero_point=0))]
+    fn quantize_int8(&self, scale: f32, zero_point: i8) -> PyResult<ListI8> {
+        check_scale(scale as f64)?;
+        Ok(ListI8::new(
+            self.inner
+                .iter()
+                .map(|&x| {
+                    (x / scale + zero_point as f32)
+                        .round()
+                        .clamp(i8::MIN as f32, i8::MAX as f32) as i8
+                })
+                .collect(),
+        ))
+    }
+}
+
+#[pymethods]
+impl ListI8 {
+    /// Inverse of `ListF32.quantize_int8`: `(q - zero_point) * scale`.
+    #[pyo3(signature = (scale, zero_point=0))]
+    fn dequantize_fp32(&self, scale: f32, zero_point: i8) -> ListF32 {
+        ListF32::new(
+            self.inner
+                .iter()
+                .map(|&q| (q as f32 - zero_point as f32) * scale)
+                .collect(),
+        )
+    }
+}
+
+#[pymethods]
+impl ListF64 {
+    /// Quantize to INT16 as `round(x / scale + zero_point)`, clamped to the `int16` range;
+    /// symmetric with the default `zero_point=0`.
+    #[pyo3(signature = (scale, zero_point=0))]
+    fn quantize_int16(&self, scale: f64, zero_point: i16) -> PyResult<ListI16> {
+        check_scale(scale)?;
+        Ok(ListI16::new(
+            self.inner
+                .iter()
+                .map(|&x| {
+                    (x / scale + zero_point as f64)
+                        .round()
+                        .clamp(i16::MIN as f64, i16::MAX as f64) as i16
+                })
+                .collect(),
+        ))
+    }
+}
+
+#[pymethods]
+impl ListI16 {
+    /// Inverse of `ListF64.quantize_int16`: `(q - zero_point) * scale`.
+    #[pyo3(signature = (scale, zero_point=0))]
+    fn dequantize_fp64(&self, scale: f64, zero_point: i16) -> ListF64 {
+        ListF64::new(
+            self.inner
+                .iter()
+                .map(|&q| (q as f64 - zero_point as f64) * scale)
+                .collect(),
+        )
+    }
+}
+
+// 分类后处理常用的布尔与索引操作
+#[pymethods]
+impl ListBool {
+    fn any(&self) -> bool {
+        self.inner.iter().any(|&v| v)
+    }
+
+    fn all(&self) -> bool {
+        self.inner.iter().all(|&v| v)
+    }
+
+    fn count_true(&self) -> usize {
+        self.inner.iter().filter(|&&v| v).count()
+    }
+}
+
+#[pymethods]
+impl ListI32 {
+    /// Index of the first maximum; raises `ValueError` if empty.
+    fn argmax(&self) -> PyResult<usize> {
+        let mut iter = self.inner.iter().enumerate();
+        let first = iter.next().ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err("argmax of an empty list")
+        })?;
+        let (index, _) = iter.fold(first, |best, cur| if cur.1 > best.1 { cur } else { best });
+        Ok(index)
+    }
+
+    /// Unique values in order of first appearance.
+    fn unique(&self) -> ListI32 {
+        let mut seen = std::collections::HashSet::new();
+        ListI32::new(self.inner.iter().copied().filter(|v| seen.insert(*v)).collect())
+    }
+}
+
+// 整数类型的去重集合；浮点类型因 NaN 不满足可哈希约定而不提供
+macro_rules! impl_to_set {
+    ($name:ident, $t:ty) => {
+        #[pymethods]
+        impl $name {
+            /// Unique values as a Python `set`, e.g. the predicted classes.
+            fn to_set<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PySet>> {
+                let unique: std::collections::HashSet<$t> = self.inner.iter().copied().collect();
+                PySet::new(py, unique)
+            }
+
+            /// Unique values as a Python `frozenset`.
+            fn to_frozenset<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyFrozenSet>> {
+                let unique: std::collections::HashSet<$t> = self.inner.iter().copied().collect();
+                PyFrozenSet::new(py, unique)
+            }
+        }
+    };
+}
+
+impl_to_set!(ListI32, i32);
+impl_to_set!(ListU32, u32);
+impl_to_set!(ListI64, i64);
+impl_to_set!(ListU64, u64);
+
+// 仅对字节数组有意义的编解码方法
+#[pymethods]
+impl ListU8 {
+    /// Standard base64 encoding, as used by Triton's HTTP/JSON protocol.
+    fn to_base64(&self) -> String {
+        BASE64_STANDARD.encode(&self.inner)
+    }
+
+    #[staticmethod]
+    fn from_base64(s: &str) -> PyResult<Self> {
+        BASE64_STANDARD
+            .decode(s)
+            .map(Self::new)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("invalid base64: {}", e)))
+    }
+
+    /// Lowercase hex string, for debugging.
+    fn to_hex(&self) -> String {
+        self.inner.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[staticmethod]
+    fn from_hex(s: &str) -> PyResult<Self> {
+        let invalid = || pyo3::exceptions::PyValueError::new_err("invalid hex string");
+        if !s.len().is_multiple_of(2) || !s.is_ascii() {
+            return Err(invalid());
+        }
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|_| invalid()))
+            .collect::<PyResult<Vec<u8>>>()
+            .map(Self::new)
+    }
+}
+
+/// PyTorch 互操作，运行时导入 torch，编译期不依赖
+#[cfg(feature = "torch")]
+macro_rules! impl_torch {
+    ($name:ident, $torch_dtype:literal) => {
+        #[pymethods]
+        impl $name {
+            /// Zero-copy `torch.Tensor` view over the list buffer.
+            ///
+            /// The list cannot be resized while the tensor is alive. The buffer is read-only, so
+            /// torch warns that the tensor is not writable; `.clone()` it before writing.
+            fn to_torch<'py>(slf: Bound<'py, Self>) -> PyResult<Bound<'py, PyAny>> {
+                let torch = import_torch(slf.py())?;
+                let kwargs = pyo3::types::PyDict::new(slf.py());
+                kwargs.set_item("dtype", torch.getattr($torch_dtype)?)?;
+                torch.call_method("frombuffer", (slf,), Some(&kwargs))
+            }
+
+            /// Copy a `torch.Tensor` of matching dtype, flattening it.
+            #[staticmethod]
+            fn from_torch(tensor: &Bound<'_, PyAny>) -> PyResult<Self> {
+                import_torch(tensor.py())?;
+                let arr = tensor
+                    .call_method0("detach")?
+                    .call_method0("cpu")?
+                    .call_method0("contiguous")?
+                    .call_method0("numpy")?
+                    .call_method1("reshape", (-1,))?;
+                Self::from_array(arr.extract()?)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "torch")]
+fn import_torch(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    py.import("torch").map_err(|_| {
+        pyo3::exceptions::PyModuleNotFoundError::new_err("torch is required for torch conversions")
+    })
+}
+
+#[cfg(feature = "torch")]
+impl_torch!(ListI32, "int32");
+#[cfg(feature = "torch")]
+impl_torch!(ListI64, "int64");
+#[cfg(feature = "torch")]
+impl_torch!(ListF32, "float32");
+#[cfg(feature = "torch")]
+impl_torch!(ListF64, "float64");
+
+/// numpy `__array_interface__` 协议，数据仍经由 buffer 协议导出
+#[cfg(feature = "numpy_interface")]
+macro_rules! impl_array_interface {
+    ($name:ident, $t:ty, $kind:literal) => {
+        #[pymethods]
+        impl $name {
+            /// numpy array interface (version 3) describing the list buffer, so
+            /// `np.asarray(lst)` creates a zero-copy, read-only view.
+            ///
+            /// `data` is omitted, so numpy takes the memory through the buffer protocol: the
+            /// list cannot be resized while the view is alive.
+            #[getter]
+            fn __array_interface__<'py>(
+                &self,
+                py: Python<'py>,
+            ) -> PyResult<Bound<'py, pyo3::types::PyDict>> {
+                let size = std::mem::size_of::<$t>();
+                let order = match size {
+                    1 => "|",
+                    _ if cfg!(target_endian = "little") => "<",
+                    _ => ">",
+                };
+                let dict = pyo3::types::PyDict::new(py);
+                dict.set_item("shape", (self.inner.len(),))?;
+                dict.set_item("typestr", format!("{}{}{}", order, $kind, size))?;
+                dict.set_item("version", 3)?;
+                Ok(dict)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "numpy_interface")]
+impl_array_interface!(ListBool, bool, "b");
+#[cfg(feature = "numpy_interface")]
+impl_array_interface!(ListI8, i8, "i");
+#[cfg(feature = "numpy_interface")]
+impl_array_interface!(ListI16, i16, "i");
+#[cfg(feature = "numpy_interface")]
+impl_array_interface!(ListI32, i32, "i");
+#[cfg(feature = "numpy_interface")]
+impl_array_interface!(ListI64, i64, "i");
+#[cfg(feature = "numpy_interface")]
+impl_array_interface!(ListU8, u8, "u");
+#[cfg(feature = "numpy_interface")]
+impl_array_interface!(ListU16, u16, "u");
+#[cfg(feature = "numpy_interface")]
+impl_array_interface!(ListU32, u32, "u");
+#[cfg(feature = "numpy_interface")]
+impl_array_interface!(ListU64, u64, "u");
+#[cfg(feature = "numpy_interface")]
+impl_array_interface!(ListF32, f32, "f");
+#[cfg(feature = "numpy_interface")]
+impl_array_interface!(ListF64, f64, "f");
+
+// 为支持的数值类型定义只读 VecRef 类型
+define_vec_ref_type!(VecRefBool, bool, PyArray1<bool>);
+define_vec_ref_type!(VecRefI8, i8, PyArray1<i8>);
+define_vec_ref_type!(VecRefI16, i16, PyArray1<i16>);
+define_vec_ref_type!(VecRefI32, i32, PyArray1<i32>);
+define_vec_ref_type!(VecRefI64, i64, PyArray1<i64>);
+define_vec_ref_type!(VecRefU8, u8, PyArray1<u8>);
+define_vec_ref_type!(VecRefU16, u16, PyArray1<u16>);
+define_vec_ref_type!(VecRefU32, u32, PyArray1<u32>);
+define_vec_ref_type!(VecRefU64, u64, PyArray1<u64>);
+define_vec_ref_type!(VecRefF32, f32, PyArray1<f32>);
+define_vec_ref_type!(VecRefF64, f64, PyArray1<f64>);
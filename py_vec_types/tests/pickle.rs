@@ -0,0 +1,49 @@
+//! `pickle.loads(pickle.dumps(lst))` 往返测试
+use py_vec_types::{ListF32, ListI32};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyModule};
+
+#[test]
+fn pickle_round_trip_keeps_type_and_items() {
+    Python::initialize();
+    Python::attach(|py| {
+        // pickle 按 `module="triton_client"` 查找类型，注册一个只含 List 类型的同名模块
+        let module = PyModule::new(py, "triton_client").unwrap();
+        module.add_class::<ListI32>().unwrap();
+        module.add_class::<ListF32>().unwrap();
+        py.import("sys")
+            .unwrap()
+            .getattr("modules")
+            .unwrap()
+            .set_item("triton_client", &module)
+            .unwrap();
+
+        let locals = PyDict::new(py);
+        locals
+            .set_item("pickle", py.import("pickle").unwrap())
+            .unwrap();
+        locals
+            .set_item("ints", ListI32::new(vec![1, -2, 3]))
+            .unwrap();
+        locals
+            .set_item("floats", ListF32::new(vec![0.5, -1.25]))
+            .unwrap();
+        py.run(
+            c"ints2 = pickle.loads(pickle.dumps(ints))\n\
+              floats2 = pickle.loads(pickle.dumps(floats))",
+            None,
+            Some(&locals),
+        )
+        .unwrap();
+
+        let ints = locals.get_item("ints2").unwrap().unwrap();
+        assert_eq!(ints.get_type().name().unwrap(), "ListI32");
+        assert_eq!(ints.extract::<ListI32>().unwrap().to_vec(), vec![1, -2, 3]);
+        let floats = locals.get_item("floats2").unwrap().unwrap();
+        assert_eq!(floats.get_type().name().unwrap(), "ListF32");
+        assert_eq!(
+            floats.extract::<ListF32>().unwrap().to_vec(),
+            vec![0.5, -1.25]
+        );
+    });
+}
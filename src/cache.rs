@@ -0,0 +1,87 @@
+//! Time-limited response cache used by [`Client`](crate::client::Client).
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Responses keyed by request, each valid for a caller-given TTL; clones share the entries.
+#[derive(Debug)]
+pub(crate) struct TtlCache<K, V> {
+    entries: Arc<Mutex<HashMap<K, (Instant, V)>>>,
+}
+
+impl<K, V> Clone for TtlCache<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<K, V> Default for TtlCache<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> TtlCache<K, V> {
+    /// The value stored for `key` less than `ttl` ago.
+    pub(crate) fn get(&self, key: &K, ttl: Duration) -> Option<V> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries
+            .get(key)
+            .filter(|(stored, _)| stored.elapsed() < ttl)
+            .map(|(_, value)| value.clone())
+    }
+
+    pub(crate) fn insert(&self, key: K, value: V) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key, (Instant::now(), value));
+    }
+
+    pub(crate) fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_expire_after_ttl() {
+        let cache = TtlCache::default();
+        cache.insert("resnet", 1);
+        assert_eq!(cache.get(&"resnet", Duration::from_secs(60)), Some(1));
+        assert_eq!(cache.get(&"bert", Duration::from_secs(60)), None);
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(cache.get(&"resnet", Duration::from_millis(10)), None);
+        // the TTL is per lookup, so a longer one still sees the entry
+        assert_eq!(cache.get(&"resnet", Duration::from_secs(60)), Some(1));
+    }
+
+    #[test]
+    fn zero_ttl_never_hits() {
+        let cache = TtlCache::default();
+        cache.insert("resnet", 1);
+        assert_eq!(cache.get(&"resnet", Duration::ZERO), None);
+    }
+
+    #[test]
+    fn insert_refreshes_and_clear_invalidates_shared_entries() {
+        let cache = TtlCache::default();
+        let shared = cache.clone();
+        cache.insert("resnet", 1);
+        shared.insert("resnet", 2);
+        assert_eq!(cache.get(&"resnet", Duration::from_secs(60)), Some(2));
+        shared.clear();
+        assert_eq!(cache.get(&"resnet", Duration::from_secs(60)), None);
+    }
+}
@@ -0,0 +1,151 @@
+//! Per-server circuit breaker used by [`Client`](crate::client::Client).
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CircuitBreakerConfig {
+    /// Consecutive failures that open the circuit.
+    pub failure_threshold: u32,
+    /// How long an open circuit rejects calls before letting a probe through.
+    pub reset_timeout: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CircuitState {
+    Closed {
+        failures: u32,
+    },
+    Open {
+        since: Instant,
+    },
+    /// A single probe request is in flight; another is allowed if it never reports back.
+    HalfOpen {
+        since: Instant,
+    },
+}
+
+/// Circuit breaker state of one server; clones share the state.
+#[derive(Debug, Clone)]
+pub(crate) struct Circuit {
+    config: CircuitBreakerConfig,
+    state: Arc<Mutex<CircuitState>>,
+}
+
+impl Circuit {
+    pub(crate) fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            state: Arc::new(Mutex::new(CircuitState::Closed { failures: 0 })),
+        }
+    }
+
+    /// Whether a call may be sent now; moves an expired open circuit to half-open.
+    pub(crate) fn allow(&self) -> bool {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        match *state {
+            CircuitState::Closed { .. } => true,
+            CircuitState::Open { since } | CircuitState::HalfOpen { since }
+                if since.elapsed() >= self.config.reset_timeout =>
+            {
+                *state = CircuitState::HalfOpen {
+                    since: Instant::now(),
+                };
+                true
+            }
+            CircuitState::Open { .. } | CircuitState::HalfOpen { .. } => false,
+        }
+    }
+
+    pub(crate) fn record_success(&self) {
+        *self.state.lock().unwrap_or_else(|e| e.into_inner()) =
+            CircuitState::Closed { failures: 0 };
+    }
+
+    pub(crate) fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        *state = match *state {
+            CircuitState::Closed { failures } if failures + 1 < self.config.failure_threshold => {
+                CircuitState::Closed {
+                    failures: failures + 1,
+                }
+            }
+            _ => CircuitState::Open {
+                since: Instant::now(),
+            },
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(failure_threshold: u32, reset_timeout: Duration) -> Circuit {
+        Circuit::new(CircuitBreakerConfig {
+            failure_threshold,
+            reset_timeout,
+        })
+    }
+
+    fn state(circuit: &Circuit) -> CircuitState {
+        *circuit.state.lock().unwrap()
+    }
+
+    #[test]
+    fn opens_after_threshold_consecutive_failures() {
+        let circuit = breaker(3, Duration::from_secs(60));
+        circuit.record_failure();
+        circuit.record_failure();
+        assert_eq!(state(&circuit), CircuitState::Closed { failures: 2 });
+        assert!(circuit.allow());
+        circuit.record_failure();
+        assert!(matches!(state(&circuit), CircuitState::Open { .. }));
+        assert!(!circuit.allow());
+    }
+
+    #[test]
+    fn success_resets_failure_count() {
+        let circuit = breaker(2, Duration::from_secs(60));
+        circuit.record_failure();
+        circuit.record_success();
+        circuit.record_failure();
+        assert_eq!(state(&circuit), CircuitState::Closed { failures: 1 });
+    }
+
+    #[test]
+    fn expired_open_circuit_lets_a_single_probe_through() {
+        let circuit = breaker(1, Duration::from_millis(20));
+        circuit.record_failure();
+        assert!(!circuit.allow());
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(circuit.allow());
+        assert!(matches!(state(&circuit), CircuitState::HalfOpen { .. }));
+        // the probe is still in flight
+        assert!(!circuit.allow());
+    }
+
+    #[test]
+    fn half_open_probe_result_closes_or_reopens() {
+        let circuit = breaker(1, Duration::ZERO);
+        circuit.record_failure();
+        assert!(circuit.allow());
+        circuit.record_success();
+        assert_eq!(state(&circuit), CircuitState::Closed { failures: 0 });
+
+        let circuit = breaker(3, Duration::ZERO);
+        for _ in 0..3 {
+            circuit.record_failure();
+        }
+        assert!(circuit.allow());
+        // a failed probe reopens the circuit right away, regardless of the threshold
+        circuit.record_failure();
+        assert!(matches!(state(&circuit), CircuitState::Open { .. }));
+    }
+
+    #[test]
+    fn clones_share_state() {
+        let circuit = breaker(1, Duration::from_secs(60));
+        circuit.clone().record_failure();
+        assert!(!circuit.allow());
+    }
+}
@@ -1,16 +1,28 @@
+use crate::cache::TtlCache;
+use crate::circuit::{Circuit, CircuitBreakerConfig};
 use crate::error::Error;
-use anyhow::Context;
-use pyo3::types::PyAnyMethods;
-use tonic::metadata::{AsciiMetadataValue, MetadataValue};
+use crate::mock::Mock;
+use crate::runtime::Runtime;
+use pyo3::Py;
+use pyo3::PyRefMut;
+use pyo3::types::{PyAnyMethods, PyList, PyListMethods, PyString, PyStringMethods};
+use std::collections::HashMap;
+use std::hash::{BuildHasher, RandomState};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use tonic::metadata::{AsciiMetadataKey, AsciiMetadataValue, KeyAndValueRef, MetadataValue};
 use tonic::service::Interceptor;
 use tonic::transport::Channel;
 use tonic::transport::channel::ClientTlsConfig;
+use tonic::transport::{Certificate, Identity};
 use tonic::{Status, service::interceptor::InterceptedService};
 
 use super::inference;
 use super::inference::grpc_inference_service_client::GrpcInferenceServiceClient;
 
 /// Adds bearer token auth to [`Client`]
+///
+/// A per-call `authorization` header takes precedence over the client's access token.
 #[derive(Clone)]
 pub struct AuthInterceptor {
     token: Option<AsciiMetadataValue>,
@@ -18,7 +30,9 @@ pub struct AuthInterceptor {
 
 impl Interceptor for AuthInterceptor {
     fn call(&mut self, mut request: tonic::Request<()>) -> Result<tonic::Request<()>, Status> {
-        if let Some(token) = self.token.as_ref() {
+        if let Some(token) = self.token.as_ref()
+            && !request.metadata().contains_key("authorization")
+        {
             request
                 .metadata_mut()
                 .insert("authorization", token.clone());
@@ -39,295 +53,1610 @@ impl AuthInterceptor {
     }
 }
 
+/// Enter a `tracing` span until the end of the enclosing block (no-op without the `tracing` feature).
+macro_rules! call_span {
+    ($($args:tt)*) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!($($args)*).entered();
+    };
+}
+
+/// Wrap a message into a [`tonic::Request`] carrying the given metadata headers.
+///
+/// With the `tracing` feature the current span context is propagated as `traceparent`.
+fn new_request<T>(
+    message: T,
+    headers: Option<&HashMap<String, String>>,
+) -> Result<tonic::Request<T>, Error> {
+    let mut request = tonic::Request::new(message);
+    if let Some(headers) = headers {
+        insert_headers(request.metadata_mut(), headers)?;
+    }
+    #[cfg(feature = "tracing")]
+    crate::telemetry::inject_trace_context(&mut request);
+    Ok(request)
+}
+
+/// Insert `headers` into `metadata`, lowercasing the keys.
+fn insert_headers(
+    metadata: &mut tonic::metadata::MetadataMap,
+    headers: &HashMap<String, String>,
+) -> Result<(), Error> {
+    for (key, value) in headers {
+        let key = AsciiMetadataKey::from_bytes(key.to_lowercase().as_bytes())?;
+        metadata.insert(key, AsciiMetadataValue::try_from(value.as_str())?);
+    }
+    Ok(())
+}
+
+/// Time left until `deadline` (Unix seconds), or [`Error::DeadlineExceeded`] if it has passed.
+fn time_until(deadline: f64) -> Result<std::time::Duration, Error> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(Error::msg)?
+        .as_secs_f64();
+    let remaining = deadline - now;
+    if remaining.is_nan() || remaining <= 0.0 {
+        return Err(Error::DeadlineExceeded);
+    }
+    std::time::Duration::try_from_secs_f64(remaining).map_err(Error::msg)
+}
+
+/// Raw grpc client type wrapped by [`Client`]
+pub type GrpcClient = GrpcInferenceServiceClient<InterceptedService<Channel, AuthInterceptor>>;
+
+/// How [`Client`] picks a server for each call.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Strategy {
+    #[default]
+    RoundRobin,
+    Random,
+}
+
+impl std::str::FromStr for Strategy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "round_robin" => Ok(Strategy::RoundRobin),
+            "random" => Ok(Strategy::Random),
+            _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "unknown strategy {:?}, expected \"round_robin\" or \"random\"",
+                s
+            ))
+            .into()),
+        }
+    }
+}
+
+/// HTTP/2 settings of every channel; `None` keeps the tonic default.
+#[derive(Debug, Clone, Copy, Default)]
+struct Http2Config {
+    initial_stream_window_size: Option<u32>,
+    max_frame_size: Option<u32>,
+    keep_alive_while_idle: bool,
+    keep_alive_interval: Option<std::time::Duration>,
+}
+
+/// Keep-alive ping interval used by `http2_keep_alive_while_idle` unless one is configured.
+const DEFAULT_KEEP_ALIVE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Contents of the PEM file named by env var `var`, or `None` if it is unset or empty.
+fn read_pem_env(var: &str) -> Result<Option<Vec<u8>>, Error> {
+    match std::env::var_os(var) {
+        Some(path) if !path.is_empty() => std::fs::read(&path)
+            .map(Some)
+            .map_err(|e| Error::msg(format!("cannot read {}={:?}: {}", var, path, e))),
+        _ => Ok(None),
+    }
+}
+
+/// TLS settings from `TRITON_TLS_CA_CERT` and, for mutual TLS, `TRITON_TLS_CLIENT_CERT` with
+/// `TRITON_TLS_CLIENT_KEY`; `None` when none of them is set.
+fn tls_from_env() -> Result<Option<ClientTlsConfig>, Error> {
+    let ca = read_pem_env("TRITON_TLS_CA_CERT")?;
+    let cert = read_pem_env("TRITON_TLS_CLIENT_CERT")?;
+    let key = read_pem_env("TRITON_TLS_CLIENT_KEY")?;
+    if ca.is_none() && cert.is_none() && key.is_none() {
+        return Ok(None);
+    }
+    let mut tls = ClientTlsConfig::new();
+    if let Some(ca) = ca {
+        tls = tls.ca_certificate(Certificate::from_pem(ca));
+    }
+    match (cert, key) {
+        (Some(cert), Some(key)) => tls = tls.identity(Identity::from_pem(cert, key)),
+        (None, None) => {}
+        _ => {
+            return Err(Error::msg(
+                "TRITON_TLS_CLIENT_CERT and TRITON_TLS_CLIENT_KEY must be set together",
+            ));
+        }
+    }
+    Ok(Some(tls))
+}
+
+/// Connect to a single server; `lazy` defers the connection to the first call.
+///
+/// `tls` takes precedence over the default TLS config enabled by `access_token`.
+fn connect(
+    url: &str,
+    access_token: Option<&str>,
+    tls: Option<&ClientTlsConfig>,
+    lazy: bool,
+    http2: Http2Config,
+    runtime: &Runtime,
+) -> Result<GrpcClient, Error> {
+    let url = url.parse::<http::Uri>()?;
+    runtime.block_on(async {
+        let mut channel = Channel::builder(url)
+            .initial_stream_window_size(http2.initial_stream_window_size)
+            .max_frame_size(http2.max_frame_size)
+            .keep_alive_while_idle(http2.keep_alive_while_idle);
+        // hyper only sends keep-alive pings once an interval is set
+        let keep_alive_interval = http2.keep_alive_interval.or(http2
+            .keep_alive_while_idle
+            .then_some(DEFAULT_KEEP_ALIVE_INTERVAL));
+        if let Some(interval) = keep_alive_interval {
+            channel = channel.http2_keep_alive_interval(interval);
+        }
+        if let Some(tls) = tls {
+            channel = channel.tls_config(tls.clone())?;
+        } else if access_token.is_some() {
+            channel = channel.tls_config(ClientTlsConfig::new())?;
+        }
+        let channel = if lazy {
+            channel.connect_lazy()
+        } else {
+            channel.connect().await?
+        };
+        Ok(GrpcInferenceServiceClient::with_interceptor(
+            channel,
+            AuthInterceptor::create(access_token)?,
+        ))
+    })
+}
+
+/// Builds a [`Client`] over one or more servers.
+///
+/// ```python
+/// client = ClientBuilder().servers(["http://a:8001", "http://b:8001"]).strategy("random").build()
+/// ```
+#[pyo3::pyclass(module = "triton_client")]
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    urls: Vec<String>,
+    access_token: Option<String>,
+    strategy: Strategy,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    log_requests: bool,
+    max_request_size_bytes: Option<usize>,
+    http2: Http2Config,
+    runtime: Option<Runtime>,
+    interceptor: Option<Arc<Py<pyo3::PyAny>>>,
+}
+
+#[pyo3::pymethods]
+impl ClientBuilder {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[doc = "Servers to send requests to."]
+    pub fn servers(mut slf: PyRefMut<'_, Self>, urls: Vec<String>) -> PyRefMut<'_, Self> {
+        slf.urls = urls;
+        slf
+    }
+
+    #[doc = "Server selection strategy, `\"round_robin\"` (default) or `\"random\"`."]
+    pub fn strategy<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        strategy: &str,
+    ) -> Result<PyRefMut<'py, Self>, Error> {
+        slf.strategy = strategy.parse()?;
+        Ok(slf)
+    }
+
+    #[doc = "Bearer token sent with every request; enables TLS. An `authorization` entry in the"]
+    #[doc = "`headers` of a call replaces it for that call."]
+    #[pyo3(signature = (token=None))]
+    pub fn access_token(mut slf: PyRefMut<'_, Self>, token: Option<String>) -> PyRefMut<'_, Self> {
+        slf.access_token = token;
+        slf
+    }
+
+    #[doc = "Stop calling a server for `reset_timeout_secs` after `failure_threshold` consecutive"]
+    #[doc = "`UNAVAILABLE` failures; then a single probe call decides whether to resume."]
+    pub fn circuit_breaker(
+        mut slf: PyRefMut<'_, Self>,
+        failure_threshold: u32,
+        reset_timeout_secs: u64,
+    ) -> Result<PyRefMut<'_, Self>, Error> {
+        if failure_threshold == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "failure_threshold must be greater than 0",
+            )
+            .into());
+        }
+        slf.circuit_breaker = Some(CircuitBreakerConfig {
+            failure_threshold,
+            reset_timeout: std::time::Duration::from_secs(reset_timeout_secs),
+        });
+        Ok(slf)
+    }
+
+    #[doc = "Log every request and response at `DEBUG` level; also enabled by `LOG_REQUESTS=1`."]
+    #[pyo3(signature = (enabled=true))]
+    pub fn log_requests(mut slf: PyRefMut<'_, Self>, enabled: bool) -> PyRefMut<'_, Self> {
+        slf.log_requests = enabled;
+        slf
+    }
+
+    #[doc = "Reject `model_infer` requests whose `estimated_wire_size()` exceeds `size` bytes with"]
+    #[doc = "`ValueError` instead of sending them; `None` disables the check."]
+    #[pyo3(signature = (size=None))]
+    pub fn max_request_size_bytes(
+        mut slf: PyRefMut<'_, Self>,
+        size: Option<usize>,
+    ) -> PyRefMut<'_, Self> {
+        slf.max_request_size_bytes = size;
+        slf
+    }
+
+    #[doc = "HTTP/2 flow control window per stream, in bytes. The 64 KiB default throttles large"]
+    #[doc = "tensors; 16 MiB (`16 * 1024 * 1024`) suits payloads of 1 MB and more."]
+    pub fn http2_initial_stream_window_size(
+        mut slf: PyRefMut<'_, Self>,
+        size: u32,
+    ) -> Result<PyRefMut<'_, Self>, Error> {
+        // HTTP/2 caps flow control windows at 2^31 - 1
+        if size > i32::MAX as u32 {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "window size must be at most {}",
+                i32::MAX
+            ))
+            .into());
+        }
+        slf.http2.initial_stream_window_size = Some(size);
+        Ok(slf)
+    }
+
+    #[doc = "Largest HTTP/2 frame accepted, in bytes, between 16 KiB and 16 MiB - 1; e.g."]
+    #[doc = "1 MiB to cut per-frame overhead for large tensors."]
+    pub fn http2_max_frame_size(
+        mut slf: PyRefMut<'_, Self>,
+        size: u32,
+    ) -> Result<PyRefMut<'_, Self>, Error> {
+        if !(1 << 14..1 << 24).contains(&size) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "max frame size must be between {} and {}, got {}",
+                1 << 14,
+                (1 << 24) - 1,
+                size
+            ))
+            .into());
+        }
+        slf.http2.max_frame_size = Some(size);
+        Ok(slf)
+    }
+
+    #[doc = "Keep sending HTTP/2 keep-alive pings while no call is in flight, so proxies and"]
+    #[doc = "load balancers do not drop idle connections. Pings are sent every 30 seconds unless"]
+    #[doc = "`http2_keep_alive_interval` is set."]
+    #[pyo3(signature = (enabled=true))]
+    pub fn http2_keep_alive_while_idle(
+        mut slf: PyRefMut<'_, Self>,
+        enabled: bool,
+    ) -> PyRefMut<'_, Self> {
+        slf.http2.keep_alive_while_idle = enabled;
+        slf
+    }
+
+    #[doc = "Send an HTTP/2 keep-alive ping every `interval_secs` seconds, e.g. below the idle"]
+    #[doc = "timeout of a proxy; only while calls are in flight unless `http2_keep_alive_while_idle`."]
+    pub fn http2_keep_alive_interval(
+        mut slf: PyRefMut<'_, Self>,
+        interval_secs: u64,
+    ) -> Result<PyRefMut<'_, Self>, Error> {
+        if interval_secs == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "interval_secs must be greater than 0",
+            )
+            .into());
+        }
+        slf.http2.keep_alive_interval = Some(std::time::Duration::from_secs(interval_secs));
+        Ok(slf)
+    }
+
+    #[doc = "Runtime running the client's calls, instead of the shared one."]
+    pub fn runtime(mut slf: PyRefMut<'_, Self>, runtime: Runtime) -> PyRefMut<'_, Self> {
+        slf.runtime = Some(runtime);
+        slf
+    }
+
+    #[doc = "Call `f(method, metadata)` before each gRPC request, e.g. to refresh auth tokens or"]
+    #[doc = "sign requests. `method` is the snake_case method name (`\"model_infer\"`), `metadata`"]
+    #[doc = "a dict of the request headers; the returned dict is merged into them."]
+    #[doc = ""]
+    #[doc = "`f` runs on the calling thread before the request is sent, and exceptions it raises"]
+    #[doc = "propagate unchanged. `model_ready_stream` calls it once, when the stream is created."]
+    pub fn interceptor<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        f: pyo3::Bound<'py, pyo3::PyAny>,
+    ) -> Result<PyRefMut<'py, Self>, Error> {
+        if !f.is_callable() {
+            return Err(
+                pyo3::exceptions::PyTypeError::new_err("interceptor must be callable").into(),
+            );
+        }
+        slf.interceptor = Some(Arc::new(f.unbind()));
+        Ok(slf)
+    }
+
+    #[doc = "Connect and build the client."]
+    #[doc = ""]
+    #[doc = "A single server is connected eagerly; with several servers connections are made on"]
+    #[doc = "first use, so an unreachable server does not prevent construction."]
+    #[doc = ""]
+    #[doc = "TLS is configured from the `TRITON_TLS_CA_CERT`, `TRITON_TLS_CLIENT_CERT` and"]
+    #[doc = "`TRITON_TLS_CLIENT_KEY` PEM file env vars when set."]
+    pub fn build(&self) -> Result<Client, Error> {
+        if self.urls.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "at least one server url is required",
+            )
+            .into());
+        }
+        let lazy = self.urls.len() > 1;
+        let runtime = match &self.runtime {
+            Some(runtime) => runtime.clone(),
+            None => crate::runtime::get_runtime()?,
+        };
+        let tls = tls_from_env()?;
+        let clients = self
+            .urls
+            .iter()
+            .map(|url| {
+                connect(
+                    url,
+                    self.access_token.as_deref(),
+                    tls.as_ref(),
+                    lazy,
+                    self.http2,
+                    &runtime,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Client {
+            inner: Arc::new(Mutex::new(Some(clients))),
+            mock: None,
+            urls: self.urls.clone(),
+            strategy: self.strategy,
+            next: Arc::new(AtomicUsize::new(0)),
+            circuits: self
+                .circuit_breaker
+                .map(|config| self.urls.iter().map(|_| Circuit::new(config)).collect())
+                .unwrap_or_default(),
+            log_requests: self.log_requests || log_requests_from_env(),
+            max_request_size_bytes: self.max_request_size_bytes,
+            runtime,
+            server_metadata_cache: Default::default(),
+            model_metadata_cache: Default::default(),
+            interceptor: self.interceptor.clone(),
+        })
+    }
+}
+
+/// Whether `LOG_REQUESTS` is set to a truthy value (`1`, `true`, `yes`).
+fn log_requests_from_env() -> bool {
+    std::env::var("LOG_REQUESTS").is_ok_and(|value| {
+        matches!(
+            value.trim().to_ascii_lowercase().as_str(),
+            "1" | "true" | "yes"
+        )
+    })
+}
+
 /// Triton Client
 #[pyo3::pyclass(module = "triton_client")]
 #[derive(Debug, Clone)]
 pub struct Client {
-    /// Raw grpc client interfaces automatically generated by tonic
+    /// Raw grpc client interfaces automatically generated by tonic, one per server
+    ///
+    /// Should not necessary to use this interface directly in most cases.
+    /// `None` once the client has been closed; clones share the same slot.
+    pub inner: Arc<Mutex<Option<Vec<GrpcClient>>>>,
+    /// Set for clients created by `Client.mock`; calls never reach the network.
+    mock: Option<Arc<Mock>>,
+    /// Server urls, in the same order as `inner`.
+    urls: Vec<String>,
+    strategy: Strategy,
+    /// Round-robin cursor shared by clones.
+    next: Arc<AtomicUsize>,
+    /// One circuit breaker per server; empty when disabled.
+    circuits: Vec<Circuit>,
+    /// Log requests and responses at `DEBUG` level.
+    log_requests: bool,
+    /// Largest `model_infer` request sent, by `ModelInferRequest::estimated_wire_size`.
+    max_request_size_bytes: Option<usize>,
+    /// Runtime the calls are run on; the shared one unless given explicitly.
+    runtime: Runtime,
+    /// Responses of `server_metadata_cached`, shared by clones.
+    server_metadata_cache: TtlCache<(), inference::ServerMetadataResponse>,
+    /// Responses of `model_metadata_cached` by `(name, version)`, shared by clones.
+    model_metadata_cache: TtlCache<(String, String), inference::ModelMetadataResponse>,
+    /// `(method, metadata) -> dict` callable set by `ClientBuilder.interceptor`.
+    interceptor: Option<Arc<Py<pyo3::PyAny>>>,
+}
+
+impl Client {
+    fn lock_inner(&self) -> MutexGuard<'_, Option<Vec<GrpcClient>>> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Index of the server to use for the next call.
+    fn next_index(&self) -> usize {
+        let n = self.urls.len().max(1);
+        match self.strategy {
+            Strategy::RoundRobin => self.next.fetch_add(1, Ordering::Relaxed) % n,
+            Strategy::Random => {
+                let seed = self.next.fetch_add(1, Ordering::Relaxed);
+                RandomState::new().hash_one(seed) as usize % n
+            }
+        }
+    }
+
+    /// Clone the raw grpc client of server `index`, failing fast if the client has been closed.
+    fn server(&self, index: usize) -> Result<GrpcClient, Error> {
+        self.lock_inner()
+            .as_ref()
+            .and_then(|clients| clients.get(index).cloned())
+            .ok_or(Error::ClientClosed)
+    }
+
+    /// Pick the next server whose circuit allows a call, other than `skip`.
+    fn pick(&self, skip: Option<usize>) -> Result<(usize, GrpcClient), Error> {
+        let start = self.next_index();
+        let n = self.urls.len();
+        for i in (0..n).map(|offset| (start + offset) % n) {
+            if Some(i) == skip {
+                continue;
+            }
+            if self.circuits.is_empty() || self.circuits[i].allow() {
+                return Ok((i, self.server(i)?));
+            }
+        }
+        if self.lock_inner().is_none() {
+            return Err(Error::ClientClosed);
+        }
+        Err(Error::CircuitOpen)
+    }
+
+    /// Copy of `req` to resend on another server after `UNAVAILABLE`; `None` with a single server.
+    fn retry_copy<T: Clone>(
+        &self,
+        req: &tonic::Request<T>,
+    ) -> Option<(tonic::metadata::MetadataMap, T)> {
+        (self.urls.len() > 1).then(|| (req.metadata().clone(), req.get_ref().clone()))
+    }
+
+    /// Feed a call result of server `index` to its circuit breaker.
+    fn record<T>(&self, index: usize, result: &Result<T, Status>) {
+        if let Some(circuit) = self.circuits.get(index) {
+            match result {
+                Err(status) if status.code() == tonic::Code::Unavailable => {
+                    circuit.record_failure()
+                }
+                _ => circuit.record_success(),
+            }
+        }
+    }
+
+    /// Clone the raw grpc client of the next server, failing fast if the client has been closed.
+    pub fn grpc_client(&self) -> Result<GrpcClient, Error> {
+        self.pick(None).map(|(_, client)| client)
+    }
+
+    /// Pass the metadata of `req` to the `ClientBuilder.interceptor` callable, if any, and merge
+    /// the headers it returns. Runs before `block_on`, so the callable gets the caller's GIL.
+    fn intercept<T>(&self, method: &str, req: &mut tonic::Request<T>) -> Result<(), Error> {
+        let Some(interceptor) = &self.interceptor else {
+            return Ok(());
+        };
+        let metadata: HashMap<String, String> = req
+            .metadata()
+            .iter()
+            .filter_map(|entry| match entry {
+                KeyAndValueRef::Ascii(key, value) => value
+                    .to_str()
+                    .ok()
+                    .map(|value| (key.as_str().to_string(), value.to_string())),
+                KeyAndValueRef::Binary(..) => None,
+            })
+            .collect();
+        let headers: HashMap<String, String> = pyo3::Python::attach(|py| {
+            interceptor
+                .bind(py)
+                .call1((method, metadata))?
+                .extract::<HashMap<String, String>>()
+        })?;
+        insert_headers(req.metadata_mut(), &headers)
+    }
+
+    /// Run a unary gRPC call on the shared runtime, recording metrics when enabled.
     ///
-    /// Should not necessary to use this interface directly in most cases
-    pub inner: GrpcInferenceServiceClient<InterceptedService<Channel, AuthInterceptor>>,
+    /// With several servers, a call failing with `UNAVAILABLE` is retried once on the next
+    /// server; the request is cloned up front for that.
+    #[cfg_attr(not(feature = "prometheus"), allow(unused_variables))]
+    fn call<Req, Resp, F, Fut>(
+        &self,
+        method: &'static str,
+        mut req: tonic::Request<Req>,
+        f: F,
+    ) -> Result<Resp, Error>
+    where
+        Req: prost::Message + Clone + std::fmt::Debug + for<'py> pyo3::IntoPyObject<'py>,
+        Resp: prost::Message + pyo3::PyClass + Clone + std::fmt::Debug,
+        F: Fn(GrpcClient, tonic::Request<Req>) -> Fut,
+        Fut: Future<Output = Result<tonic::Response<Resp>, Status>>,
+    {
+        self.intercept(method, &mut req)?;
+        if self.log_requests {
+            log::debug!("{} request: {:?}", method, req.get_ref());
+        }
+        if let Some(mock) = &self.mock {
+            let result = mock.respond(method, req.into_inner());
+            if self.log_requests {
+                log::debug!("{} response: {:?}", method, &result);
+            }
+            return result;
+        }
+        let (index, inner) = self.pick(None)?;
+        let retry = self.retry_copy(&req);
+        // each attempt is observed separately, labelled with the server it went to
+        let attempt = |index: usize, inner: GrpcClient, req: tonic::Request<Req>| {
+            #[cfg(feature = "prometheus")]
+            let (start, sent) = (
+                std::time::Instant::now(),
+                prost::Message::encoded_len(req.get_ref()),
+            );
+            let result = self.runtime.block_on(f(inner, req));
+            #[cfg(feature = "prometheus")]
+            crate::metrics::observe(method, &self.urls[index], start, sent, &result);
+            self.record(index, &result);
+            result
+        };
+        let mut result = attempt(index, inner, req);
+        if let (Err(status), Some((metadata, message))) = (&result, retry)
+            && status.code() == tonic::Code::Unavailable
+            && let Ok((next, inner)) = self.pick(Some(index))
+        {
+            log::warn!(
+                "{} failed on {}: {}, retrying on {}",
+                method,
+                self.urls[index],
+                status.message(),
+                self.urls[next]
+            );
+            let req = tonic::Request::from_parts(metadata, Default::default(), message);
+            result = attempt(next, inner, req);
+        }
+        if self.log_requests {
+            log::debug!(
+                "{} response: {:?}",
+                method,
+                result.as_ref().map(tonic::Response::get_ref)
+            );
+        }
+        Ok(result?.into_inner())
+    }
+
+    /// Run `model_infer` for each `(server index, client, request)` concurrently on the shared
+    /// runtime, feeding the circuit breakers and returning the results in input order.
+    #[cfg_attr(not(feature = "prometheus"), allow(unused_variables))]
+    fn infer_concurrently(
+        &self,
+        calls: Vec<(
+            usize,
+            GrpcClient,
+            tonic::Request<inference::ModelInferRequest>,
+        )>,
+    ) -> Vec<InferResult> {
+        let indices: Vec<usize> = calls.iter().map(|(index, ..)| *index).collect();
+        let results = self.runtime.block_on(async {
+            let handles = calls.into_iter().map(|(index, mut inner, req)| {
+                #[cfg(feature = "prometheus")]
+                let server = self.urls[index].clone();
+                tokio::spawn(async move {
+                    #[cfg(feature = "prometheus")]
+                    let (start, sent) = (
+                        std::time::Instant::now(),
+                        prost::Message::encoded_len(req.get_ref()),
+                    );
+                    let result = inner.model_infer(req).await;
+                    #[cfg(feature = "prometheus")]
+                    crate::metrics::observe("model_infer_many", &server, start, sent, &result);
+                    result
+                })
+            });
+            futures::future::join_all(handles).await
+        });
+        for (index, result) in indices.into_iter().zip(&results) {
+            if let Ok(result) = result {
+                self.record(index, result);
+            }
+        }
+        results
+    }
+}
+
+/// Outcome of one spawned `model_infer` call of [`Client::model_infer_many`].
+type InferResult =
+    Result<Result<tonic::Response<inference::ModelInferResponse>, Status>, tokio::task::JoinError>;
+
+/// Iterator returned by [`Client::model_ready_stream`], yielding one readiness result per poll.
+///
+/// Polling runs in a background task; dropping the stream stops it.
+#[pyo3::pyclass(module = "triton_client")]
+pub struct ModelReadyStream {
+    rx: tokio::sync::mpsc::Receiver<bool>,
 }
 
 #[pyo3::pymethods]
+impl ModelReadyStream {
+    fn __iter__(slf: pyo3::PyRef<'_, Self>) -> pyo3::PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: pyo3::Python<'_>) -> Option<bool> {
+        py.detach(|| self.rx.blocking_recv())
+    }
+}
+
 impl Client {
-    #[new]
-    pub fn new(url: &str, access_token: Option<String>) -> Result<Self, Error> {
-        let url = url.parse::<http::Uri>()?;
-        let client = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async {
-                let mut channel = Channel::builder(url);
-                if access_token.is_some() {
-                    channel = channel.tls_config(ClientTlsConfig::new())?;
+    /// Poll readiness of a model once, sending `metadata`; any error counts as not ready.
+    async fn poll_model_ready(
+        &self,
+        req: inference::ModelReadyRequest,
+        metadata: tonic::metadata::MetadataMap,
+    ) -> bool {
+        if let Some(mock) = &self.mock {
+            return mock
+                .respond::<_, inference::ModelReadyResponse>("model_ready", req)
+                .is_ok_and(|resp| resp.ready);
+        }
+        let Ok((index, mut inner)) = self.pick(None) else {
+            return false;
+        };
+        let req = tonic::Request::from_parts(metadata, Default::default(), req);
+        let result = inner.model_ready(req).await;
+        self.record(index, &result);
+        result.is_ok_and(|resp| resp.into_inner().ready)
+    }
+}
+
+impl Client {
+    /// Check liveness, readiness and optionally a model's readiness on one server concurrently.
+    fn ping_server(
+        &self,
+        model: Option<inference::ModelReadyRequest>,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<bool, Error> {
+        if self.mock.is_some() {
+            let model_ready = match model {
+                Some(req) => self.model_ready(req, headers.clone())?.ready,
+                None => true,
+            };
+            return Ok(self.server_live(headers.clone())?.live
+                && self.server_ready(headers)?.ready
+                && model_ready);
+        }
+        let mut live_req = new_request(inference::ServerLiveRequest::default(), headers.as_ref())?;
+        self.intercept("server_live", &mut live_req)?;
+        let mut ready_req =
+            new_request(inference::ServerReadyRequest::default(), headers.as_ref())?;
+        self.intercept("server_ready", &mut ready_req)?;
+        let model_req = match model {
+            Some(req) => {
+                let mut req = new_request(req, headers.as_ref())?;
+                self.intercept("model_ready", &mut req)?;
+                Some(req)
+            }
+            None => None,
+        };
+        let (index, inner) = self.pick(None)?;
+        let (mut live_client, mut ready_client, mut model_client) =
+            (inner.clone(), inner.clone(), inner);
+        let (live, ready, model_ready) = self.runtime.block_on(async {
+            tokio::join!(
+                live_client.server_live(live_req),
+                ready_client.server_ready(ready_req),
+                async {
+                    match model_req {
+                        Some(req) => model_client.model_ready(req).await.map(Some),
+                        None => Ok(None),
+                    }
                 }
-                let channel = channel.connect().await?;
-                let client = GrpcInferenceServiceClient::with_interceptor(
-                    channel,
-                    AuthInterceptor::create(access_token.as_deref())?,
+            )
+        });
+        self.record(index, &live);
+        self.record(index, &ready);
+        self.record(index, &model_ready);
+        Ok(live?.into_inner().live
+            && ready?.into_inner().ready
+            && model_ready?.is_none_or(|resp| resp.into_inner().ready))
+    }
+
+    /// `ttl_secs` as a cache lifetime; raises `ValueError` if negative or not finite.
+    fn cache_ttl(ttl_secs: f64) -> Result<std::time::Duration, Error> {
+        std::time::Duration::try_from_secs_f64(ttl_secs).map_err(|_| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "ttl_secs must be a non-negative number, got {}",
+                ttl_secs
+            ))
+            .into()
+        })
+    }
+
+    /// Fail with `ValueError` if `req` is larger than `max_request_size_bytes`.
+    fn check_request_size(&self, req: &inference::ModelInferRequest) -> Result<(), Error> {
+        let Some(limit) = self.max_request_size_bytes else {
+            return Ok(());
+        };
+        let size = req.estimated_wire_size();
+        if size > limit {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "request size {} bytes exceeds max_request_size_bytes {}",
+                size, limit
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Model names from the repository index, optionally only the ready ones.
+    fn list_models(
+        &self,
+        ready: bool,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<Vec<String>, Error> {
+        let req = inference::RepositoryIndexRequest {
+            repository_name: String::new(),
+            ready,
+        };
+        let resp = self.repository_index(req, headers)?;
+        Ok(resp.models.into_iter().map(|m| m.name).collect())
+    }
+
+    /// Connect to a single server.
+    pub fn new(url: &str, access_token: Option<String>) -> Result<Self, Error> {
+        ClientBuilder {
+            urls: vec![url.to_string()],
+            access_token,
+            ..Default::default()
+        }
+        .build()
+    }
+}
+
+#[pyo3::pymethods]
+impl Client {
+    #[doc = "Connect to `url`, or to a list of urls used round-robin (see `ClientBuilder`)."]
+    #[doc = ""]
+    #[doc = "Calls run on `runtime`, or on the shared runtime configured by `init_runtime`."]
+    #[new]
+    #[pyo3(signature = (url, access_token=None, runtime=None))]
+    fn py_new(
+        url: &pyo3::Bound<'_, pyo3::PyAny>,
+        access_token: Option<String>,
+        runtime: Option<Runtime>,
+    ) -> Result<Self, Error> {
+        let urls = if let Ok(url) = url.cast::<PyString>() {
+            vec![url.to_str()?.to_string()]
+        } else if let Ok(urls) = url.cast::<PyList>() {
+            if urls.is_empty() {
+                return Err(
+                    pyo3::exceptions::PyValueError::new_err("url list must not be empty").into(),
                 );
-                Ok::<_, Error>(client)
-            })?;
-        Ok(Client { inner: client })
+            }
+            urls.extract()?
+        } else {
+            return Err(pyo3::exceptions::PyTypeError::new_err(
+                "url must be a str or a list of str",
+            )
+            .into());
+        };
+        ClientBuilder {
+            urls,
+            access_token,
+            runtime,
+            ..Default::default()
+        }
+        .build()
+    }
+
+    #[doc = "Create a client answering calls from `responses` instead of a server."]
+    #[doc = ""]
+    #[doc = "`responses` maps method names (e.g. `\"model_infer\"`) to a response object or to a"]
+    #[doc = "callable receiving the request. Mock clients ignore `close()`."]
+    #[staticmethod]
+    pub fn mock(responses: HashMap<String, Py<pyo3::PyAny>>) -> Result<Self, Error> {
+        Ok(Client {
+            inner: Arc::new(Mutex::new(None)),
+            mock: Some(Arc::new(Mock::new(responses))),
+            urls: vec!["mock".to_string()],
+            strategy: Strategy::default(),
+            next: Arc::new(AtomicUsize::new(0)),
+            circuits: vec![],
+            log_requests: log_requests_from_env(),
+            max_request_size_bytes: None,
+            runtime: crate::runtime::get_runtime()?,
+            server_metadata_cache: Default::default(),
+            model_metadata_cache: Default::default(),
+            interceptor: None,
+        })
+    }
+
+    #[doc = "Requests received by a mock client for `method`, oldest first."]
+    pub fn calls_to(
+        &self,
+        py: pyo3::Python<'_>,
+        method: &str,
+    ) -> Result<Vec<Py<pyo3::PyAny>>, Error> {
+        let mock = self
+            .mock
+            .as_ref()
+            .ok_or_else(|| Error::msg("calls_to is only available on mock clients"))?;
+        Ok(mock.calls_to(py, method))
+    }
+
+    #[doc = "Close the client and release the underlying gRPC channel."]
+    pub fn close(&self) {
+        self.lock_inner().take();
+    }
+
+    #[doc = "Whether the client has been closed."]
+    pub fn is_closed(&self) -> bool {
+        self.mock.is_none() && self.lock_inner().is_none()
+    }
+
+    fn __repr__(&self) -> String {
+        let connected = if self.is_closed() { "False" } else { "True" };
+        match self.urls.as_slice() {
+            [url] => format!("Client(url={:?}, connected={})", url, connected),
+            urls => format!("Client(urls={:?}, connected={})", urls, connected),
+        }
     }
 
     #[doc = "Check liveness of the inference server."]
     #[inline(always)]
-    pub fn server_live(&self) -> Result<inference::ServerLiveResponse, Error> {
+    #[pyo3(signature = (headers=None))]
+    pub fn server_live(
+        &self,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<inference::ServerLiveResponse, Error> {
         let req: inference::ServerLiveRequest = Default::default();
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async { inner.server_live(tonic::Request::new(req)).await })?;
-        Ok(response.into_inner())
+        call_span!("triton.server_live");
+        let req = new_request(req, headers.as_ref())?;
+        self.call("server_live", req, |mut inner, req| async move {
+            inner.server_live(req).await
+        })
     }
 
     #[doc = "Check readiness of the inference server."]
     #[inline(always)]
-    pub fn server_ready(&self) -> Result<inference::ServerReadyResponse, Error> {
+    #[pyo3(signature = (headers=None))]
+    pub fn server_ready(
+        &self,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<inference::ServerReadyResponse, Error> {
         let req: inference::ServerReadyRequest = Default::default();
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async { inner.server_ready(tonic::Request::new(req)).await })?;
-        Ok(response.into_inner())
+        call_span!("triton.server_ready");
+        let req = new_request(req, headers.as_ref())?;
+        self.call("server_ready", req, |mut inner, req| async move {
+            inner.server_ready(req).await
+        })
     }
     #[doc = "Check readiness of a model in the inference server."]
     #[inline(always)]
+    #[pyo3(signature = (req, headers=None))]
     pub fn model_ready(
         &self,
         req: inference::ModelReadyRequest,
+        headers: Option<HashMap<String, String>>,
     ) -> Result<inference::ModelReadyResponse, Error> {
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async { inner.model_ready(tonic::Request::new(req)).await })?;
-        Ok(response.into_inner())
+        call_span!("triton.model_ready");
+        let req = new_request(req, headers.as_ref())?;
+        self.call("model_ready", req, |mut inner, req| async move {
+            inner.model_ready(req).await
+        })
+    }
+    #[doc = "Poll readiness of a model every `interval_ms`, yielding `True` or `False` per check."]
+    #[doc = ""]
+    #[doc = "Iterates until the caller stops, or after `max_polls` checks when given."]
+    #[doc = "A `ClientBuilder.interceptor` runs once, and its headers are sent with every check."]
+    #[pyo3(signature = (model_name, version=String::new(), interval_ms=1000, max_polls=None))]
+    pub fn model_ready_stream(
+        &self,
+        model_name: String,
+        version: String,
+        interval_ms: u64,
+        max_polls: Option<u32>,
+    ) -> Result<ModelReadyStream, Error> {
+        let (tx, rx) = tokio::sync::mpsc::channel(1);
+        let client = self.clone();
+        let req = inference::ModelReadyRequest {
+            name: model_name,
+            version,
+        };
+        let interval = std::time::Duration::from_millis(interval_ms);
+        // Polls run on worker threads without the GIL, so the interceptor runs here
+        let mut template = tonic::Request::new(());
+        self.intercept("model_ready", &mut template)?;
+        let metadata = template.into_parts().0;
+        self.runtime.spawn(async move {
+            let mut polls = 0u32;
+            while max_polls.is_none_or(|max| polls < max) {
+                if polls > 0 {
+                    tokio::time::sleep(interval).await;
+                }
+                let ready = client.poll_model_ready(req.clone(), metadata.clone()).await;
+                if tx.send(ready).await.is_err() {
+                    break;
+                }
+                polls += 1;
+            }
+        });
+        Ok(ModelReadyStream { rx })
+    }
+    #[doc = "Whether the server is both live and ready; the two checks run concurrently."]
+    #[pyo3(signature = (headers=None))]
+    pub fn ping(&self, headers: Option<HashMap<String, String>>) -> Result<bool, Error> {
+        call_span!("triton.ping");
+        self.ping_server(None, headers)
+    }
+    #[doc = "Like `ping`, additionally requiring model `model_name` to be ready."]
+    #[pyo3(signature = (model_name, version=String::new(), headers=None))]
+    pub fn ping_with_model(
+        &self,
+        model_name: String,
+        version: String,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<bool, Error> {
+        call_span!("triton.ping_with_model", model_name = %model_name);
+        let req = inference::ModelReadyRequest {
+            name: model_name,
+            version,
+        };
+        self.ping_server(Some(req), headers)
     }
     #[doc = "Get server metadata."]
     #[inline(always)]
-    pub fn server_metadata(&self) -> Result<inference::ServerMetadataResponse, Error> {
+    #[pyo3(signature = (headers=None))]
+    pub fn server_metadata(
+        &self,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<inference::ServerMetadataResponse, Error> {
         let req: inference::ServerMetadataRequest = Default::default();
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async { inner.server_metadata(tonic::Request::new(req)).await })?;
-        Ok(response.into_inner())
+        call_span!("triton.server_metadata");
+        let req = new_request(req, headers.as_ref())?;
+        self.call("server_metadata", req, |mut inner, req| async move {
+            inner.server_metadata(req).await
+        })
+    }
+    #[doc = "Server metadata, fetched at most once per `ttl_secs` and shared by clones of the client."]
+    #[doc = ""]
+    #[doc = "Cached responses are returned whatever `headers` are passed."]
+    #[pyo3(signature = (ttl_secs=60.0, headers=None))]
+    pub fn server_metadata_cached(
+        &self,
+        ttl_secs: f64,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<inference::ServerMetadataResponse, Error> {
+        let ttl = Self::cache_ttl(ttl_secs)?;
+        if let Some(resp) = self.server_metadata_cache.get(&(), ttl) {
+            return Ok(resp);
+        }
+        let resp = self.server_metadata(headers)?;
+        self.server_metadata_cache.insert((), resp.clone());
+        Ok(resp)
+    }
+    #[doc = "Metadata of a model, fetched at most once per `ttl_secs` for each model and version."]
+    #[doc = ""]
+    #[doc = "Cached responses are returned whatever `headers` are passed."]
+    #[pyo3(signature = (model_name, version=String::new(), ttl_secs=60.0, headers=None))]
+    pub fn model_metadata_cached(
+        &self,
+        model_name: String,
+        version: String,
+        ttl_secs: f64,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<inference::ModelMetadataResponse, Error> {
+        let ttl = Self::cache_ttl(ttl_secs)?;
+        let key = (model_name, version);
+        if let Some(resp) = self.model_metadata_cache.get(&key, ttl) {
+            return Ok(resp);
+        }
+        let req = inference::ModelMetadataRequest {
+            name: key.0.clone(),
+            version: key.1.clone(),
+        };
+        let resp = self.model_metadata(req, headers)?;
+        self.model_metadata_cache.insert(key, resp.clone());
+        Ok(resp)
+    }
+    #[doc = "Drop the responses cached by `server_metadata_cached` and `model_metadata_cached`."]
+    pub fn invalidate_metadata_cache(&self) {
+        self.server_metadata_cache.clear();
+        self.model_metadata_cache.clear();
     }
     #[doc = "Get model metadata."]
     #[inline(always)]
+    #[pyo3(signature = (req, headers=None))]
     pub fn model_metadata(
         &self,
         req: inference::ModelMetadataRequest,
+        headers: Option<HashMap<String, String>>,
     ) -> Result<inference::ModelMetadataResponse, Error> {
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async { inner.model_metadata(tonic::Request::new(req)).await })?;
-        Ok(response.into_inner())
+        call_span!("triton.model_metadata");
+        let req = new_request(req, headers.as_ref())?;
+        self.call("model_metadata", req, |mut inner, req| async move {
+            inner.model_metadata(req).await
+        })
     }
     #[doc = "Perform inference using a specific model."]
+    #[doc = ""]
+    #[doc = "`deadline` is a Unix timestamp in seconds; a past deadline raises `TritonTimeoutError`"]
+    #[doc = "without sending the request. With `validate=True` the request is checked by"]
+    #[doc = "`ModelInferRequest.validate()` first and `ValueError` is raised on problems."]
+    #[doc = ""]
+    #[doc = "`grpc_timeout_ms` forwards a relative deadline, e.g. one propagated from an upstream"]
+    #[doc = "call; tonic sends it as the `grpc-timeout` header so the server enforces it too."]
+    #[doc = "With both given, the earlier of the two applies."]
+    #[doc = ""]
+    #[doc = "Requests larger than `ClientBuilder.max_request_size_bytes` raise `ValueError`."]
     #[inline(always)]
+    #[pyo3(signature = (req, headers=None, deadline=None, validate=false, grpc_timeout_ms=None))]
     pub fn model_infer(
         &self,
         req: pyo3::Bound<'_, pyo3::PyAny>,
+        headers: Option<HashMap<String, String>>,
+        deadline: Option<f64>,
+        validate: bool,
+        grpc_timeout_ms: Option<u64>,
     ) -> Result<inference::ModelInferResponse, Error> {
-        let req = req.extract::<inference::ModelInferRequest>().map_err(Error::msg)?;
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async { inner.model_infer(tonic::Request::new(req)).await })?;
-        Ok(response.into_inner())
+        let grpc_timeout = grpc_timeout_ms.map(std::time::Duration::from_millis);
+        let timeout = match (deadline.map(time_until).transpose()?, grpc_timeout) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        let req = req
+            .extract::<inference::ModelInferRequest>()
+            .map_err(Error::msg)?;
+        if validate {
+            let errors = req.validate();
+            if !errors.is_empty() {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "invalid request: {}",
+                    errors.join("; ")
+                ))
+                .into());
+            }
+        }
+        self.check_request_size(&req)?;
+        call_span!(
+            "triton.model_infer",
+            model_name = %req.model_name,
+            model_version = %req.model_version
+        );
+        let mut req = new_request(req, headers.as_ref())?;
+        if let Some(timeout) = timeout {
+            req.set_timeout(timeout);
+        }
+        self.call("model_infer", req, |mut inner, req| async move {
+            inner.model_infer(req).await
+        })
+        .map_err(|e| match e {
+            // tonic reports the client-side `grpc-timeout` expiry as `Cancelled`
+            Error::ResponseError(status)
+                if timeout.is_some() && status.code() == tonic::Code::Cancelled =>
+            {
+                Error::DeadlineExceeded
+            }
+            e => e,
+        })
+    }
+    #[doc = "Perform inference for multiple requests concurrently, returning responses in input order."]
+    #[doc = ""]
+    #[doc = "Like other calls, a request failing with `UNAVAILABLE` is retried once on the next server."]
+    #[pyo3(signature = (reqs, headers=None))]
+    pub fn model_infer_many(
+        &self,
+        reqs: Vec<inference::ModelInferRequest>,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<Vec<inference::ModelInferResponse>, Error> {
+        call_span!("triton.model_infer_many", count = reqs.len());
+        for req in &reqs {
+            self.check_request_size(req)?;
+        }
+        let reqs = reqs
+            .into_iter()
+            .map(|req| {
+                let mut req = new_request(req, headers.as_ref())?;
+                self.intercept("model_infer", &mut req)?;
+                Ok(req)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        if self.log_requests {
+            for (i, req) in reqs.iter().enumerate() {
+                log::debug!("model_infer_many request {}: {:?}", i, req.get_ref());
+            }
+        }
+        if let Some(mock) = &self.mock {
+            let results: Vec<Result<inference::ModelInferResponse, Error>> = reqs
+                .into_iter()
+                .map(|req| mock.respond("model_infer", req.into_inner()))
+                .collect();
+            if self.log_requests {
+                for (i, result) in results.iter().enumerate() {
+                    log::debug!("model_infer_many response {}: {:?}", i, result);
+                }
+            }
+            if results.iter().any(Result::is_err) {
+                return Err(Error::Batch(results.into_iter().map(Result::err).collect()));
+            }
+            return Ok(results.into_iter().flatten().collect());
+        }
+        let retries: Vec<_> = reqs.iter().map(|req| self.retry_copy(req)).collect();
+        let calls = reqs
+            .into_iter()
+            .map(|req| self.pick(None).map(|(index, inner)| (index, inner, req)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let indices: Vec<usize> = calls.iter().map(|(index, ..)| *index).collect();
+        let mut results = self.infer_concurrently(calls);
+        // Requests failing with UNAVAILABLE are retried once, together, on the next server
+        let mut retried = Vec::new();
+        let mut calls = Vec::new();
+        for (i, (result, retry)) in results.iter().zip(retries).enumerate() {
+            if let (Ok(Err(status)), Some((metadata, message))) = (result, retry)
+                && status.code() == tonic::Code::Unavailable
+                && let Ok((next, inner)) = self.pick(Some(indices[i]))
+            {
+                log::warn!(
+                    "model_infer_many request {} failed on {}: {}, retrying on {}",
+                    i,
+                    self.urls[indices[i]],
+                    status.message(),
+                    self.urls[next]
+                );
+                let req = tonic::Request::from_parts(metadata, Default::default(), message);
+                retried.push(i);
+                calls.push((next, inner, req));
+            }
+        }
+        if !calls.is_empty() {
+            for (i, result) in retried.into_iter().zip(self.infer_concurrently(calls)) {
+                results[i] = result;
+            }
+        }
+        let mut responses = Vec::with_capacity(results.len());
+        let mut errors = Vec::with_capacity(results.len());
+        for (i, result) in results.into_iter().enumerate() {
+            if self.log_requests
+                && let Ok(result) = &result
+            {
+                log::debug!(
+                    "model_infer_many response {}: {:?}",
+                    i,
+                    result.as_ref().map(tonic::Response::get_ref)
+                );
+            }
+            match result {
+                Ok(Ok(response)) => {
+                    responses.push(response.into_inner());
+                    errors.push(None);
+                }
+                Ok(Err(status)) => errors.push(Some(Error::from(status))),
+                Err(e) => errors.push(Some(Error::msg(e))),
+            }
+        }
+        if errors.iter().any(Option::is_some) {
+            return Err(Error::Batch(errors));
+        }
+        Ok(responses)
     }
     #[doc = "Get model configuration."]
     #[inline(always)]
+    #[pyo3(signature = (req, headers=None))]
     pub fn model_config(
         &self,
         req: inference::ModelConfigRequest,
+        headers: Option<HashMap<String, String>>,
     ) -> Result<inference::ModelConfigResponse, Error> {
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async { inner.model_config(tonic::Request::new(req)).await })?;
-        Ok(response.into_inner())
+        call_span!("triton.model_config");
+        let req = new_request(req, headers.as_ref())?;
+        self.call("model_config", req, |mut inner, req| async move {
+            inner.model_config(req).await
+        })
     }
     #[doc = "Get the cumulative inference statistics for a model."]
     #[inline(always)]
+    #[pyo3(signature = (req, headers=None))]
     pub fn model_statistics(
         &self,
         req: inference::ModelStatisticsRequest,
+        headers: Option<HashMap<String, String>>,
     ) -> Result<inference::ModelStatisticsResponse, Error> {
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async { inner.model_statistics(tonic::Request::new(req)).await })?;
-        Ok(response.into_inner())
+        call_span!("triton.model_statistics");
+        let req = new_request(req, headers.as_ref())?;
+        self.call("model_statistics", req, |mut inner, req| async move {
+            inner.model_statistics(req).await
+        })
     }
     #[doc = "Get the index of model repository contents."]
+    #[doc = ""]
+    #[doc = "`models` is a list of `ModelIndex`, e.g. `[m.name for m in resp.models]`."]
     #[inline(always)]
+    #[pyo3(signature = (req, headers=None))]
     pub fn repository_index(
         &self,
         req: inference::RepositoryIndexRequest,
+        headers: Option<HashMap<String, String>>,
     ) -> Result<inference::RepositoryIndexResponse, Error> {
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async { inner.repository_index(tonic::Request::new(req)).await })?;
-        Ok(response.into_inner())
+        call_span!("triton.repository_index");
+        let req = new_request(req, headers.as_ref())?;
+        self.call("repository_index", req, |mut inner, req| async move {
+            inner.repository_index(req).await
+        })
+    }
+    #[doc = "Names of the models in the repository that are ready for inference."]
+    #[pyo3(signature = (headers=None))]
+    pub fn list_ready_models(
+        &self,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<Vec<String>, Error> {
+        self.list_models(true, headers)
+    }
+    #[doc = "Names of all models in the repository, whatever their state."]
+    #[pyo3(signature = (headers=None))]
+    pub fn list_all_models(
+        &self,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<Vec<String>, Error> {
+        self.list_models(false, headers)
     }
     #[doc = "Load or reload a model from a repository."]
     #[inline(always)]
+    #[pyo3(signature = (req, headers=None))]
     pub fn repository_model_load(
         &self,
         req: inference::RepositoryModelLoadRequest,
+        headers: Option<HashMap<String, String>>,
     ) -> Result<inference::RepositoryModelLoadResponse, Error> {
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async { inner.repository_model_load(tonic::Request::new(req)).await })?;
-        Ok(response.into_inner())
+        call_span!("triton.repository_model_load");
+        let req = new_request(req, headers.as_ref())?;
+        self.call("repository_model_load", req, |mut inner, req| async move {
+            inner.repository_model_load(req).await
+        })
     }
     #[doc = "Unload a model."]
     #[inline(always)]
+    #[pyo3(signature = (req, headers=None))]
     pub fn repository_model_unload(
         &self,
         req: inference::RepositoryModelUnloadRequest,
+        headers: Option<HashMap<String, String>>,
     ) -> Result<inference::RepositoryModelUnloadResponse, Error> {
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async {
-                inner
-                    .repository_model_unload(tonic::Request::new(req))
-                    .await
-            })?;
-        Ok(response.into_inner())
+        call_span!("triton.repository_model_unload");
+        let req = new_request(req, headers.as_ref())?;
+        self.call(
+            "repository_model_unload",
+            req,
+            |mut inner, req| async move { inner.repository_model_unload(req).await },
+        )
+    }
+    #[doc = "Unload every ready model, returning the names of the unloaded models."]
+    #[doc = ""]
+    #[doc = "All unloads are attempted; if any fail, `TritonBatchError` holds one optional error"]
+    #[doc = "per model. With `dry_run=True` the models are only listed."]
+    #[pyo3(signature = (dry_run=false, headers=None))]
+    pub fn model_unload_all(
+        &self,
+        dry_run: bool,
+        headers: Option<HashMap<String, String>>,
+    ) -> Result<Vec<String>, Error> {
+        let names = self.list_models(true, headers.clone())?;
+        if dry_run {
+            return Ok(names);
+        }
+        let errors: Vec<Option<Error>> = names
+            .iter()
+            .map(|name| {
+                let req = inference::RepositoryModelUnloadRequest {
+                    model_name: name.clone(),
+                    ..Default::default()
+                };
+                self.repository_model_unload(req, headers.clone()).err()
+            })
+            .collect();
+        if errors.iter().any(Option::is_some) {
+            return Err(Error::Batch(errors));
+        }
+        Ok(names)
     }
     #[doc = "Get the status of all registered system-shared-memory regions."]
     #[inline(always)]
+    #[pyo3(signature = (req, headers=None))]
     pub fn system_shared_memory_status(
         &self,
         req: inference::SystemSharedMemoryStatusRequest,
+        headers: Option<HashMap<String, String>>,
     ) -> Result<inference::SystemSharedMemoryStatusResponse, Error> {
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async {
-                inner
-                    .system_shared_memory_status(tonic::Request::new(req))
-                    .await
-            })?;
-        Ok(response.into_inner())
+        call_span!("triton.system_shared_memory_status");
+        let req = new_request(req, headers.as_ref())?;
+        self.call(
+            "system_shared_memory_status",
+            req,
+            |mut inner, req| async move { inner.system_shared_memory_status(req).await },
+        )
     }
     #[doc = "Register a system-shared-memory region."]
     #[inline(always)]
+    #[pyo3(signature = (req, headers=None))]
     pub fn system_shared_memory_register(
         &self,
         req: inference::SystemSharedMemoryRegisterRequest,
+        headers: Option<HashMap<String, String>>,
     ) -> Result<inference::SystemSharedMemoryRegisterResponse, Error> {
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async {
-                inner
-                    .system_shared_memory_register(tonic::Request::new(req))
-                    .await
-            })?;
-        Ok(response.into_inner())
+        call_span!("triton.system_shared_memory_register");
+        let req = new_request(req, headers.as_ref())?;
+        self.call(
+            "system_shared_memory_register",
+            req,
+            |mut inner, req| async move { inner.system_shared_memory_register(req).await },
+        )
     }
     #[doc = "Unregister a system-shared-memory region."]
     #[inline(always)]
+    #[pyo3(signature = (req, headers=None))]
     pub fn system_shared_memory_unregister(
         &self,
         req: inference::SystemSharedMemoryUnregisterRequest,
+        headers: Option<HashMap<String, String>>,
     ) -> Result<inference::SystemSharedMemoryUnregisterResponse, Error> {
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async {
-                inner
-                    .system_shared_memory_unregister(tonic::Request::new(req))
-                    .await
-            })?;
-        Ok(response.into_inner())
+        call_span!("triton.system_shared_memory_unregister");
+        let req = new_request(req, headers.as_ref())?;
+        self.call(
+            "system_shared_memory_unregister",
+            req,
+            |mut inner, req| async move { inner.system_shared_memory_unregister(req).await },
+        )
     }
     #[doc = "Get the status of all registered CUDA-shared-memory regions."]
     #[inline(always)]
+    #[pyo3(signature = (req, headers=None))]
     pub fn cuda_shared_memory_status(
         &self,
         req: inference::CudaSharedMemoryStatusRequest,
+        headers: Option<HashMap<String, String>>,
     ) -> Result<inference::CudaSharedMemoryStatusResponse, Error> {
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async {
-                inner
-                    .cuda_shared_memory_status(tonic::Request::new(req))
-                    .await
-            })?;
-        Ok(response.into_inner())
+        call_span!("triton.cuda_shared_memory_status");
+        let req = new_request(req, headers.as_ref())?;
+        self.call(
+            "cuda_shared_memory_status",
+            req,
+            |mut inner, req| async move { inner.cuda_shared_memory_status(req).await },
+        )
     }
     #[doc = "Register a CUDA-shared-memory region."]
     #[inline(always)]
+    #[pyo3(signature = (req, headers=None))]
     pub fn cuda_shared_memory_register(
         &self,
         req: inference::CudaSharedMemoryRegisterRequest,
+        headers: Option<HashMap<String, String>>,
     ) -> Result<inference::CudaSharedMemoryRegisterResponse, Error> {
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async {
-                inner
-                    .cuda_shared_memory_register(tonic::Request::new(req))
-                    .await
-            })?;
-        Ok(response.into_inner())
+        call_span!("triton.cuda_shared_memory_register");
+        let req = new_request(req, headers.as_ref())?;
+        self.call(
+            "cuda_shared_memory_register",
+            req,
+            |mut inner, req| async move { inner.cuda_shared_memory_register(req).await },
+        )
     }
     #[doc = "Unregister a CUDA-shared-memory region."]
     #[inline(always)]
+    #[pyo3(signature = (req, headers=None))]
     pub fn cuda_shared_memory_unregister(
         &self,
         req: inference::CudaSharedMemoryUnregisterRequest,
+        headers: Option<HashMap<String, String>>,
     ) -> Result<inference::CudaSharedMemoryUnregisterResponse, Error> {
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async {
-                inner
-                    .cuda_shared_memory_unregister(tonic::Request::new(req))
-                    .await
-            })?;
-        Ok(response.into_inner())
+        call_span!("triton.cuda_shared_memory_unregister");
+        let req = new_request(req, headers.as_ref())?;
+        self.call(
+            "cuda_shared_memory_unregister",
+            req,
+            |mut inner, req| async move { inner.cuda_shared_memory_unregister(req).await },
+        )
     }
     #[doc = "Update and get the trace setting of the Triton server."]
     #[inline(always)]
+    #[pyo3(signature = (req, headers=None))]
     pub fn trace_setting(
         &self,
         req: inference::TraceSettingRequest,
+        headers: Option<HashMap<String, String>>,
     ) -> Result<inference::TraceSettingResponse, Error> {
-        let mut inner = self.inner.clone();
-        let response = crate::TOKIO_RT
-            .get()
-            .context("failed to get tokio runtime")?
-            .block_on(async { inner.trace_setting(tonic::Request::new(req)).await })?;
-        Ok(response.into_inner())
+        call_span!("triton.trace_setting");
+        let req = new_request(req, headers.as_ref())?;
+        self.call("trace_setting", req, |mut inner, req| async move {
+            inner.trace_setting(req).await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    #[test]
+    fn insert_headers_lowercases_keys_and_rejects_invalid_values() {
+        let mut metadata = tonic::metadata::MetadataMap::new();
+        let headers = HashMap::from([("X-Request-Id".to_string(), "42".to_string())]);
+        insert_headers(&mut metadata, &headers).unwrap();
+        assert_eq!(metadata.get("x-request-id").unwrap(), "42");
+
+        let headers = HashMap::from([("x-bad".to_string(), "line\nbreak".to_string())]);
+        assert!(matches!(
+            insert_headers(&mut metadata, &headers),
+            Err(Error::InvalidAccessToken(_))
+        ));
+    }
+
+    #[test]
+    fn time_until_past_deadline_fails() {
+        assert!(matches!(time_until(0.0), Err(Error::DeadlineExceeded)));
+        assert!(matches!(time_until(f64::NAN), Err(Error::DeadlineExceeded)));
+    }
+
+    #[test]
+    fn mock_client_answers_and_records_calls() {
+        pyo3::Python::initialize();
+        let live = inference::ServerLiveResponse { live: true };
+        let responses = pyo3::Python::attach(|py| {
+            HashMap::from([(
+                "server_live".to_string(),
+                Py::new(py, live).unwrap().into_any(),
+            )])
+        });
+        let client = Client::mock(responses).unwrap();
+
+        assert!(client.server_live(None).unwrap().live);
+        assert!(client.server_live(None).unwrap().live);
+        // methods without a canned response fail instead of reaching the network
+        assert!(matches!(client.server_ready(None), Err(Error::Msg(_))));
+        pyo3::Python::attach(|py| {
+            assert_eq!(client.calls_to(py, "server_live").unwrap().len(), 2);
+            assert!(client.calls_to(py, "model_infer").unwrap().is_empty());
+        });
+    }
+
+    #[test]
+    fn mock_model_infer_many_reports_failures_per_request() {
+        pyo3::Python::initialize();
+        let responses = pyo3::Python::attach(|py| {
+            let locals = PyDict::new(py);
+            let response = inference::ModelInferResponse {
+                model_name: "resnet".to_string(),
+                ..Default::default()
+            };
+            locals
+                .set_item("response", Py::new(py, response).unwrap())
+                .unwrap();
+            let respond = py
+                .eval(
+                    c"lambda req, r=response: r if req.model_name == 'resnet' else 1 / 0",
+                    None,
+                    Some(&locals),
+                )
+                .unwrap();
+            HashMap::from([("model_infer".to_string(), respond.unbind())])
+        });
+        let client = Client::mock(responses).unwrap();
+        let request = |model_name: &str| inference::ModelInferRequest {
+            model_name: model_name.to_string(),
+            ..Default::default()
+        };
+
+        let responses = client
+            .model_infer_many(vec![request("resnet"), request("resnet")], None)
+            .unwrap();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0].model_name, "resnet");
+
+        match client.model_infer_many(vec![request("resnet"), request("missing")], None) {
+            Err(Error::Batch(errors)) => {
+                assert!(errors[0].is_none());
+                assert!(matches!(errors[1], Some(Error::Python(_))));
+            }
+            other => panic!("expected a batch error, got {:?}", other.map(|r| r.len())),
+        }
+    }
+
+    #[test]
+    fn open_circuits_raise_circuit_open_without_calling_servers() {
+        let client = ClientBuilder {
+            urls: vec![
+                "http://127.0.0.1:1".to_string(),
+                "http://127.0.0.1:2".to_string(),
+            ],
+            circuit_breaker: Some(CircuitBreakerConfig {
+                failure_threshold: 1,
+                reset_timeout: std::time::Duration::from_secs(3600),
+            }),
+            ..Default::default()
+        }
+        .build()
+        .unwrap();
+        for circuit in &client.circuits {
+            circuit.record_failure();
+        }
+        assert!(matches!(client.server_live(None), Err(Error::CircuitOpen)));
+
+        pyo3::Python::initialize();
+        pyo3::Python::attach(|py| {
+            let err = pyo3::PyErr::from(Error::CircuitOpen);
+            assert!(err.is_instance_of::<crate::error::TritonConnectionError>(py));
+        });
+    }
+
+    #[test]
+    fn http2_keep_alive_interval_must_be_positive() {
+        pyo3::Python::initialize();
+        pyo3::Python::attach(|py| {
+            let builder = Py::new(py, ClientBuilder::new()).unwrap();
+            assert!(ClientBuilder::http2_keep_alive_interval(builder.borrow_mut(py), 0).is_err());
+            ClientBuilder::http2_keep_alive_interval(builder.borrow_mut(py), 20).unwrap();
+            assert_eq!(
+                builder.borrow(py).http2.keep_alive_interval,
+                Some(std::time::Duration::from_secs(20))
+            );
+        });
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn call_span_is_recorded_and_propagated() {
+        use opentelemetry::trace::TracerProvider as _;
+        use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider};
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let exporter = InMemorySpanExporter::default();
+        let provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+
+        let request = tracing::subscriber::with_default(subscriber, || {
+            let req = inference::ModelInferRequest {
+                model_name: "resnet".to_string(),
+                ..Default::default()
+            };
+            call_span!(
+                "triton.model_infer",
+                model_name = %req.model_name,
+                model_version = %req.model_version
+            );
+            new_request(req, None).unwrap()
+        });
+
+        assert!(request.metadata().contains_key("traceparent"));
+        let spans = exporter.get_finished_spans().unwrap();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].name, "triton.model_infer");
     }
 }
@@ -0,0 +1,77 @@
+//! Mapping between Triton `DataType`s, their protocol names and numpy dtypes.
+use crate::inference::DataType;
+use pyo3::prelude::*;
+
+/// `(DataType, Triton protocol name, numpy dtype name)`
+///
+/// `BF16` has no numpy counterpart and `BYTES` maps to numpy `object` arrays.
+pub(crate) const DTYPE_TABLE: &[(DataType, &str, Option<&str>)] = &[
+    (DataType::TypeBool, "BOOL", Some("bool")),
+    (DataType::TypeUint8, "UINT8", Some("uint8")),
+    (DataType::TypeUint16, "UINT16", Some("uint16")),
+    (DataType::TypeUint32, "UINT32", Some("uint32")),
+    (DataType::TypeUint64, "UINT64", Some("uint64")),
+    (DataType::TypeInt8, "INT8", Some("int8")),
+    (DataType::TypeInt16, "INT16", Some("int16")),
+    (DataType::TypeInt32, "INT32", Some("int32")),
+    (DataType::TypeInt64, "INT64", Some("int64")),
+    (DataType::TypeFp16, "FP16", Some("float16")),
+    (DataType::TypeFp32, "FP32", Some("float32")),
+    (DataType::TypeFp64, "FP64", Some("float64")),
+    (DataType::TypeString, "BYTES", Some("object")),
+    (DataType::TypeBf16, "BF16", None),
+];
+
+/// numpy dtype name of a `DataType`, if any.
+pub(crate) fn to_numpy(dtype: DataType) -> Option<&'static str> {
+    DTYPE_TABLE
+        .iter()
+        .find(|(d, _, _)| *d == dtype)
+        .and_then(|(_, _, np)| *np)
+}
+
+/// Triton protocol name of a `DataType`, e.g. `"FP32"`.
+pub(crate) fn to_triton(dtype: DataType) -> Option<&'static str> {
+    DTYPE_TABLE
+        .iter()
+        .find(|(d, _, _)| *d == dtype)
+        .map(|(_, name, _)| *name)
+}
+
+/// `DataType` of a Triton protocol name, e.g. `"FP32"`.
+pub(crate) fn from_triton(name: &str) -> Option<DataType> {
+    DTYPE_TABLE
+        .iter()
+        .find(|(_, n, _)| *n == name)
+        .map(|(d, _, _)| *d)
+}
+
+/// `DataType` of a numpy dtype name, e.g. `"float32"`.
+pub(crate) fn from_numpy(name: &str) -> Option<DataType> {
+    DTYPE_TABLE
+        .iter()
+        .find(|(_, _, np)| *np == Some(name))
+        .map(|(d, _, _)| *d)
+}
+
+#[pymethods]
+impl DataType {
+    /// numpy dtype string, e.g. `"float32"` for `TypeFp32`.
+    #[allow(clippy::wrong_self_convention)] // pymethods cannot take `self` by value
+    fn to_numpy_dtype(&self) -> PyResult<String> {
+        to_numpy(*self).map(str::to_string).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "{} has no numpy dtype",
+                self.as_str_name()
+            ))
+        })
+    }
+
+    /// `DataType` of a numpy dtype string, e.g. `"float32"`.
+    #[staticmethod]
+    fn from_numpy_dtype(dtype: &str) -> PyResult<Self> {
+        from_numpy(dtype.trim()).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(format!("unsupported numpy dtype: {}", dtype))
+        })
+    }
+}
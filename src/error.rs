@@ -1,18 +1,65 @@
+#[cfg(feature = "client")]
 use http::uri::InvalidUri;
 use numpy::{FromVecError, NotContiguousError};
+#[cfg(feature = "client")]
 use tonic::Status;
 use serde_json::Error as SerdeJsonError;
 
+pyo3::create_exception!(
+    triton_client,
+    TritonConnectionError,
+    pyo3::exceptions::PyConnectionError,
+    "Raised when the client is closed or the server cannot be reached (gRPC `UNAVAILABLE`)."
+);
+
+pyo3::create_exception!(
+    triton_client,
+    TritonTimeoutError,
+    pyo3::exceptions::PyTimeoutError,
+    "Raised when a request deadline has passed, before or while sending it."
+);
+
+pyo3::create_exception!(
+    triton_client,
+    TritonServerError,
+    pyo3::exceptions::PyException,
+    "Raised when the server answers with an error status; `status_code` holds the gRPC code\n\
+     (e.g. 5 for NOT_FOUND), `message` its message and `details` the binary status details."
+);
+
+pyo3::create_exception!(
+    triton_client,
+    TritonBatchError,
+    pyo3::exceptions::PyException,
+    "Raised when some requests of a batch fail; `args[1]` holds one optional exception per\n\
+     request, e.g. a `TritonServerError` with its `status_code`."
+);
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("General error: {0}")]
     Msg(String),
+    #[error("Client is closed")]
+    ClientClosed,
+    #[error("All servers are unavailable (circuit open)")]
+    CircuitOpen,
+    #[error("Deadline exceeded")]
+    DeadlineExceeded,
+    #[error("{failed} of {total} requests failed", failed = .0.iter().flatten().count(), total = .0.len())]
+    Batch(Vec<Option<Error>>),
+    #[cfg(feature = "client")]
     #[error(transparent)]
     TransportError(#[from] tonic::transport::Error),
+    #[cfg(feature = "client")]
     #[error(transparent)]
     InvalidUri(#[from] InvalidUri),
+    #[cfg(feature = "client")]
     #[error(transparent)]
     InvalidAccessToken(#[from] tonic::metadata::errors::InvalidMetadataValue),
+    #[cfg(feature = "client")]
+    #[error(transparent)]
+    InvalidMetadataKey(#[from] tonic::metadata::errors::InvalidMetadataKey),
+    #[cfg(feature = "client")]
     #[error(transparent)]
     ResponseError(#[from] Status),
     #[error(transparent)]
@@ -23,6 +70,8 @@ pub enum Error {
     FromVecError(#[from] FromVecError),
     #[error(transparent)]
     NotContiguousError(#[from] NotContiguousError),
+    #[error(transparent)]
+    Python(#[from] pyo3::PyErr),
 }
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -35,10 +84,51 @@ impl From<anyhow::Error> for Error {
 
 impl From<Error> for pyo3::PyErr {
     fn from(error: Error) -> Self {
-        pyo3::exceptions::PyException::new_err(format!("{:#}", error))
+        let msg = format!("{:#}", error);
+        match error {
+            Error::ClientClosed | Error::CircuitOpen => TritonConnectionError::new_err(msg),
+            #[cfg(feature = "client")]
+            Error::TransportError(_) => TritonConnectionError::new_err(msg),
+            Error::DeadlineExceeded => TritonTimeoutError::new_err(msg),
+            #[cfg(feature = "client")]
+            Error::ResponseError(status) if status.code() == tonic::Code::DeadlineExceeded => {
+                TritonTimeoutError::new_err(msg)
+            }
+            #[cfg(feature = "client")]
+            Error::ResponseError(status) if status.code() == tonic::Code::Unavailable => {
+                TritonConnectionError::new_err(msg)
+            }
+            #[cfg(feature = "client")]
+            Error::ResponseError(status) => server_error(msg, &status),
+            Error::Batch(errors) => {
+                // converted exceptions keep attributes such as `TritonServerError.status_code`
+                let errors: Vec<Option<pyo3::PyErr>> = errors
+                    .into_iter()
+                    .map(|e| e.map(pyo3::PyErr::from))
+                    .collect();
+                TritonBatchError::new_err((msg, errors))
+            }
+            Error::Python(e) => e,
+            _ => pyo3::exceptions::PyException::new_err(msg),
+        }
     }
 }
 
+/// A [`TritonServerError`] carrying the code, message and details of `status`.
+#[cfg(feature = "client")]
+fn server_error(msg: String, status: &Status) -> pyo3::PyErr {
+    use pyo3::types::PyAnyMethods;
+    let err = TritonServerError::new_err(msg);
+    pyo3::Python::attach(|py| {
+        let value = err.value(py);
+        // setting attributes on a fresh exception instance cannot fail
+        let _ = value.setattr("status_code", status.code() as i32);
+        let _ = value.setattr("message", status.message());
+        let _ = value.setattr("details", pyo3::types::PyBytes::new(py, status.details()));
+    });
+    err
+}
+
 impl Error {
     pub fn msg(msg: impl ToString) -> Self {
         Self::Msg(msg.to_string())
@@ -7,6 +7,9 @@
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct HealthCheckRequest {
     #[prost(string, tag = "1")]
@@ -20,6 +23,9 @@ pub struct HealthCheckRequest {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct HealthCheckResponse {
     #[prost(enumeration = "health_check_response::ServingStatus", tag = "1")]
@@ -35,6 +41,8 @@ pub mod health_check_response {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
     #[derive(
         Clone,
         Copy,
@@ -79,6 +87,7 @@ pub mod health_check_response {
     }
 }
 /// Generated client implementations.
+#[cfg(feature = "client")]
 pub mod health_client {
     #![allow(
         unused_variables,
@@ -206,6 +215,7 @@ pub mod health_client {
     }
 }
 /// Generated server implementations.
+#[cfg(feature = "client")]
 pub mod health_server {
     #![allow(
         unused_variables,
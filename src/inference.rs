@@ -9,6 +9,10 @@
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelRateLimiter {
     /// @@  .. cpp:var:: Resource resources (repeated)
@@ -40,6 +44,10 @@ pub mod model_rate_limiter {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct Resource {
         /// @@  .. cpp:var:: string name
@@ -75,6 +83,10 @@ pub mod model_rate_limiter {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelInstanceGroup {
     /// @@  .. cpp:var:: string name
@@ -169,6 +181,10 @@ pub mod model_instance_group {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct SecondaryDevice {
         /// @@  .. cpp:var:: SecondaryDeviceKind kind
@@ -194,6 +210,8 @@ pub mod model_instance_group {
         #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
         #[derive(::triton_client_macros::ImplPyNew)]
         #[derive(::triton_client_macros::ImplPyVecAccessors)]
+        #[derive(::triton_client_macros::ImplPyProtoBytes)]
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
         #[derive(
             Clone,
             Copy,
@@ -241,6 +259,8 @@ pub mod model_instance_group {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
     #[derive(
         Clone,
         Copy,
@@ -316,6 +336,10 @@ pub mod model_instance_group {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ModelTensorReshape {
     /// @@  .. cpp:var:: int64 shape (repeated)
@@ -333,6 +357,10 @@ pub struct ModelTensorReshape {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ModelInput {
     /// @@  .. cpp:var:: string name
@@ -416,6 +444,8 @@ pub mod model_input {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
     #[derive(
         Clone,
         Copy,
@@ -484,6 +514,10 @@ pub mod model_input {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ModelOutput {
     /// @@  .. cpp:var:: string name
@@ -547,6 +581,10 @@ pub struct ModelOutput {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct BatchInput {
     /// @@    .. cpp:var:: Kind kind
@@ -588,6 +626,8 @@ pub mod batch_input {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
     #[derive(
         Clone,
         Copy,
@@ -700,6 +740,10 @@ pub mod batch_input {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct BatchOutput {
     /// @@  .. cpp:var:: string target_name (repeated)
@@ -733,6 +777,8 @@ pub mod batch_output {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
     #[derive(
         Clone,
         Copy,
@@ -784,6 +830,10 @@ pub mod batch_output {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ModelVersionPolicy {
     /// @@  .. cpp:var:: oneof policy_choice
@@ -804,6 +854,10 @@ pub mod model_version_policy {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct Latest {
         /// @@    .. cpp:var:: uint32 num_versions
@@ -823,6 +877,10 @@ pub mod model_version_policy {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct All {}
     /// @@  .. cpp:var:: message Specific
@@ -832,6 +890,10 @@ pub mod model_version_policy {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct Specific {
         /// @@    .. cpp:var:: int64 versions (repeated)
@@ -849,6 +911,8 @@ pub mod model_version_policy {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
     pub enum PolicyChoice {
         /// @@    .. cpp:var:: Latest latest
@@ -881,6 +945,10 @@ pub mod model_version_policy {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelOptimizationPolicy {
     /// @@  .. cpp:var:: Graph graph
@@ -968,6 +1036,10 @@ pub mod model_optimization_policy {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct Graph {
         /// @@    .. cpp:var:: int32 level
@@ -990,6 +1062,10 @@ pub mod model_optimization_policy {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Cuda {
         /// @@    .. cpp:var:: bool graphs
@@ -1039,6 +1115,10 @@ pub mod model_optimization_policy {
         #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
         #[derive(::triton_client_macros::ImplPyNew)]
         #[derive(::triton_client_macros::ImplPyVecAccessors)]
+        #[derive(::triton_client_macros::ImplPyProtoBytes)]
+        #[pyclass_accessors(naming = "snake_case")]
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+        #[serde(default)]
         #[derive(Clone, PartialEq, ::prost::Message)]
         pub struct GraphSpec {
             /// @@      .. cpp:var:: int32 batch_size
@@ -1084,6 +1164,10 @@ pub mod model_optimization_policy {
             #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
             #[derive(::triton_client_macros::ImplPyNew)]
             #[derive(::triton_client_macros::ImplPyVecAccessors)]
+            #[derive(::triton_client_macros::ImplPyProtoBytes)]
+            #[pyclass_accessors(naming = "snake_case")]
+            #[derive(::serde::Serialize, ::serde::Deserialize)]
+            #[serde(default)]
             #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
             pub struct Shape {
                 /// @@        .. cpp:var:: int64 dim (repeated)
@@ -1096,6 +1180,10 @@ pub mod model_optimization_policy {
             #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
             #[derive(::triton_client_macros::ImplPyNew)]
             #[derive(::triton_client_macros::ImplPyVecAccessors)]
+            #[derive(::triton_client_macros::ImplPyProtoBytes)]
+            #[pyclass_accessors(naming = "snake_case")]
+            #[derive(::serde::Serialize, ::serde::Deserialize)]
+            #[serde(default)]
             #[derive(Clone, PartialEq, ::prost::Message)]
             pub struct LowerBound {
                 /// @@      .. cpp:var:: int32 batch_size
@@ -1138,6 +1226,10 @@ pub mod model_optimization_policy {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ExecutionAccelerators {
         /// @@    .. cpp:var:: Accelerator gpu_execution_accelerator (repeated)
@@ -1207,6 +1299,10 @@ pub mod model_optimization_policy {
         #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
         #[derive(::triton_client_macros::ImplPyNew)]
         #[derive(::triton_client_macros::ImplPyVecAccessors)]
+        #[derive(::triton_client_macros::ImplPyProtoBytes)]
+        #[pyclass_accessors(naming = "snake_case")]
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+        #[serde(default)]
         #[derive(Clone, PartialEq, ::prost::Message)]
         pub struct Accelerator {
             /// @@    .. cpp:var:: string name
@@ -1242,6 +1338,10 @@ pub mod model_optimization_policy {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct PinnedMemoryBuffer {
         /// @@    .. cpp:var:: bool enable
@@ -1261,6 +1361,8 @@ pub mod model_optimization_policy {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
     #[derive(
         Clone,
         Copy,
@@ -1321,6 +1423,10 @@ pub mod model_optimization_policy {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ModelQueuePolicy {
     /// @@
@@ -1370,6 +1476,8 @@ pub mod model_queue_policy {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
     #[derive(
         Clone,
         Copy,
@@ -1427,6 +1535,10 @@ pub mod model_queue_policy {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelDynamicBatching {
     /// @@  .. cpp:var:: int32 preferred_batch_size (repeated)
@@ -1505,6 +1617,10 @@ pub struct ModelDynamicBatching {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelSequenceBatching {
     /// @@  .. cpp:var:: uint64 max_sequence_idle_microseconds
@@ -1567,6 +1683,10 @@ pub mod model_sequence_batching {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Control {
         /// @@    .. cpp:var:: Kind kind
@@ -1622,6 +1742,8 @@ pub mod model_sequence_batching {
         #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
         #[derive(::triton_client_macros::ImplPyNew)]
         #[derive(::triton_client_macros::ImplPyVecAccessors)]
+        #[derive(::triton_client_macros::ImplPyProtoBytes)]
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
         #[derive(
             Clone,
             Copy,
@@ -1705,6 +1827,10 @@ pub mod model_sequence_batching {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ControlInput {
         /// @@    .. cpp:var:: string name
@@ -1729,6 +1855,10 @@ pub mod model_sequence_batching {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct InitialState {
         /// @@      .. cpp:var:: DataType data_type
@@ -1766,6 +1896,8 @@ pub mod model_sequence_batching {
         #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
         #[derive(::triton_client_macros::ImplPyNew)]
         #[derive(::triton_client_macros::ImplPyVecAccessors)]
+        #[derive(::triton_client_macros::ImplPyProtoBytes)]
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
         #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
         pub enum StateData {
             /// @@
@@ -1794,6 +1926,10 @@ pub mod model_sequence_batching {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct State {
         /// @@    .. cpp:var:: string input_name
@@ -1872,6 +2008,10 @@ pub mod model_sequence_batching {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, Copy, PartialEq, ::prost::Message)]
     pub struct StrategyDirect {
         /// @@    .. cpp:var:: uint64 max_queue_delay_microseconds
@@ -1912,6 +2052,10 @@ pub mod model_sequence_batching {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct StrategyOldest {
         /// @@    .. cpp:var:: int32 max_candidate_sequences
@@ -1973,6 +2117,8 @@ pub mod model_sequence_batching {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum StrategyChoice {
         /// @@    .. cpp:var:: StrategyDirect direct
@@ -1998,6 +2144,10 @@ pub mod model_sequence_batching {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelEnsembling {
     /// @@  .. cpp:var:: Step step (repeated)
@@ -2018,6 +2168,10 @@ pub mod model_ensembling {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Step {
         /// @@  .. cpp:var:: string model_name
@@ -2076,6 +2230,10 @@ pub mod model_ensembling {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ModelParameter {
     /// @@  .. cpp:var:: string string_value
@@ -2093,6 +2251,10 @@ pub struct ModelParameter {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelWarmup {
     /// @@  .. cpp:var:: string name
@@ -2145,6 +2307,10 @@ pub mod model_warmup {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct Input {
         /// @@    .. cpp:var:: DataType data_type
@@ -2179,6 +2345,8 @@ pub mod model_warmup {
         #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
         #[derive(::triton_client_macros::ImplPyNew)]
         #[derive(::triton_client_macros::ImplPyVecAccessors)]
+        #[derive(::triton_client_macros::ImplPyProtoBytes)]
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
         #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
         pub enum InputDataType {
             /// @@
@@ -2221,6 +2389,10 @@ pub mod model_warmup {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ModelOperations {
     /// @@  .. cpp:var:: string op_library_filename (repeated)
@@ -2240,6 +2412,10 @@ pub struct ModelOperations {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ModelTransactionPolicy {
     /// @@  .. cpp:var:: bool decoupled
@@ -2262,6 +2438,10 @@ pub struct ModelTransactionPolicy {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelRepositoryAgents {
     /// @@
@@ -2285,6 +2465,10 @@ pub mod model_repository_agents {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Agent {
         /// @@    .. cpp:var:: string name
@@ -2312,6 +2496,10 @@ pub mod model_repository_agents {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ModelResponseCache {
     /// @@
@@ -2336,6 +2524,10 @@ pub struct ModelResponseCache {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelMetrics {
     /// @@
@@ -2356,6 +2548,10 @@ pub mod model_metrics {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct MetricControl {
         /// @@  .. cpp:var:: MetricIdentifier metric_identifier
@@ -2382,6 +2578,10 @@ pub mod model_metrics {
         #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
         #[derive(::triton_client_macros::ImplPyNew)]
         #[derive(::triton_client_macros::ImplPyVecAccessors)]
+        #[derive(::triton_client_macros::ImplPyProtoBytes)]
+        #[pyclass_accessors(naming = "snake_case")]
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+        #[serde(default)]
         #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
         pub struct MetricIdentifier {
             /// @@  .. cpp:var:: string family
@@ -2401,6 +2601,10 @@ pub mod model_metrics {
         #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
         #[derive(::triton_client_macros::ImplPyNew)]
         #[derive(::triton_client_macros::ImplPyVecAccessors)]
+        #[derive(::triton_client_macros::ImplPyProtoBytes)]
+        #[pyclass_accessors(naming = "snake_case")]
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+        #[serde(default)]
         #[derive(Clone, PartialEq, ::prost::Message)]
         pub struct HistogramOptions {
             /// @@  .. cpp:var:: double buckets (repeated)
@@ -2421,6 +2625,8 @@ pub mod model_metrics {
         #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
         #[derive(::triton_client_macros::ImplPyNew)]
         #[derive(::triton_client_macros::ImplPyVecAccessors)]
+        #[derive(::triton_client_macros::ImplPyProtoBytes)]
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
         #[derive(Clone, PartialEq, ::prost::Oneof)]
         pub enum MetricOptions {
             /// @@  .. cpp:var:: HistogramOptions histogram_options
@@ -2437,9 +2643,14 @@ pub mod model_metrics {
 /// @@
 /// @@   A model configuration.
 /// @@
+#[derive(::triton_client_macros::ImplPyCopy)]
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelConfig {
     /// @@  .. cpp:var:: string name
@@ -2636,9 +2847,12 @@ pub mod model_config {
     /// @@     default scheduling policy is used for the model. The default
     /// @@     policy is to execute each inference request independently.
     /// @@
+    #[derive(::triton_client_macros::ImplPyCopy)]
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum SchedulingChoice {
         /// @@    .. cpp:var:: ModelDynamicBatching dynamic_batching
@@ -2682,6 +2896,8 @@ pub mod model_config {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum DataType {
@@ -2770,6 +2986,10 @@ impl DataType {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ServerLiveRequest {}
 /// @@
@@ -2777,9 +2997,15 @@ pub struct ServerLiveRequest {}
 /// @@
 /// @@   Response message for ServerLive.
 /// @@
+#[derive(::triton_client_macros::ImplPyHash)]
+#[hash = "immutable"]
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ServerLiveResponse {
     /// @@
@@ -2798,6 +3024,10 @@ pub struct ServerLiveResponse {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ServerReadyRequest {}
 /// @@
@@ -2805,9 +3035,15 @@ pub struct ServerReadyRequest {}
 /// @@
 /// @@   Response message for ServerReady.
 /// @@
+#[derive(::triton_client_macros::ImplPyHash)]
+#[hash = "immutable"]
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ServerReadyResponse {
     /// @@
@@ -2826,6 +3062,10 @@ pub struct ServerReadyResponse {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ModelReadyRequest {
     /// @@
@@ -2848,9 +3088,15 @@ pub struct ModelReadyRequest {
 /// @@
 /// @@   Response message for ModelReady.
 /// @@
+#[derive(::triton_client_macros::ImplPyHash)]
+#[hash = "immutable"]
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ModelReadyResponse {
     /// @@
@@ -2869,6 +3115,10 @@ pub struct ModelReadyResponse {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ServerMetadataRequest {}
 /// @@
@@ -2876,9 +3126,15 @@ pub struct ServerMetadataRequest {}
 /// @@
 /// @@   Response message for ServerMetadata.
 /// @@
+#[derive(::triton_client_macros::ImplPyHash)]
+#[hash = "immutable"]
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ServerMetadataResponse {
     /// @@
@@ -2911,6 +3167,10 @@ pub struct ServerMetadataResponse {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ModelMetadataRequest {
     /// @@
@@ -2937,6 +3197,10 @@ pub struct ModelMetadataRequest {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelMetadataResponse {
     /// @@
@@ -2985,6 +3249,10 @@ pub mod model_metadata_response {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct TensorMetadata {
         /// @@
@@ -3016,9 +3284,14 @@ pub mod model_metadata_response {
 /// @@
 /// @@   An inference parameter value.
 /// @@
+#[derive(::triton_client_macros::ImplPyCopy)]
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InferParameter {
     /// @@  .. cpp:var:: oneof parameter_choice
@@ -3044,9 +3317,12 @@ pub mod infer_parameter {
     /// @@           placeholders for future use and
     /// @@           are not supported for custom parameters
     /// @@
+    #[derive(::triton_client_macros::ImplPyCopy)]
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum ParameterChoice {
         /// @@    .. cpp:var:: bool bool_param
@@ -3090,9 +3366,14 @@ pub mod infer_parameter {
 /// @@   that matches the tensor's data type. Protobuf oneof is not used
 /// @@   because oneofs cannot contain repeated fields.
 /// @@
+#[derive(::triton_client_macros::ImplPyCopy)]
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InferTensorContents {
     /// @@
@@ -3103,6 +3384,7 @@ pub struct InferTensorContents {
     /// @@     one-dimensional, row-major order of the tensor elements.
     /// @@
     #[prost(bool, repeated, tag = "1")]
+    #[pyclass_accessors(skip = "replace")]
     pub bool_contents: ::prost::alloc::vec::Vec<bool>,
     /// @@
     /// @@  .. cpp:var:: int32 int_contents (repeated)
@@ -3113,6 +3395,7 @@ pub struct InferTensorContents {
     /// @@     tensor elements.
     /// @@
     #[prost(int32, repeated, tag = "2")]
+    #[pyclass_accessors(skip = "replace")]
     pub int_contents: ::prost::alloc::vec::Vec<i32>,
     /// @@
     /// @@  .. cpp:var:: int64 int64_contents (repeated)
@@ -3122,6 +3405,7 @@ pub struct InferTensorContents {
     /// @@     flattened, one-dimensional, row-major order of the tensor elements.
     /// @@
     #[prost(int64, repeated, tag = "3")]
+    #[pyclass_accessors(skip = "replace")]
     pub int64_contents: ::prost::alloc::vec::Vec<i64>,
     /// @@
     /// @@  .. cpp:var:: uint32 uint_contents (repeated)
@@ -3132,6 +3416,7 @@ pub struct InferTensorContents {
     /// @@     tensor elements.
     /// @@
     #[prost(uint32, repeated, tag = "4")]
+    #[pyclass_accessors(skip = "replace")]
     pub uint_contents: ::prost::alloc::vec::Vec<u32>,
     /// @@
     /// @@  .. cpp:var:: uint64 uint64_contents (repeated)
@@ -3141,6 +3426,7 @@ pub struct InferTensorContents {
     /// @@     flattened, one-dimensional, row-major order of the tensor elements.
     /// @@
     #[prost(uint64, repeated, tag = "5")]
+    #[pyclass_accessors(skip = "replace")]
     pub uint64_contents: ::prost::alloc::vec::Vec<u64>,
     /// @@
     /// @@  .. cpp:var:: float fp32_contents (repeated)
@@ -3150,6 +3436,7 @@ pub struct InferTensorContents {
     /// @@     one-dimensional, row-major order of the tensor elements.
     /// @@
     #[prost(float, repeated, tag = "6")]
+    #[pyclass_accessors(skip = "replace")]
     pub fp32_contents: ::prost::alloc::vec::Vec<f32>,
     /// @@
     /// @@  .. cpp:var:: double fp64_contents (repeated)
@@ -3159,6 +3446,7 @@ pub struct InferTensorContents {
     /// @@     one-dimensional, row-major order of the tensor elements.
     /// @@
     #[prost(double, repeated, tag = "7")]
+    #[pyclass_accessors(skip = "replace")]
     pub fp64_contents: ::prost::alloc::vec::Vec<f64>,
     /// @@
     /// @@  .. cpp:var:: bytes bytes_contents (repeated)
@@ -3168,6 +3456,7 @@ pub struct InferTensorContents {
     /// @@     one-dimensional, row-major order of the tensor elements.
     /// @@
     #[prost(bytes = "vec", repeated, tag = "8")]
+    #[serde(with = "crate::json::base64_list")]
     pub bytes_contents: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
 /// @@
@@ -3175,9 +3464,16 @@ pub struct InferTensorContents {
 /// @@
 /// @@   Request message for ModelInfer.
 /// @@
+#[derive(::triton_client_macros::ImplPyCopy)]
+#[derive(::triton_client_macros::ImplPyHash)]
+#[hash = "mutable"]
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelInferRequest {
     /// @@  .. cpp:var:: string model_name
@@ -3251,6 +3547,7 @@ pub struct ModelInferRequest {
     /// @@     must not be specified for any input tensor.
     /// @@
     #[prost(bytes = "vec", repeated, tag = "7")]
+    #[serde(with = "crate::json::base64_list")]
     pub raw_input_contents: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
 /// Nested message and enum types in `ModelInferRequest`.
@@ -3260,9 +3557,16 @@ pub mod model_infer_request {
     /// @@
     /// @@     An input tensor for an inference request.
     /// @@
+    #[derive(::triton_client_macros::ImplPyCopy)]
+    #[derive(::triton_client_macros::ImplPyHash)]
+    #[hash = "mutable"]
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct InferInputTensor {
         /// @@
@@ -3309,9 +3613,16 @@ pub mod model_infer_request {
     /// @@
     /// @@     An output tensor requested for an inference request.
     /// @@
+    #[derive(::triton_client_macros::ImplPyCopy)]
+    #[derive(::triton_client_macros::ImplPyHash)]
+    #[hash = "mutable"]
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct InferRequestedOutputTensor {
         /// @@
@@ -3340,6 +3651,10 @@ pub mod model_infer_request {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelInferResponse {
     /// @@  .. cpp:var:: string model_name
@@ -3400,6 +3715,7 @@ pub struct ModelInferResponse {
     /// @@     must not be specified for any output tensor.
     /// @@
     #[prost(bytes = "vec", repeated, tag = "6")]
+    #[serde(with = "crate::json::base64_list")]
     pub raw_output_contents: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
 }
 /// Nested message and enum types in `ModelInferResponse`.
@@ -3412,6 +3728,10 @@ pub mod model_infer_response {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct InferOutputTensor {
         /// @@
@@ -3462,6 +3782,10 @@ pub mod model_infer_response {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelStreamInferResponse {
     /// @@
@@ -3488,6 +3812,10 @@ pub struct ModelStreamInferResponse {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ModelConfigRequest {
     /// @@
@@ -3513,6 +3841,10 @@ pub struct ModelConfigRequest {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelConfigResponse {
     /// @@
@@ -3531,6 +3863,10 @@ pub struct ModelConfigResponse {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ModelStatisticsRequest {
     /// @@  .. cpp:var:: string name
@@ -3556,6 +3892,10 @@ pub struct ModelStatisticsRequest {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct StatisticDuration {
     /// @@  .. cpp:var:: uint64 count
@@ -3579,6 +3919,10 @@ pub struct StatisticDuration {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct InferStatistics {
     /// @@  .. cpp:var:: StatisticDuration success
@@ -3679,6 +4023,10 @@ pub struct InferStatistics {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct InferResponseStatistics {
     /// @@  .. cpp:var:: StatisticDuration compute_infer
@@ -3728,6 +4076,10 @@ pub struct InferResponseStatistics {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct InferBatchStatistics {
     /// @@  .. cpp:var:: uint64 batch_size
@@ -3770,6 +4122,10 @@ pub struct InferBatchStatistics {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct MemoryUsage {
     /// @@  .. cpp:var:: string type
@@ -3800,6 +4156,10 @@ pub struct MemoryUsage {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelStatistics {
     /// @@  .. cpp:var:: string name
@@ -3899,6 +4259,10 @@ pub struct ModelStatistics {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ModelStatisticsResponse {
     /// @@  .. cpp:var:: ModelStatistics model_stats (repeated)
@@ -3916,6 +4280,10 @@ pub struct ModelStatisticsResponse {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ModelRepositoryParameter {
     /// @@  .. cpp:var:: oneof parameter_choice
@@ -3938,6 +4306,8 @@ pub mod model_repository_parameter {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
     pub enum ParameterChoice {
         /// @@    .. cpp:var:: bool bool_param
@@ -3963,6 +4333,7 @@ pub mod model_repository_parameter {
         /// @@       A bytes parameter value.
         /// @@
         #[prost(bytes, tag = "4")]
+        #[serde(with = "crate::json::base64_bytes")]
         BytesParam(::prost::alloc::vec::Vec<u8>),
     }
 }
@@ -3974,6 +4345,10 @@ pub mod model_repository_parameter {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct RepositoryIndexRequest {
     /// @@  .. cpp:var:: string repository_name
@@ -3998,6 +4373,10 @@ pub struct RepositoryIndexRequest {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RepositoryIndexResponse {
     /// @@
@@ -4018,6 +4397,10 @@ pub mod repository_index_response {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct ModelIndex {
         /// @@
@@ -4057,6 +4440,10 @@ pub mod repository_index_response {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RepositoryModelLoadRequest {
     /// @@  .. cpp:var:: string repository_name
@@ -4090,6 +4477,10 @@ pub struct RepositoryModelLoadRequest {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct RepositoryModelLoadResponse {}
 /// @@
@@ -4100,6 +4491,10 @@ pub struct RepositoryModelLoadResponse {}
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RepositoryModelUnloadRequest {
     /// @@  .. cpp:var:: string repository_name
@@ -4133,6 +4528,10 @@ pub struct RepositoryModelUnloadRequest {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct RepositoryModelUnloadResponse {}
 /// @@
@@ -4143,6 +4542,10 @@ pub struct RepositoryModelUnloadResponse {}
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct SystemSharedMemoryStatusRequest {
     /// @@
@@ -4162,6 +4565,10 @@ pub struct SystemSharedMemoryStatusRequest {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SystemSharedMemoryStatusResponse {
     /// @@
@@ -4186,6 +4593,10 @@ pub mod system_shared_memory_status_response {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct RegionStatus {
         /// @@
@@ -4225,6 +4636,10 @@ pub mod system_shared_memory_status_response {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct SystemSharedMemoryRegisterRequest {
     /// @@
@@ -4263,6 +4678,10 @@ pub struct SystemSharedMemoryRegisterRequest {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct SystemSharedMemoryRegisterResponse {}
 /// @@
@@ -4273,6 +4692,10 @@ pub struct SystemSharedMemoryRegisterResponse {}
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct SystemSharedMemoryUnregisterRequest {
     /// @@
@@ -4292,6 +4715,10 @@ pub struct SystemSharedMemoryUnregisterRequest {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct SystemSharedMemoryUnregisterResponse {}
 /// @@
@@ -4302,6 +4729,10 @@ pub struct SystemSharedMemoryUnregisterResponse {}
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CudaSharedMemoryStatusRequest {
     /// @@
@@ -4321,6 +4752,10 @@ pub struct CudaSharedMemoryStatusRequest {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CudaSharedMemoryStatusResponse {
     /// @@
@@ -4345,6 +4780,10 @@ pub mod cuda_shared_memory_status_response {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct RegionStatus {
         /// @@
@@ -4376,6 +4815,10 @@ pub mod cuda_shared_memory_status_response {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CudaSharedMemoryRegisterRequest {
     /// @@
@@ -4390,6 +4833,7 @@ pub struct CudaSharedMemoryRegisterRequest {
     /// @@     The raw serialized cudaIPC handle.
     /// @@
     #[prost(bytes = "vec", tag = "2")]
+    #[serde(with = "crate::json::base64_bytes")]
     pub raw_handle: ::prost::alloc::vec::Vec<u8>,
     /// @@  .. cpp:var:: int64 device_id
     /// @@
@@ -4412,6 +4856,10 @@ pub struct CudaSharedMemoryRegisterRequest {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CudaSharedMemoryRegisterResponse {}
 /// @@
@@ -4422,6 +4870,10 @@ pub struct CudaSharedMemoryRegisterResponse {}
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CudaSharedMemoryUnregisterRequest {
     /// @@
@@ -4441,6 +4893,10 @@ pub struct CudaSharedMemoryUnregisterRequest {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CudaSharedMemoryUnregisterResponse {}
 /// @@
@@ -4451,6 +4907,10 @@ pub struct CudaSharedMemoryUnregisterResponse {}
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TraceSettingRequest {
     /// @@  .. cpp:var:: map\<string,SettingValue> settings
@@ -4484,6 +4944,10 @@ pub mod trace_setting_request {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct SettingValue {
         /// @@
@@ -4503,6 +4967,10 @@ pub mod trace_setting_request {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TraceSettingResponse {
     /// @@  .. cpp:var:: map\<string,SettingValue> settings
@@ -4511,6 +4979,7 @@ pub struct TraceSettingResponse {
     /// @@     by TraceSettingRequest.
     /// @@
     #[prost(map = "string, message", tag = "1")]
+    #[pyo3(name = "setting_values")]
     pub settings: ::std::collections::HashMap<
         ::prost::alloc::string::String,
         trace_setting_response::SettingValue,
@@ -4526,6 +4995,10 @@ pub mod trace_setting_response {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct SettingValue {
         /// @@
@@ -4545,6 +5018,10 @@ pub mod trace_setting_response {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LogSettingsRequest {
     /// @@  .. cpp:var:: map\<string,SettingValue> settings
@@ -4562,6 +5039,10 @@ pub mod log_settings_request {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct SettingValue {
         #[prost(oneof = "setting_value::ParameterChoice", tags = "1, 2, 3")]
@@ -4572,6 +5053,8 @@ pub mod log_settings_request {
         #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
         #[derive(::triton_client_macros::ImplPyNew)]
         #[derive(::triton_client_macros::ImplPyVecAccessors)]
+        #[derive(::triton_client_macros::ImplPyProtoBytes)]
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
         #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
         pub enum ParameterChoice {
             /// @@    .. cpp:var:: bool bool_param
@@ -4603,6 +5086,10 @@ pub mod log_settings_request {
 #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
 #[derive(::triton_client_macros::ImplPyNew)]
 #[derive(::triton_client_macros::ImplPyVecAccessors)]
+#[derive(::triton_client_macros::ImplPyProtoBytes)]
+#[pyclass_accessors(naming = "snake_case")]
+#[derive(::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LogSettingsResponse {
     /// @@  .. cpp:var:: map\<string,SettingValue> settings
@@ -4620,6 +5107,10 @@ pub mod log_settings_response {
     #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
     #[derive(::triton_client_macros::ImplPyNew)]
     #[derive(::triton_client_macros::ImplPyVecAccessors)]
+    #[derive(::triton_client_macros::ImplPyProtoBytes)]
+    #[pyclass_accessors(naming = "snake_case")]
+    #[derive(::serde::Serialize, ::serde::Deserialize)]
+    #[serde(default)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
     pub struct SettingValue {
         #[prost(oneof = "setting_value::ParameterChoice", tags = "1, 2, 3")]
@@ -4630,6 +5121,8 @@ pub mod log_settings_response {
         #[::pyo3::pyclass(get_all, set_all, module = "triton_client")]
         #[derive(::triton_client_macros::ImplPyNew)]
         #[derive(::triton_client_macros::ImplPyVecAccessors)]
+        #[derive(::triton_client_macros::ImplPyProtoBytes)]
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
         #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
         pub enum ParameterChoice {
             /// @@    .. cpp:var:: bool bool_param
@@ -4654,6 +5147,7 @@ pub mod log_settings_response {
     }
 }
 /// Generated client implementations.
+#[cfg(feature = "client")]
 pub mod grpc_inference_service_client {
     #![allow(
         unused_variables,
@@ -5397,6 +5891,7 @@ pub mod grpc_inference_service_client {
     }
 }
 /// Generated server implementations.
+#[cfg(feature = "client")]
 pub mod grpc_inference_service_server {
     #![allow(
         unused_variables,
@@ -0,0 +1,86 @@
+//! JSON (de)serialisation of protobuf messages, for inspection and replay.
+use crate::inference::{ModelConfig, ModelInferRequest, ModelInferResponse};
+use pyo3::prelude::*;
+
+/// serde adapter encoding `bytes` fields as base64 strings.
+pub(crate) mod base64_bytes {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        STANDARD.decode(s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// serde adapter encoding `repeated bytes` fields as lists of base64 strings.
+pub(crate) mod base64_list {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+    use serde::ser::SerializeSeq;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(list: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(list.len()))?;
+        for bytes in list {
+            seq.serialize_element(&STANDARD.encode(bytes))?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Vec<u8>>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .into_iter()
+            .map(|s| STANDARD.decode(s).map_err(serde::de::Error::custom))
+            .collect()
+    }
+}
+
+#[pymethods]
+impl ModelInferRequest {
+    /// Serialise the request to JSON; bytes contents are base64-encoded.
+    fn to_json(&self) -> crate::Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Parse a request previously produced by `to_json`.
+    #[staticmethod]
+    fn from_json(s: &str) -> crate::Result<Self> {
+        Ok(serde_json::from_str(s)?)
+    }
+}
+
+#[pymethods]
+impl ModelInferResponse {
+    /// Serialise the response to JSON; bytes contents are base64-encoded.
+    fn to_json(&self) -> crate::Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Parse a response previously produced by `to_json`.
+    #[staticmethod]
+    fn from_json(s: &str) -> crate::Result<Self> {
+        Ok(serde_json::from_str(s)?)
+    }
+}
+
+#[pymethods]
+impl ModelConfig {
+    /// Serialise the model configuration to JSON.
+    fn to_json(&self) -> crate::Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Parse a model configuration previously produced by `to_json`.
+    #[staticmethod]
+    fn from_json(s: &str) -> crate::Result<Self> {
+        Ok(serde_json::from_str(s)?)
+    }
+}
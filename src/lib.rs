@@ -1,29 +1,71 @@
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "client")]
 pub mod client;
+#[allow(clippy::enum_variant_names, clippy::too_many_arguments)]
 mod inference;
-// mod py_types;
+mod py_types;
 mod utils;
 mod error;
+#[cfg(feature = "client")]
+mod runtime;
+mod dtype_map;
+mod json;
+#[cfg(feature = "client")]
+mod mock;
+#[cfg(feature = "client")]
+mod circuit;
+#[cfg(feature = "client")]
+mod cache;
+#[cfg(feature = "tracing")]
+mod telemetry;
+#[cfg(feature = "prometheus")]
+mod metrics;
 
-pub use client::Client;
+#[cfg(feature = "client")]
+pub use client::{Client, ClientBuilder, ModelReadyStream};
 pub use error::{Error, Result};
 
 use pyo3::prelude::*;
-use tokio::sync::OnceCell;
-
-static TOKIO_RT: OnceCell<tokio::runtime::Runtime> = OnceCell::const_new();
 
 #[pymodule(gil_used = false)]
 fn triton_client(m: &Bound<'_, PyModule>) -> PyResult<()> {
-    utils::init_log(std::env::var("LOG_LEVEL").unwrap_or("INFO".to_string()));
-    let rt = tokio::runtime::Runtime::new()?;
-    if let Err(e) = TOKIO_RT.set(rt) {
-        log::error!("TRITON_CLIENT RUNTIME SET ERROR: {:#}", e);
-    }
+    utils::init_log(
+        std::env::var("LOG_LEVEL").unwrap_or("INFO".to_string()),
+        &std::env::var("LOG_FORMAT").unwrap_or("text".to_string()),
+    );
     m.add("__doc__", "High-performance Triton inference client")?;
+    // The tokio runtime is created lazily so that `init_runtime` can configure it first
+    #[cfg(feature = "client")]
+    {
+        m.add_function(wrap_pyfunction!(runtime::init_runtime, m)?)?;
+        m.add_class::<runtime::Runtime>()?;
+    }
+    m.add_function(wrap_pyfunction!(utils::get_log_handle, m)?)?;
+    m.add_function(wrap_pyfunction!(utils::version, m)?)?;
+    m.add_function(wrap_pyfunction!(utils::triton_proto_version, m)?)?;
+    m.add_class::<utils::LogHandle>()?;
+    #[cfg(feature = "tracing")]
+    m.add_function(wrap_pyfunction!(telemetry::init_tracing, m)?)?;
+    #[cfg(feature = "prometheus")]
+    {
+        metrics::init();
+        m.add_function(wrap_pyfunction!(metrics::metrics_text, m)?)?;
+    }
     // Add client class
-    m.add_class::<Client>()?;
+    #[cfg(feature = "client")]
+    {
+        m.add_class::<Client>()?;
+        m.add_class::<ClientBuilder>()?;
+        m.add_class::<ModelReadyStream>()?;
+    }
+    m.add(
+        "TritonConnectionError",
+        m.py().get_type::<error::TritonConnectionError>(),
+    )?;
+    m.add("TritonBatchError", m.py().get_type::<error::TritonBatchError>())?;
+    m.add("TritonServerError", m.py().get_type::<error::TritonServerError>())?;
+    m.add("TritonTimeoutError", m.py().get_type::<error::TritonTimeoutError>())?;
     // Add request/response types
     m.add_class::<inference::ServerLiveResponse>()?;
     m.add_class::<inference::ServerReadyResponse>()?;
@@ -62,6 +104,7 @@ fn triton_client(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<inference::CudaSharedMemoryRegisterResponse>()?;
     m.add_class::<inference::CudaSharedMemoryUnregisterRequest>()?;
     m.add_class::<inference::CudaSharedMemoryUnregisterResponse>()?;
+    m.add_class::<inference::DataType>()?;
     // child types
     m.add_class::<inference::model_repository_parameter::ParameterChoice>()?;
     m.add_class::<inference::model_metadata_response::TensorMetadata>()?;
@@ -71,6 +114,20 @@ fn triton_client(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<inference::model_infer_request::InferRequestedOutputTensor>()?;
     m.add_class::<inference::model_infer_response::InferOutputTensor>()?;
     m.add_class::<inference::repository_index_response::ModelIndex>()?;
+    m.add_class::<inference::StatisticDuration>()?;
+    m.add_class::<inference::InferStatistics>()?;
+    m.add_class::<inference::InferResponseStatistics>()?;
+    m.add_class::<inference::InferBatchStatistics>()?;
+    m.add_class::<inference::MemoryUsage>()?;
+    m.add(
+        "TraceSettingValue",
+        m.py().get_type::<inference::trace_setting_response::SettingValue>(),
+    )?;
+    // same Python name as the system shared memory `RegionStatus`
+    m.add(
+        "CudaRegionStatus",
+        m.py().get_type::<inference::cuda_shared_memory_status_response::RegionStatus>(),
+    )?;
     // Add List and VecRef types
     m.add_class::<py_vec_types::ListBool>()?;
     m.add_class::<py_vec_types::ListI8>()?;
@@ -83,5 +140,16 @@ fn triton_client(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<py_vec_types::ListU64>()?;
     m.add_class::<py_vec_types::ListF32>()?;
     m.add_class::<py_vec_types::ListF64>()?;
+    m.add_class::<py_vec_types::VecRefBool>()?;
+    m.add_class::<py_vec_types::VecRefI8>()?;
+    m.add_class::<py_vec_types::VecRefI16>()?;
+    m.add_class::<py_vec_types::VecRefI32>()?;
+    m.add_class::<py_vec_types::VecRefI64>()?;
+    m.add_class::<py_vec_types::VecRefU8>()?;
+    m.add_class::<py_vec_types::VecRefU16>()?;
+    m.add_class::<py_vec_types::VecRefU32>()?;
+    m.add_class::<py_vec_types::VecRefU64>()?;
+    m.add_class::<py_vec_types::VecRefF32>()?;
+    m.add_class::<py_vec_types::VecRefF64>()?;
     Ok(())
 }
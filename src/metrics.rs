@@ -0,0 +1,136 @@
+//! Prometheus metrics for `Client` requests, enabled by the `prometheus` feature.
+use prometheus::{
+    HistogramOpts, HistogramVec, IntCounter, IntCounterVec, Opts, Registry, TextEncoder,
+};
+use pyo3::prelude::*;
+use std::sync::LazyLock;
+use std::time::Instant;
+use tonic::Status;
+
+struct Metrics {
+    registry: Registry,
+    requests: IntCounterVec,
+    duration: HistogramVec,
+    bytes_sent: IntCounter,
+    bytes_received: IntCounter,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new();
+        let requests = IntCounterVec::new(
+            Opts::new(
+                "triton_requests_total",
+                "Total gRPC requests sent to Triton",
+            ),
+            &["method", "server", "status"],
+        )
+        .expect("valid metric");
+        let duration = HistogramVec::new(
+            HistogramOpts::new(
+                "triton_request_duration_seconds",
+                "gRPC request latency in seconds",
+            ),
+            &["method", "server"],
+        )
+        .expect("valid metric");
+        let bytes_sent = IntCounter::new(
+            "triton_bytes_sent_total",
+            "Encoded bytes of request messages",
+        )
+        .expect("valid metric");
+        let bytes_received = IntCounter::new(
+            "triton_bytes_received_total",
+            "Encoded bytes of response messages",
+        )
+        .expect("valid metric");
+        registry
+            .register(Box::new(requests.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(duration.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(bytes_sent.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(bytes_received.clone()))
+            .expect("unique metric");
+        Self {
+            registry,
+            requests,
+            duration,
+            bytes_sent,
+            bytes_received,
+        }
+    }
+}
+
+static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::new);
+
+/// Create the global registry; called from module init.
+pub(crate) fn init() {
+    LazyLock::force(&METRICS);
+}
+
+/// Record the outcome of one gRPC call to `server` started at `start` with a `sent`-byte
+/// request; a retried call is recorded once per attempt.
+pub(crate) fn observe<Resp: prost::Message>(
+    method: &str,
+    server: &str,
+    start: Instant,
+    sent: usize,
+    result: &Result<tonic::Response<Resp>, Status>,
+) {
+    let status = match result {
+        Ok(_) => "Ok".to_string(),
+        Err(status) => format!("{:?}", status.code()),
+    };
+    METRICS
+        .requests
+        .with_label_values(&[method, server, status.as_str()])
+        .inc();
+    METRICS
+        .duration
+        .with_label_values(&[method, server])
+        .observe(start.elapsed().as_secs_f64());
+    METRICS.bytes_sent.inc_by(sent as u64);
+    if let Ok(response) = result {
+        METRICS
+            .bytes_received
+            .inc_by(response.get_ref().encoded_len() as u64);
+    }
+}
+
+/// Metrics in the Prometheus text exposition format.
+#[pyfunction]
+pub fn metrics_text() -> PyResult<String> {
+    TextEncoder::new()
+        .encode_to_string(&METRICS.registry.gather())
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attempts_are_labelled_with_their_server() {
+        let failed: Result<tonic::Response<()>, Status> = Err(Status::unavailable("down"));
+        observe("test_call", "http://a:8001", Instant::now(), 10, &failed);
+        observe(
+            "test_call",
+            "http://b:8001",
+            Instant::now(),
+            10,
+            &Ok(tonic::Response::new(())),
+        );
+        let text = metrics_text().unwrap();
+        assert!(text.contains(
+            r#"triton_requests_total{method="test_call",server="http://a:8001",status="Unavailable"} 1"#
+        ));
+        assert!(text.contains(
+            r#"triton_requests_total{method="test_call",server="http://b:8001",status="Ok"} 1"#
+        ));
+    }
+}
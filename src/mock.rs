@@ -0,0 +1,62 @@
+//! Canned responses for [`Client::mock`](crate::client::Client), used to test code without a server.
+use crate::error::Error;
+use pyo3::IntoPyObjectExt;
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+#[derive(Debug)]
+pub(crate) struct Mock {
+    /// Method name to a response object, or a callable taking the request.
+    responses: HashMap<String, Py<PyAny>>,
+    /// Requests received so far, keyed by method name.
+    calls: Mutex<HashMap<String, Vec<Py<PyAny>>>>,
+}
+
+impl Mock {
+    pub(crate) fn new(responses: HashMap<String, Py<PyAny>>) -> Self {
+        Self {
+            responses,
+            calls: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Record `req` and answer it from the configured responses.
+    pub(crate) fn respond<Req, Resp>(&self, method: &str, req: Req) -> Result<Resp, Error>
+    where
+        Req: for<'py> IntoPyObject<'py>,
+        Resp: pyo3::PyClass + Clone,
+    {
+        Python::attach(|py| {
+            let req = req.into_bound_py_any(py)?;
+            self.calls
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .entry(method.to_string())
+                .or_default()
+                .push(req.clone().unbind());
+            let response = self
+                .responses
+                .get(method)
+                .ok_or_else(|| Error::msg(format!("no mock response for {}", method)))?
+                .bind(py);
+            let response = if response.is_callable() {
+                response.call1((req,))?
+            } else {
+                response.clone()
+            };
+            let response = response.cast::<Resp>().map_err(PyErr::from)?;
+            Ok(response.try_borrow().map_err(PyErr::from)?.clone())
+        })
+    }
+
+    /// Requests recorded for `method`, oldest first.
+    pub(crate) fn calls_to(&self, py: Python<'_>, method: &str) -> Vec<Py<PyAny>> {
+        self.calls
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(method)
+            .map(|calls| calls.iter().map(|c| c.clone_ref(py)).collect())
+            .unwrap_or_default()
+    }
+}
@@ -1,11 +1,221 @@
-use crate::inference::InferTensorContents;
+use crate::inference::infer_parameter::ParameterChoice;
+use crate::inference::model_infer_request::InferInputTensor;
+use crate::inference::model_infer_response::InferOutputTensor;
+use crate::inference::model_metadata_response::TensorMetadata;
+use crate::inference::model_repository_parameter::ParameterChoice as RepositoryParameterChoice;
+use crate::inference::trace_setting_response::SettingValue;
+use crate::inference::{
+    CudaSharedMemoryRegisterRequest, InferParameter, InferTensorContents, ModelConfig,
+    ModelInferRequest, ModelInferResponse, ModelMetadataResponse, ModelReadyResponse,
+    ModelRepositoryParameter, ModelStatistics, ModelStatisticsResponse, ServerLiveResponse,
+    ServerMetadataResponse, ServerReadyResponse, SystemSharedMemoryRegisterRequest,
+    TraceSettingResponse,
+};
 use numpy::{
-    PyArray1, PyArray2, PyArrayMethods, PyReadonlyArray1, PyReadonlyArray2, PyUntypedArrayMethods,
+    PyArray1, PyArray2, PyArrayDescrMethods, PyArrayMethods, PyReadonlyArray1, PyReadonlyArray2,
+    PyUntypedArray, PyUntypedArrayMethods,
 };
+use pyo3::IntoPyObjectExt;
 use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple};
+use std::collections::HashMap;
+
+impl InferTensorContents {
+    /// Names of the non-empty content fields.
+    fn populated_fields(&self) -> Vec<&'static str> {
+        [
+            ("bool_contents", self.bool_contents.is_empty()),
+            ("int_contents", self.int_contents.is_empty()),
+            ("int64_contents", self.int64_contents.is_empty()),
+            ("uint_contents", self.uint_contents.is_empty()),
+            ("uint64_contents", self.uint64_contents.is_empty()),
+            ("fp32_contents", self.fp32_contents.is_empty()),
+            ("fp64_contents", self.fp64_contents.is_empty()),
+            ("bytes_contents", self.bytes_contents.is_empty()),
+        ]
+        .into_iter()
+        .filter_map(|(name, empty)| (!empty).then_some(name))
+        .collect()
+    }
+}
 
 #[pymethods]
 impl InferTensorContents {
+    /// Whether every content field is empty.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.element_count() == 0
+    }
+
+    /// Total number of elements across all content fields.
+    ///
+    /// At most one field should be populated in a well-formed request.
+    pub(crate) fn element_count(&self) -> usize {
+        self.bool_contents.len()
+            + self.int_contents.len()
+            + self.int64_contents.len()
+            + self.uint_contents.len()
+            + self.uint64_contents.len()
+            + self.fp32_contents.len()
+            + self.fp64_contents.len()
+            + self.bytes_contents.len()
+    }
+
+    /// Payload size in bytes of the contents as Triton serialises them, e.g. for admission
+    /// control before sending a request.
+    ///
+    /// Each `bytes_contents` element counts its 4-byte length prefix.
+    pub(crate) fn total_byte_size(&self) -> usize {
+        self.bool_contents.len()
+            + self.int_contents.len() * 4
+            + self.int64_contents.len() * 8
+            + self.uint_contents.len() * 4
+            + self.uint64_contents.len() * 8
+            + self.fp32_contents.len() * 4
+            + self.fp64_contents.len() * 8
+            + self
+                .bytes_contents
+                .iter()
+                .map(|b| 4 + b.len())
+                .sum::<usize>()
+    }
+
+    /// Concatenate per-sample contents into one, e.g. to build a batched request.
+    ///
+    /// Every non-empty part must populate the same single content field; raises `ValueError`
+    /// otherwise.
+    #[staticmethod]
+    fn combine(parts: Vec<InferTensorContents>) -> crate::Result<Self> {
+        let mut field = None;
+        for (i, part) in parts.iter().enumerate() {
+            let populated = part.populated_fields();
+            match (populated.as_slice(), field) {
+                ([], _) => {}
+                ([name], None) => field = Some(*name),
+                ([name], Some(expected)) if *name == expected => {}
+                _ => {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "cannot combine contents: part {} uses {}, expected {}",
+                        i,
+                        populated.join(", "),
+                        field.unwrap_or("a single content field")
+                    ))
+                    .into());
+                }
+            }
+        }
+        let mut combined = Self::default();
+        for part in parts {
+            combined.bool_contents.extend(part.bool_contents);
+            combined.int_contents.extend(part.int_contents);
+            combined.int64_contents.extend(part.int64_contents);
+            combined.uint_contents.extend(part.uint_contents);
+            combined.uint64_contents.extend(part.uint64_contents);
+            combined.fp32_contents.extend(part.fp32_contents);
+            combined.fp64_contents.extend(part.fp64_contents);
+            combined.bytes_contents.extend(part.bytes_contents);
+        }
+        Ok(combined)
+    }
+
+    /// Build contents from an N-D numpy array, flattening it into the field matching its dtype.
+    ///
+    /// 8/16-bit integers are widened into `int_contents` / `uint_contents` as in the Triton
+    /// protocol. The shape is not stored; set it on `InferInputTensor`.
+    #[staticmethod]
+    pub(crate) fn from_numpy_nd<'py>(py: Python<'py>, arr: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let arr = arr.cast::<PyUntypedArray>()?;
+        let dtype = arr.dtype();
+        let flat = arr.call_method1("reshape", (-1,))?;
+        macro_rules! flatten {
+            ($t:ty) => {
+                flat.extract::<PyReadonlyArray1<$t>>()?
+                    .as_array()
+                    .iter()
+                    .map(|&v| v.into())
+                    .collect()
+            };
+        }
+        let mut contents = Self::default();
+        if dtype.is_equiv_to(&numpy::dtype::<bool>(py)) {
+            contents.bool_contents = flatten!(bool);
+        } else if dtype.is_equiv_to(&numpy::dtype::<i8>(py)) {
+            contents.int_contents = flatten!(i8);
+        } else if dtype.is_equiv_to(&numpy::dtype::<i16>(py)) {
+            contents.int_contents = flatten!(i16);
+        } else if dtype.is_equiv_to(&numpy::dtype::<i32>(py)) {
+            contents.int_contents = flatten!(i32);
+        } else if dtype.is_equiv_to(&numpy::dtype::<i64>(py)) {
+            contents.int64_contents = flatten!(i64);
+        } else if dtype.is_equiv_to(&numpy::dtype::<u8>(py)) {
+            contents.uint_contents = flatten!(u8);
+        } else if dtype.is_equiv_to(&numpy::dtype::<u16>(py)) {
+            contents.uint_contents = flatten!(u16);
+        } else if dtype.is_equiv_to(&numpy::dtype::<u32>(py)) {
+            contents.uint_contents = flatten!(u32);
+        } else if dtype.is_equiv_to(&numpy::dtype::<u64>(py)) {
+            contents.uint64_contents = flatten!(u64);
+        } else if dtype.is_equiv_to(&numpy::dtype::<f32>(py)) {
+            contents.fp32_contents = flatten!(f32);
+        } else if dtype.is_equiv_to(&numpy::dtype::<f64>(py)) {
+            contents.fp64_contents = flatten!(f64);
+        } else {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "unsupported numpy dtype: {}",
+                dtype
+            )));
+        }
+        Ok(contents)
+    }
+
+    /// Build contents from a list or other iterable of Python values, stored in the field
+    /// matching the Triton datatype `dtype`, e.g. `from_list("FP32", [1.0, 2.0])`.
+    ///
+    /// `BYTES` elements may be `bytes` or `str`. Raises `ValueError` for datatypes without a
+    /// content field, such as `FP16`.
+    #[staticmethod]
+    fn from_list(dtype: &str, data: &Bound<'_, PyAny>) -> PyResult<Self> {
+        macro_rules! collect {
+            ($t:ty) => {
+                data.try_iter()?
+                    .map(|v| Ok(v?.extract::<$t>()?.into()))
+                    .collect::<PyResult<_>>()?
+            };
+        }
+        let mut contents = Self::default();
+        match dtype {
+            "BOOL" => contents.bool_contents = collect!(bool),
+            "INT8" => contents.int_contents = collect!(i8),
+            "INT16" => contents.int_contents = collect!(i16),
+            "INT32" => contents.int_contents = collect!(i32),
+            "INT64" => contents.int64_contents = collect!(i64),
+            "UINT8" => contents.uint_contents = collect!(u8),
+            "UINT16" => contents.uint_contents = collect!(u16),
+            "UINT32" => contents.uint_contents = collect!(u32),
+            "UINT64" => contents.uint64_contents = collect!(u64),
+            "FP32" => contents.fp32_contents = collect!(f32),
+            "FP64" => contents.fp64_contents = collect!(f64),
+            "BYTES" => {
+                contents.bytes_contents = data
+                    .try_iter()?
+                    .map(|v| {
+                        let v = v?;
+                        match v.cast::<PyString>() {
+                            Ok(s) => Ok(s.to_str()?.as_bytes().to_vec()),
+                            Err(_) => Ok(v.cast::<PyBytes>()?.as_bytes().to_vec()),
+                        }
+                    })
+                    .collect::<PyResult<_>>()?
+            }
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "unsupported datatype for from_list: {:?}",
+                    dtype
+                )));
+            }
+        }
+        Ok(contents)
+    }
+
     #[pyo3(signature = (src=None))]
     fn replace_bool_contents<'py>(
         &mut self,
@@ -111,26 +321,1064 @@ impl InferTensorContents {
         let contents = std::mem::replace(&mut self.fp64_contents, src);
         Ok(PyArray1::from_vec(py, contents))
     }
+    /// Accepts either a list of `bytes` or a 2-D `uint8` numpy array (one row per element).
+    ///
+    /// Returns the old contents as a 2-D `uint8` array, or as a list of `bytes` when the
+    /// rows differ in length.
     #[pyo3(signature = (src=None))]
     fn replace_bytes_contents<'py>(
         &mut self,
         py: Python<'py>,
-        src: Option<PyReadonlyArray2<u8>>,
-    ) -> crate::Result<Bound<'py, PyArray2<u8>>> {
+        src: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
         let src = match src {
             None => {
                 vec![]
             }
             Some(src) => {
-                let shape = src.shape();
-                let num_cols = shape[1];
-                let a = src.as_slice()?;
-                a.chunks_exact(num_cols)
-                    .map(|chunk| chunk.to_vec())
-                    .collect()
+                if let Ok(list) = src.cast::<PyList>() {
+                    list.iter()
+                        .map(|item| match item.cast::<PyBytes>() {
+                            Ok(bytes) => Ok(bytes.as_bytes().to_vec()),
+                            Err(_) => item.extract::<Vec<u8>>(),
+                        })
+                        .collect::<PyResult<Vec<_>>>()?
+                } else {
+                    let src = src.extract::<PyReadonlyArray2<u8>>()?;
+                    let (num_rows, num_cols) = (src.shape()[0], src.shape()[1]);
+                    if num_cols == 0 {
+                        vec![vec![]; num_rows]
+                    } else {
+                        src.as_slice()?
+                            .chunks_exact(num_cols)
+                            .map(|chunk| chunk.to_vec())
+                            .collect()
+                    }
+                }
             }
         };
         let contents = std::mem::replace(&mut self.bytes_contents, src);
-        Ok(PyArray2::from_vec2(py, contents.as_slice())?)
+        let ragged = contents.windows(2).any(|w| w[0].len() != w[1].len());
+        if ragged {
+            return Ok(
+                PyList::new(py, contents.iter().map(|row| PyBytes::new(py, row)))?.into_any(),
+            );
+        }
+        Ok(PyArray2::from_vec2(py, contents.as_slice())?.into_any())
+    }
+}
+
+/// Describe invalid dimensions of a config tensor; `-1` (variable size) is allowed.
+fn check_dims(errors: &mut Vec<String>, kind: &str, name: &str, dims: &[i64]) {
+    if name.is_empty() {
+        errors.push(format!("{} name must not be empty", kind));
+    }
+    if let Some(dim) = dims.iter().find(|&&d| d == 0 || d < -1) {
+        errors.push(format!(
+            "{} '{}' has invalid dimension {} in {:?}",
+            kind, name, dim, dims
+        ));
+    }
+}
+
+#[pymethods]
+impl ModelConfig {
+    /// Check the configuration for common mistakes.
+    ///
+    /// Returns a list of error messages; an empty list means the config is valid.
+    fn validate(&self) -> Vec<String> {
+        let mut errors = vec![];
+        if self.name.is_empty() {
+            errors.push("name must not be empty".to_string());
+        }
+        if self.input.is_empty() {
+            errors.push("input must not be empty".to_string());
+        }
+        if self.output.is_empty() {
+            errors.push("output must not be empty".to_string());
+        }
+        for input in &self.input {
+            check_dims(&mut errors, "input", &input.name, &input.dims);
+        }
+        for output in &self.output {
+            check_dims(&mut errors, "output", &output.name, &output.dims);
+        }
+        errors
+    }
+}
+
+#[pymethods]
+impl InferParameter {
+    /// Build a parameter from a Python `bool`, `int`, `float` or `str`.
+    ///
+    /// Integers beyond the `int64` range are stored as `uint64`. A `float` is stored as
+    /// `double_param` rather than a formatted `string_param`, so it keeps full precision and
+    /// `to_value` returns a `float` again.
+    #[staticmethod]
+    pub(crate) fn from_value(val: &Bound<'_, PyAny>) -> PyResult<Self> {
+        // `bool` is a subclass of `int`, so it must be checked first
+        let choice = if val.is_instance_of::<PyBool>() {
+            ParameterChoice::BoolParam(val.extract()?)
+        } else if val.is_instance_of::<PyInt>() {
+            match val.extract::<i64>() {
+                Ok(v) => ParameterChoice::Int64Param(v),
+                Err(_) => ParameterChoice::Uint64Param(val.extract()?),
+            }
+        } else if val.is_instance_of::<PyFloat>() {
+            ParameterChoice::DoubleParam(val.extract()?)
+        } else if val.is_instance_of::<PyString>() {
+            ParameterChoice::StringParam(val.extract()?)
+        } else {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "unsupported parameter type: {}",
+                val.get_type().name()?
+            )));
+        };
+        Ok(Self {
+            parameter_choice: Some(choice),
+        })
+    }
+
+    /// The inner value as a native Python object, or `None` if unset.
+    pub(crate) fn to_value(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        match &self.parameter_choice {
+            None => Ok(py.None()),
+            Some(ParameterChoice::BoolParam(v)) => v.into_py_any(py),
+            Some(ParameterChoice::Int64Param(v)) => v.into_py_any(py),
+            Some(ParameterChoice::StringParam(v)) => v.into_py_any(py),
+            Some(ParameterChoice::DoubleParam(v)) => v.into_py_any(py),
+            Some(ParameterChoice::Uint64Param(v)) => v.into_py_any(py),
+        }
+    }
+}
+
+fn parameters_from_dict(params: &Bound<'_, PyDict>) -> PyResult<HashMap<String, InferParameter>> {
+    let mut parsed = HashMap::with_capacity(params.len());
+    for (key, value) in params.iter() {
+        parsed.insert(
+            key.extract::<String>()?,
+            InferParameter::from_value(&value)?,
+        );
+    }
+    Ok(parsed)
+}
+
+fn parameters_to_dict<'py>(
+    py: Python<'py>,
+    params: &HashMap<String, InferParameter>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    for (key, param) in params {
+        dict.set_item(key, param.to_value(py)?)?;
+    }
+    Ok(dict)
+}
+
+impl ModelInferRequest {
+    fn set_parameter(&mut self, key: &str, choice: ParameterChoice) -> &mut Self {
+        self.parameters.insert(
+            key.to_string(),
+            InferParameter {
+                parameter_choice: Some(choice),
+            },
+        );
+        self
+    }
+}
+
+#[pymethods]
+impl ModelInferRequest {
+    /// Insert every `key: value` of `params` into `parameters`.
+    ///
+    /// Values go through `InferParameter.from_value`; nothing is inserted if any fails.
+    fn set_parameters_from_dict(&mut self, params: &Bound<'_, PyDict>) -> PyResult<()> {
+        self.parameters.extend(parameters_from_dict(params)?);
+        Ok(())
+    }
+
+    /// Check inputs for shape, datatype and content problems before sending.
+    ///
+    /// Every input needs exactly one data source: `contents`, its `raw_input_contents` entry,
+    /// or a region set by `InferInputTensor.set_shared_memory`. Triton rejects requests mixing
+    /// the first two: once `raw_input_contents` is used, no input may set `contents`.
+    ///
+    /// Returns a list of error messages; an empty list means the request is valid.
+    pub(crate) fn validate(&self) -> Vec<String> {
+        let mut errors = vec![];
+        let has_raw = !self.raw_input_contents.is_empty();
+        if has_raw && self.raw_input_contents.len() != self.inputs.len() {
+            errors.push(format!(
+                "raw_input_contents has {} entries but there are {} inputs",
+                self.raw_input_contents.len(),
+                self.inputs.len()
+            ));
+        }
+        for (i, input) in self.inputs.iter().enumerate() {
+            let name = if input.name.is_empty() {
+                errors.push(format!("input {} has an empty name", i));
+                format!("#{}", i)
+            } else {
+                input.name.clone()
+            };
+            if crate::dtype_map::from_triton(&input.datatype).is_none() {
+                errors.push(format!(
+                    "input {}: unknown datatype {:?}",
+                    name, input.datatype
+                ));
+            }
+            if input.shape.is_empty() {
+                errors.push(format!("input {}: shape must not be empty", name));
+            }
+            let has_contents = input.contents.as_ref().is_some_and(|c| !c.is_empty());
+            let has_raw_contents = self
+                .raw_input_contents
+                .get(i)
+                .is_some_and(|raw| !raw.is_empty());
+            // `set_shared_memory` inputs are read from the region instead
+            let has_shared_memory = input.parameters.contains_key("shared_memory_region");
+            let sources: Vec<_> = [
+                (has_contents, "contents"),
+                (has_raw_contents, "raw_input_contents"),
+                (has_shared_memory, "a shared memory region"),
+            ]
+            .into_iter()
+            .filter_map(|(set, source)| set.then_some(source))
+            .collect();
+            match sources.as_slice() {
+                [] => errors.push(format!("input {}: no data", name)),
+                [_] if has_raw && has_contents => errors.push(format!(
+                    "input {}: contents must not be set when raw_input_contents is used",
+                    name
+                )),
+                [_] => {}
+                sources => errors.push(format!(
+                    "input {}: data is set in both {}",
+                    name,
+                    sources.join(" and ")
+                )),
+            }
+        }
+        errors
+    }
+
+    /// Size in bytes the request takes on the wire, to check it against gRPC message size
+    /// limits before sending.
+    ///
+    /// This is the protobuf encoded length of the inputs, raw contents and all other fields,
+    /// plus the 5-byte gRPC message prefix.
+    pub(crate) fn estimated_wire_size(&self) -> usize {
+        prost::Message::encoded_len(self) + 5
+    }
+
+    /// Set the `sequence_id` request parameter.
+    fn set_sequence_id(&mut self, id: u64) -> PyResult<()> {
+        // Triton reads sequence ids from `int64_param`
+        let id = i64::try_from(id).map_err(|_| {
+            pyo3::exceptions::PyOverflowError::new_err("sequence_id does not fit in int64")
+        })?;
+        self.set_parameter("sequence_id", ParameterChoice::Int64Param(id));
+        Ok(())
+    }
+
+    /// Set the `sequence_start` request parameter.
+    fn set_sequence_start(&mut self, v: bool) {
+        self.set_parameter("sequence_start", ParameterChoice::BoolParam(v));
+    }
+
+    /// Set the `sequence_end` request parameter.
+    fn set_sequence_end(&mut self, v: bool) {
+        self.set_parameter("sequence_end", ParameterChoice::BoolParam(v));
+    }
+
+    /// Set the `priority` request parameter; 0 means the model's default.
+    fn set_priority(&mut self, priority: u64) {
+        self.set_parameter("priority", ParameterChoice::Uint64Param(priority));
+    }
+
+    /// Set the `timeout` request parameter, in microseconds.
+    fn set_timeout_microseconds(&mut self, timeout: u64) -> PyResult<()> {
+        let timeout = i64::try_from(timeout).map_err(|_| {
+            pyo3::exceptions::PyOverflowError::new_err("timeout does not fit in int64")
+        })?;
+        self.set_parameter("timeout", ParameterChoice::Int64Param(timeout));
+        Ok(())
+    }
+
+    /// `parameters` as a dict of native Python values.
+    fn parameters_to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        parameters_to_dict(py, &self.parameters)
+    }
+
+    /// Copy the bytes of a C-contiguous numpy array into `raw_input_contents[index]`.
+    ///
+    /// The array's dtype is not checked against the input: `shape` and `datatype` must already
+    /// be set on `inputs[index]`. Missing `raw_input_contents` entries before `index` are
+    /// filled with empty bytes, to be filled in by later calls.
+    ///
+    /// Triton requires every input of a request using `raw_input_contents` to be raw, so this
+    /// raises `ValueError` while another input still sets `contents`.
+    fn add_raw_input_from_numpy(&mut self, index: usize, arr: &Bound<'_, PyAny>) -> PyResult<()> {
+        if index >= self.inputs.len() {
+            return Err(pyo3::exceptions::PyIndexError::new_err(format!(
+                "input index {} out of range for {} inputs",
+                index,
+                self.inputs.len()
+            )));
+        }
+        if let Some(typed) = self.inputs.iter().enumerate().find(|(i, input)| {
+            *i != index && input.contents.as_ref().is_some_and(|c| !c.is_empty())
+        }) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "input {:?} sets contents; raw and typed inputs cannot be mixed",
+                typed.1.name
+            )));
+        }
+        let untyped = arr.cast::<PyUntypedArray>()?;
+        if !untyped.is_c_contiguous() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "array must be C-contiguous, e.g. use numpy.ascontiguousarray",
+            ));
+        }
+        if untyped.dtype().kind() == b'O' {
+            return Err(pyo3::exceptions::PyTypeError::new_err(
+                "object arrays have no raw byte representation",
+            ));
+        }
+        // reshape/view of a contiguous array does not copy
+        let bytes = arr
+            .call_method1("reshape", (-1,))?
+            .call_method1("view", ("uint8",))?;
+        let bytes = bytes.extract::<PyReadonlyArray1<u8>>()?;
+        let data = bytes.as_slice()?.to_vec();
+        if self.raw_input_contents.len() <= index {
+            self.raw_input_contents.resize(index + 1, vec![]);
+        }
+        self.raw_input_contents[index] = data;
+        Ok(())
+    }
+}
+
+/// `to_dict` and dict parsing shared by `InferInputTensor` and `InferOutputTensor`.
+///
+/// Keys are `name`, `datatype`, `shape`, `parameters` and `contents`.
+macro_rules! impl_tensor_dict {
+    ($ty:ty) => {
+        #[pymethods]
+        impl $ty {
+            /// Dict with the same keys accepted by `from_dict`.
+            fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+                let dict = PyDict::new(py);
+                dict.set_item("name", &self.name)?;
+                dict.set_item("datatype", &self.datatype)?;
+                dict.set_item("shape", &self.shape)?;
+                dict.set_item("parameters", parameters_to_dict(py, &self.parameters)?)?;
+                dict.set_item("contents", self.contents.clone())?;
+                Ok(dict)
+            }
+        }
+
+        impl $ty {
+            /// Overwrite the fields whose keys are present in `d`.
+            fn update_from_dict(&mut self, d: &Bound<'_, PyDict>) -> PyResult<()> {
+                if let Some(v) = d.get_item("name")? {
+                    self.name = v.extract()?;
+                }
+                if let Some(v) = d.get_item("datatype")? {
+                    self.datatype = v.extract()?;
+                }
+                if let Some(v) = d.get_item("shape")? {
+                    self.shape = v.extract()?;
+                }
+                if let Some(v) = d.get_item("parameters")? {
+                    self.parameters = parameters_from_dict(v.cast()?)?;
+                }
+                if let Some(v) = d.get_item("contents")? {
+                    self.contents = v.extract()?;
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_tensor_dict!(InferInputTensor);
+impl_tensor_dict!(InferOutputTensor);
+
+#[pymethods]
+impl InferOutputTensor {
+    /// Build from a dict with the keys produced by `to_dict`; missing keys keep their defaults.
+    #[staticmethod]
+    fn from_dict(d: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let mut tensor = Self::default();
+        tensor.update_from_dict(d)?;
+        Ok(tensor)
+    }
+}
+
+#[pymethods]
+impl InferInputTensor {
+    /// Build a tensor named `name` from a numpy array, taking `shape` and `datatype` from it.
+    #[staticmethod]
+    fn from_numpy<'py>(py: Python<'py>, name: String, arr: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let untyped = arr.cast::<PyUntypedArray>()?;
+        let dtype = untyped.dtype().to_string();
+        let datatype = crate::dtype_map::from_numpy(&dtype)
+            .and_then(crate::dtype_map::to_triton)
+            .ok_or_else(|| {
+                pyo3::exceptions::PyTypeError::new_err(format!(
+                    "unsupported numpy dtype: {}",
+                    dtype
+                ))
+            })?;
+        Ok(Self {
+            name,
+            datatype: datatype.to_string(),
+            shape: untyped.shape().iter().map(|&d| d as i64).collect(),
+            contents: Some(InferTensorContents::from_numpy_nd(py, arr)?),
+            ..Default::default()
+        })
+    }
+
+    /// Copy of this tensor declaring `new_shape`; contents are kept as is.
+    ///
+    /// The element count must not change. One dimension may be `-1` to infer it, as in numpy.
+    fn reshape(&self, new_shape: Vec<i64>) -> PyResult<Self> {
+        let invalid = |msg: String| pyo3::exceptions::PyValueError::new_err(msg);
+        let count: i64 = self.shape.iter().product();
+        let mut inferred = None;
+        let mut known: i64 = 1;
+        for (i, &dim) in new_shape.iter().enumerate() {
+            match dim {
+                -1 if inferred.is_none() => inferred = Some(i),
+                -1 => return Err(invalid("can only specify one unknown dimension".into())),
+                d if d < 0 => return Err(invalid(format!("invalid dimension {}", d))),
+                d => {
+                    known = known
+                        .checked_mul(d)
+                        .ok_or_else(|| invalid(format!("shape {:?} is too large", new_shape)))?
+                }
+            }
+        }
+        let mut shape = new_shape;
+        if let Some(i) = inferred {
+            if known == 0 || count % known != 0 {
+                return Err(invalid(format!(
+                    "cannot reshape tensor of {} elements into shape {:?}",
+                    count, shape
+                )));
+            }
+            shape[i] = count / known;
+        } else if known != count {
+            return Err(invalid(format!(
+                "cannot reshape tensor of {} elements into shape {:?}",
+                count, shape
+            )));
+        }
+        Ok(Self {
+            shape,
+            ..self.clone()
+        })
+    }
+
+    /// Set a single `BYTES` element from `data`, stored in `contents.bytes_contents`.
+    ///
+    /// `datatype` becomes `"BYTES"` and an empty `shape` becomes `[1]`. In
+    /// `ModelInferRequest.raw_input_contents` Triton instead expects `BYTES` tensors serialized
+    /// as each element's length as a 4-byte little-endian integer followed by its bytes.
+    fn set_bytes_content(&mut self, data: &Bound<'_, PyBytes>) -> PyResult<()> {
+        if !self.datatype.is_empty() && self.datatype != "BYTES" {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "set_bytes_content requires datatype BYTES, tensor has {}",
+                self.datatype
+            )));
+        }
+        self.datatype = "BYTES".to_string();
+        if self.shape.is_empty() {
+            self.shape = vec![1];
+        }
+        self.contents = Some(InferTensorContents {
+            bytes_contents: vec![data.as_bytes().to_vec()],
+            ..Default::default()
+        });
+        Ok(())
+    }
+
+    /// The single `BYTES` element set by `set_bytes_content`; raises `ValueError` unless the
+    /// tensor holds exactly one.
+    fn get_bytes_content<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        match self.contents.as_ref().map(|c| c.bytes_contents.as_slice()) {
+            Some([data]) => Ok(PyBytes::new(py, data)),
+            other => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "expected a single BYTES element, tensor has {}",
+                other.map_or(0, <[_]>::len)
+            ))),
+        }
+    }
+
+    /// Read this input from a registered shared memory region instead of `contents`.
+    #[pyo3(signature = (region, byte_size, offset=0))]
+    fn set_shared_memory(&mut self, region: String, byte_size: u64, offset: u64) -> PyResult<()> {
+        let byte_size = i64::try_from(byte_size).map_err(|_| {
+            pyo3::exceptions::PyOverflowError::new_err("byte_size does not fit in int64")
+        })?;
+        let offset = i64::try_from(offset).map_err(|_| {
+            pyo3::exceptions::PyOverflowError::new_err("offset does not fit in int64")
+        })?;
+        for (key, choice) in [
+            ("shared_memory_region", ParameterChoice::StringParam(region)),
+            (
+                "shared_memory_byte_size",
+                ParameterChoice::Int64Param(byte_size),
+            ),
+            ("shared_memory_offset", ParameterChoice::Int64Param(offset)),
+        ] {
+            self.parameters.insert(
+                key.to_string(),
+                InferParameter {
+                    parameter_choice: Some(choice),
+                },
+            );
+        }
+        self.contents = None;
+        Ok(())
+    }
+
+    /// Build from a dict with the keys produced by `to_dict`; missing keys keep their defaults.
+    ///
+    /// A `data` numpy array goes through `from_numpy`, and explicit keys override what it sets.
+    #[staticmethod]
+    fn from_dict<'py>(py: Python<'py>, d: &Bound<'py, PyDict>) -> PyResult<Self> {
+        let mut tensor = match d.get_item("data")? {
+            Some(data) => Self::from_numpy(py, String::new(), &data)?,
+            None => Self::default(),
+        };
+        tensor.update_from_dict(d)?;
+        Ok(tensor)
+    }
+}
+
+#[pymethods]
+impl TraceSettingResponse {
+    /// Trace settings as a `dict[str, list[str]]`.
+    ///
+    /// Every value is a list, even with a single element, since a setting may hold several.
+    /// The `TraceSettingValue` objects are available as `setting_values`.
+    #[getter]
+    fn settings(&self) -> HashMap<String, Vec<String>> {
+        self.settings
+            .iter()
+            .map(|(key, setting)| (key.clone(), setting.value.clone()))
+            .collect()
+    }
+
+    #[setter]
+    fn set_settings(&mut self, settings: HashMap<String, Vec<String>>) {
+        self.settings = settings
+            .into_iter()
+            .map(|(key, value)| (key, SettingValue { value }))
+            .collect();
+    }
+}
+
+#[pymethods]
+impl SystemSharedMemoryRegisterRequest {
+    /// Register request for a region holding `arr`, sized from `arr.nbytes`.
+    ///
+    /// If `byte_size` is given it must equal `arr.nbytes`.
+    #[staticmethod]
+    #[pyo3(signature = (name, arr, shm_key, offset=0, byte_size=None))]
+    fn for_numpy(
+        name: String,
+        arr: &Bound<'_, PyAny>,
+        shm_key: String,
+        offset: u64,
+        byte_size: Option<u64>,
+    ) -> PyResult<Self> {
+        let arr = arr.cast::<PyUntypedArray>()?;
+        let nbytes = (arr.len() * arr.dtype().itemsize()) as u64;
+        if let Some(byte_size) = byte_size
+            && byte_size != nbytes
+        {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "byte_size {} does not match the array's nbytes {}",
+                byte_size, nbytes
+            )));
+        }
+        Ok(Self {
+            name,
+            key: shm_key,
+            offset,
+            byte_size: nbytes,
+        })
+    }
+}
+
+#[pymethods]
+impl CudaSharedMemoryRegisterRequest {
+    /// Register request for `byte_size` bytes of CUDA memory on `device_id`.
+    ///
+    /// `raw_handle` is the serialized `cudaIpcMemHandle_t` of the allocation.
+    #[staticmethod]
+    #[pyo3(signature = (name, byte_size, device_id, raw_handle=None))]
+    fn for_device(
+        name: String,
+        byte_size: u64,
+        device_id: i64,
+        raw_handle: Option<Vec<u8>>,
+    ) -> PyResult<Self> {
+        if byte_size == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "byte_size must be greater than 0",
+            ));
+        }
+        if device_id < 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "device_id must not be negative",
+            ));
+        }
+        Ok(Self {
+            name,
+            raw_handle: raw_handle.unwrap_or_default(),
+            device_id,
+            byte_size,
+        })
+    }
+}
+
+#[pymethods]
+impl ModelRepositoryParameter {
+    #[staticmethod]
+    fn from_string(s: &str) -> Self {
+        Self {
+            parameter_choice: Some(RepositoryParameterChoice::StringParam(s.to_string())),
+        }
+    }
+
+    #[staticmethod]
+    fn from_bool(b: bool) -> Self {
+        Self {
+            parameter_choice: Some(RepositoryParameterChoice::BoolParam(b)),
+        }
+    }
+
+    #[staticmethod]
+    fn from_int64(i: i64) -> Self {
+        Self {
+            parameter_choice: Some(RepositoryParameterChoice::Int64Param(i)),
+        }
+    }
+
+    #[staticmethod]
+    fn from_bytes(b: Vec<u8>) -> Self {
+        Self {
+            parameter_choice: Some(RepositoryParameterChoice::BytesParam(b)),
+        }
+    }
+
+    /// The inner value as `str`, `bool`, `int` or `bytes`, or `None` if unset.
+    fn to_python_value<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        match &self.parameter_choice {
+            None => Ok(py.None().into_bound(py)),
+            Some(RepositoryParameterChoice::BoolParam(v)) => v.into_bound_py_any(py),
+            Some(RepositoryParameterChoice::Int64Param(v)) => v.into_bound_py_any(py),
+            Some(RepositoryParameterChoice::StringParam(v)) => v.into_bound_py_any(py),
+            Some(RepositoryParameterChoice::BytesParam(v)) => Ok(PyBytes::new(py, v).into_any()),
+        }
+    }
+}
+
+#[pymethods]
+impl ModelMetadataResponse {
+    /// Input tensor metadata named `name`; raises `KeyError` if there is none.
+    fn get_input_by_name(&self, name: &str) -> PyResult<TensorMetadata> {
+        find_tensor(&self.inputs, name)
+    }
+
+    /// Output tensor metadata named `name`; raises `KeyError` if there is none.
+    fn get_output_by_name(&self, name: &str) -> PyResult<TensorMetadata> {
+        find_tensor(&self.outputs, name)
+    }
+}
+
+impl ModelStatisticsResponse {
+    fn find_model(&self, name: &str, version: &str) -> Option<&ModelStatistics> {
+        self.model_stats
+            .iter()
+            .find(|m| m.name == name && (version.is_empty() || m.version == version))
+    }
+}
+
+#[pymethods]
+impl ModelStatisticsResponse {
+    /// Statistics of model `name` at `version`, or of its first listed version if `version`
+    /// is empty; raises `KeyError` if there are none.
+    #[pyo3(signature = (name, version=""))]
+    fn get_model(&self, name: &str, version: &str) -> PyResult<ModelStatistics> {
+        self.find_model(name, version).cloned().ok_or_else(|| {
+            pyo3::exceptions::PyKeyError::new_err(format!("{} (version {:?})", name, version))
+        })
+    }
+
+    /// Whether statistics of model `name` at `version` (any version if empty) are present.
+    #[pyo3(signature = (name, version=""))]
+    fn has_model(&self, name: &str, version: &str) -> bool {
+        self.find_model(name, version).is_some()
+    }
+
+    /// Names of the models with statistics, without duplicates, in response order.
+    fn model_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = vec![];
+        for m in &self.model_stats {
+            if !names.contains(&m.name) {
+                names.push(m.name.clone());
+            }
+        }
+        names
+    }
+}
+
+fn find_tensor(tensors: &[TensorMetadata], name: &str) -> PyResult<TensorMetadata> {
+    tensors
+        .iter()
+        .find(|t| t.name == name)
+        .cloned()
+        .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(name.to_string()))
+}
+
+#[pymethods]
+impl TensorMetadata {
+    /// `shape` as a tuple; `-1` marks a variable-size dimension.
+    fn shape_as_tuple<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        PyTuple::new(py, &self.shape)
+    }
+
+    /// `shape` as a list; `-1` marks a variable-size dimension.
+    fn shape_as_list<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        PyList::new(py, &self.shape)
+    }
+
+    /// Whether any dimension is variable-size (`-1`).
+    fn is_dynamic_shape(&self) -> bool {
+        self.shape.iter().any(|&d| d < 0)
+    }
+
+    /// Number of elements of the tensor; raises `ValueError` if the shape is dynamic.
+    fn num_elements(&self) -> PyResult<u64> {
+        self.shape.iter().try_fold(1u64, |n, &d| {
+            let d = u64::try_from(d).map_err(|_| {
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "{} has a dynamic shape {:?}",
+                    self.name, self.shape
+                ))
+            })?;
+            n.checked_mul(d).ok_or_else(|| {
+                pyo3::exceptions::PyOverflowError::new_err("number of elements overflows u64")
+            })
+        })
+    }
+}
+
+impl ModelInferResponse {
+    /// Output `index` as a numpy array of its datatype, shaped by the tensor `shape`.
+    ///
+    /// Reads `raw_output_contents[index]` when present and the typed `contents` otherwise.
+    fn output_array<'py>(&self, py: Python<'py>, index: usize) -> PyResult<Bound<'py, PyAny>> {
+        let np = py.import("numpy")?;
+        let output = &self.outputs[index];
+        let dtype = crate::dtype_map::from_triton(&output.datatype)
+            .and_then(crate::dtype_map::to_numpy)
+            .ok_or_else(|| {
+                pyo3::exceptions::PyTypeError::new_err(format!(
+                    "output {} has unsupported datatype {}",
+                    output.name, output.datatype
+                ))
+            })?;
+        let flat = match self.raw_output_contents.get(index) {
+            Some(raw) if dtype == "object" => {
+                np.call_method1("asarray", (split_raw_bytes(py, raw)?, dtype))?
+            }
+            Some(raw) => np.call_method1("frombuffer", (PyBytes::new(py, raw), dtype))?,
+            None => {
+                let contents = output.contents.clone().unwrap_or_default();
+                let values = if !contents.bool_contents.is_empty() {
+                    contents.bool_contents.into_bound_py_any(py)?
+                } else if !contents.int_contents.is_empty() {
+                    contents.int_contents.into_bound_py_any(py)?
+                } else if !contents.int64_contents.is_empty() {
+                    contents.int64_contents.into_bound_py_any(py)?
+                } else if !contents.uint_contents.is_empty() {
+                    contents.uint_contents.into_bound_py_any(py)?
+                } else if !contents.uint64_contents.is_empty() {
+                    contents.uint64_contents.into_bound_py_any(py)?
+                } else if !contents.fp32_contents.is_empty() {
+                    contents.fp32_contents.into_bound_py_any(py)?
+                } else if !contents.fp64_contents.is_empty() {
+                    contents.fp64_contents.into_bound_py_any(py)?
+                } else {
+                    PyList::new(
+                        py,
+                        contents.bytes_contents.iter().map(|b| PyBytes::new(py, b)),
+                    )?
+                    .into_any()
+                };
+                np.call_method1("asarray", (values, dtype))?
+            }
+        };
+        flat.call_method1("reshape", (output.shape.clone(),))
+    }
+
+    /// Position of the output named `name`; raises `KeyError` if there is none.
+    fn output_index(&self, name: &str) -> PyResult<usize> {
+        self.outputs
+            .iter()
+            .position(|o| o.name == name)
+            .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(name.to_string()))
+    }
+}
+
+/// Split a raw `BYTES` tensor, a sequence of 4-byte little-endian lengths each followed by
+/// that many bytes, into its elements.
+fn split_raw_bytes<'py>(py: Python<'py>, mut raw: &[u8]) -> PyResult<Bound<'py, PyList>> {
+    let list = PyList::empty(py);
+    while !raw.is_empty() {
+        let (len, rest) = raw.split_first_chunk::<4>().ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err("truncated BYTES tensor length prefix")
+        })?;
+        let len = u32::from_le_bytes(*len) as usize;
+        if rest.len() < len {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "truncated BYTES tensor element",
+            ));
+        }
+        list.append(PyBytes::new(py, &rest[..len]))?;
+        raw = &rest[len..];
+    }
+    Ok(list)
+}
+
+/// A pandas column for `arr`: 1-D arrays as is, one row per first-axis entry otherwise.
+fn pandas_column<'py>(arr: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    match arr.getattr("ndim")?.extract::<usize>()? {
+        0 => arr.call_method1("reshape", (1,)),
+        1 => Ok(arr),
+        _ => Ok(PyList::new(arr.py(), arr.try_iter()?.collect::<PyResult<Vec<_>>>()?)?.into_any()),
+    }
+}
+
+fn import_pandas(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    py.import("pandas").map_err(|_| {
+        pyo3::exceptions::PyImportError::new_err(
+            "pandas is required for this method; install it with `pip install pandas`",
+        )
+    })
+}
+
+#[pymethods]
+impl ModelInferResponse {
+    /// Output `name` as a numpy array; raises `KeyError` if there is none.
+    fn output_to_numpy<'py>(&self, py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyAny>> {
+        self.output_array(py, self.output_index(name)?)
+    }
+
+    /// All outputs as numpy arrays keyed by output name.
+    ///
+    /// Like `get_response_as_dict()` of the official client, with the tensor data decoded.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for (index, output) in self.outputs.iter().enumerate() {
+            dict.set_item(&output.name, self.output_array(py, index)?)?;
+        }
+        Ok(dict)
+    }
+
+    /// All outputs as a `pandas.DataFrame` with one column per output tensor.
+    ///
+    /// Multi-dimensional outputs get one row per entry of their first axis.
+    fn to_dataframe<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let pandas = import_pandas(py)?;
+        let data = PyDict::new(py);
+        for (index, output) in self.outputs.iter().enumerate() {
+            data.set_item(&output.name, pandas_column(self.output_array(py, index)?)?)?;
+        }
+        pandas.call_method1("DataFrame", (data,))
+    }
+
+    /// Output `name` as a `pandas.Series`; raises `KeyError` if there is none.
+    fn output_to_series<'py>(&self, py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyAny>> {
+        let pandas = import_pandas(py)?;
+        let column = pandas_column(self.output_array(py, self.output_index(name)?)?)?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("name", name)?;
+        pandas.call_method("Series", (column,), Some(&kwargs))
+    }
+}
+
+#[pymethods]
+impl ServerLiveResponse {
+    fn __repr__(&self) -> String {
+        format!("ServerLiveResponse(live={})", py_bool(self.live))
+    }
+}
+
+#[pymethods]
+impl ServerReadyResponse {
+    fn __repr__(&self) -> String {
+        format!("ServerReadyResponse(ready={})", py_bool(self.ready))
+    }
+}
+
+#[pymethods]
+impl ModelReadyResponse {
+    fn __repr__(&self) -> String {
+        format!("ModelReadyResponse(ready={})", py_bool(self.ready))
+    }
+}
+
+#[pymethods]
+impl ServerMetadataResponse {
+    fn __repr__(&self) -> String {
+        format!(
+            "ServerMetadataResponse(name={:?}, version={:?}, extensions={:?})",
+            self.name, self.version, self.extensions
+        )
+    }
+}
+
+/// Python spelling of a bool for `__repr__`.
+fn py_bool(value: bool) -> &'static str {
+    if value { "True" } else { "False" }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_contents_have_no_elements() {
+        let contents = InferTensorContents::default();
+        assert!(contents.is_empty());
+        assert_eq!(contents.element_count(), 0);
+    }
+
+    #[test]
+    fn element_count_sums_every_content_field() {
+        let contents = InferTensorContents {
+            fp32_contents: vec![1.0, 2.0, 3.0],
+            bytes_contents: vec![b"a".to_vec()],
+            ..Default::default()
+        };
+        assert!(!contents.is_empty());
+        assert_eq!(contents.element_count(), 4);
+    }
+
+    fn is_value_error(err: &PyErr) -> bool {
+        Python::initialize();
+        Python::attach(|py| err.is_instance_of::<pyo3::exceptions::PyValueError>(py))
+    }
+
+    #[test]
+    fn combine_concatenates_parts_in_order() {
+        let part = |values: Vec<f32>| InferTensorContents {
+            fp32_contents: values,
+            ..Default::default()
+        };
+        let combined = InferTensorContents::combine(vec![
+            part(vec![1.0]),
+            InferTensorContents::default(),
+            part(vec![2.0, 3.0]),
+        ])
+        .unwrap();
+        assert_eq!(combined.fp32_contents, vec![1.0, 2.0, 3.0]);
+        assert_eq!(combined.populated_fields(), vec!["fp32_contents"]);
+        assert!(InferTensorContents::combine(vec![]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn combine_rejects_mixed_fields() {
+        let fp32 = InferTensorContents {
+            fp32_contents: vec![1.0],
+            ..Default::default()
+        };
+        let int = InferTensorContents {
+            int_contents: vec![1],
+            ..Default::default()
+        };
+        let mixed = InferTensorContents {
+            fp32_contents: vec![1.0],
+            int_contents: vec![1],
+            ..Default::default()
+        };
+        for parts in [vec![fp32, int], vec![mixed]] {
+            match InferTensorContents::combine(parts) {
+                Err(crate::Error::Python(err)) => assert!(is_value_error(&err)),
+                other => panic!(
+                    "expected ValueError, got {:?}",
+                    other.map(|c| c.element_count())
+                ),
+            }
+        }
+    }
+
+    fn tensor(shape: Vec<i64>) -> InferInputTensor {
+        InferInputTensor {
+            name: "input".to_string(),
+            datatype: "FP32".to_string(),
+            shape,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reshape_keeps_everything_but_the_shape() {
+        let reshaped = tensor(vec![2, 3]).reshape(vec![3, 2]).unwrap();
+        assert_eq!(reshaped.shape, vec![3, 2]);
+        assert_eq!(reshaped.name, "input");
+        assert_eq!(reshaped.datatype, "FP32");
+        assert_eq!(tensor(vec![2, 3]).reshape(vec![-1]).unwrap().shape, vec![6]);
+        assert_eq!(
+            tensor(vec![2, 3]).reshape(vec![3, -1]).unwrap().shape,
+            vec![3, 2]
+        );
+    }
+
+    #[test]
+    fn reshape_rejects_invalid_shapes() {
+        for new_shape in [
+            vec![4, 2],
+            vec![-1, -1],
+            vec![-2, 3],
+            vec![4, -1],
+            vec![0, -1],
+            vec![i64::MAX, 2],
+        ] {
+            let err = tensor(vec![2, 3]).reshape(new_shape.clone()).unwrap_err();
+            assert!(is_value_error(&err), "{:?}", new_shape);
+        }
+    }
+
+    #[test]
+    fn tensor_metadata_shape_helpers() {
+        let metadata = |shape: Vec<i64>| TensorMetadata {
+            name: "input".to_string(),
+            shape,
+            ..Default::default()
+        };
+        let fixed = metadata(vec![2, 3]);
+        assert!(!fixed.is_dynamic_shape());
+        assert_eq!(fixed.num_elements().unwrap(), 6);
+        assert_eq!(metadata(vec![]).num_elements().unwrap(), 1);
+
+        let dynamic = metadata(vec![-1, 3]);
+        assert!(dynamic.is_dynamic_shape());
+        assert!(is_value_error(&dynamic.num_elements().unwrap_err()));
+
+        let err = metadata(vec![i64::MAX, i64::MAX, 4])
+            .num_elements()
+            .unwrap_err();
+        Python::attach(|py| {
+            assert!(err.is_instance_of::<pyo3::exceptions::PyOverflowError>(py));
+            let list = fixed.shape_as_list(py).unwrap();
+            assert_eq!(list.extract::<Vec<i64>>().unwrap(), vec![2, 3]);
+        });
     }
 }
@@ -0,0 +1,145 @@
+use crate::error::Error;
+use pyo3::prelude::*;
+use std::ops::Deref;
+use std::sync::Arc;
+use tokio::runtime::Builder;
+use tokio::sync::OnceCell;
+
+static TOKIO_RT: OnceCell<Runtime> = OnceCell::const_new();
+
+/// A tokio runtime running the gRPC calls of the clients using it; clones share it.
+///
+/// `init_runtime` configures the shared one used by default. Constructing a `Runtime` creates
+/// an isolated one, e.g. per test, that shuts down once no client uses it.
+#[pyclass(module = "triton_client", frozen)]
+#[derive(Debug, Clone)]
+pub struct Runtime {
+    inner: Arc<OwnedRuntime>,
+}
+
+/// Shuts the runtime down without blocking when dropped, which a plain tokio runtime
+/// refuses to do from async context, e.g. when its last client is dropped by a spawned task.
+#[derive(Debug)]
+struct OwnedRuntime(Option<tokio::runtime::Runtime>);
+
+impl Drop for OwnedRuntime {
+    fn drop(&mut self) {
+        if let Some(rt) = self.0.take() {
+            rt.shutdown_background();
+        }
+    }
+}
+
+impl Runtime {
+    fn new(
+        num_threads: Option<usize>,
+        thread_name: Option<&str>,
+        stack_size_kb: Option<usize>,
+    ) -> PyResult<Self> {
+        if num_threads == Some(0) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "num_threads must be greater than 0",
+            ));
+        }
+        let rt = build_runtime(num_threads, thread_name, stack_size_kb)?;
+        Ok(Self {
+            inner: Arc::new(OwnedRuntime(Some(rt))),
+        })
+    }
+}
+
+impl Deref for Runtime {
+    type Target = tokio::runtime::Runtime;
+
+    fn deref(&self) -> &Self::Target {
+        // only taken in `Drop`
+        self.inner.0.as_ref().expect("runtime is shut down")
+    }
+}
+
+#[pymethods]
+impl Runtime {
+    /// Create an isolated runtime, independent of the shared one.
+    #[new]
+    #[pyo3(signature = (num_threads=None, thread_name=None, stack_size_kb=None))]
+    fn py_new(
+        num_threads: Option<usize>,
+        thread_name: Option<&str>,
+        stack_size_kb: Option<usize>,
+    ) -> PyResult<Self> {
+        Self::new(num_threads, thread_name, stack_size_kb)
+    }
+}
+
+/// Env var capping the worker threads of the default runtime.
+const NUM_THREADS_ENV: &str = "TRITON_NUM_THREADS";
+
+fn build_runtime(
+    num_threads: Option<usize>,
+    thread_name: Option<&str>,
+    stack_size_kb: Option<usize>,
+) -> std::io::Result<tokio::runtime::Runtime> {
+    let mut builder = Builder::new_multi_thread();
+    builder.enable_all();
+    if let Some(num_threads) = num_threads {
+        builder.worker_threads(num_threads);
+    }
+    if let Some(thread_name) = thread_name {
+        builder.thread_name(thread_name);
+    }
+    if let Some(stack_size_kb) = stack_size_kb {
+        builder.thread_stack_size(stack_size_kb * 1024);
+    }
+    builder.build()
+}
+
+fn num_threads_from_env() -> Option<usize> {
+    let value = std::env::var(NUM_THREADS_ENV).ok()?;
+    match value.trim().parse::<usize>() {
+        Ok(n) if n > 0 => Some(n),
+        _ => {
+            log::warn!("ignoring invalid {}={:?}", NUM_THREADS_ENV, value);
+            None
+        }
+    }
+}
+
+/// Get the shared tokio runtime, creating the default one on first use.
+///
+/// The default runtime respects `TRITON_NUM_THREADS`.
+pub(crate) fn get_runtime() -> Result<Runtime, Error> {
+    if let Some(rt) = TOKIO_RT.get() {
+        return Ok(rt.clone());
+    }
+    let rt = Runtime::new(num_threads_from_env(), None, None)
+        .map_err(|e| Error::msg(format!("failed to build tokio runtime: {}", e)))?;
+    // Another thread may have won the race; its runtime is kept and ours is dropped.
+    let _ = TOKIO_RT.set(rt);
+    TOKIO_RT
+        .get()
+        .cloned()
+        .ok_or_else(|| Error::msg("failed to get tokio runtime"))
+}
+
+/// Configure the shared tokio runtime and return it.
+///
+/// Must be called before the first `Client` is constructed; raises `RuntimeError`
+/// if the runtime has already been initialised.
+#[pyfunction]
+#[pyo3(signature = (num_threads=None, thread_name=None, stack_size_kb=None))]
+pub fn init_runtime(
+    num_threads: Option<usize>,
+    thread_name: Option<&str>,
+    stack_size_kb: Option<usize>,
+) -> PyResult<Runtime> {
+    if TOKIO_RT.initialized() {
+        return Err(pyo3::exceptions::PyRuntimeError::new_err(
+            "tokio runtime is already initialized",
+        ));
+    }
+    let rt = Runtime::new(num_threads, thread_name, stack_size_kb)?;
+    TOKIO_RT.set(rt.clone()).map_err(|_| {
+        pyo3::exceptions::PyRuntimeError::new_err("tokio runtime is already initialized")
+    })?;
+    Ok(rt)
+}
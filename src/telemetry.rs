@@ -0,0 +1,62 @@
+//! OpenTelemetry integration, enabled by the `tracing` feature.
+use opentelemetry::propagation::{Injector, TextMapPropagator};
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use tonic::metadata::{AsciiMetadataKey, AsciiMetadataValue, MetadataMap};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::layer::SubscriberExt;
+
+/// Writes propagation fields into gRPC request metadata.
+struct MetadataInjector<'a>(&'a mut MetadataMap);
+
+impl Injector for MetadataInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(key), Ok(value)) = (
+            AsciiMetadataKey::from_bytes(key.as_bytes()),
+            AsciiMetadataValue::try_from(value),
+        ) {
+            self.0.insert(key, value);
+        }
+    }
+}
+
+/// Serialise the current span context into the W3C `traceparent` metadata header.
+pub(crate) fn inject_trace_context<T>(request: &mut tonic::Request<T>) {
+    let cx = tracing::Span::current().context();
+    TraceContextPropagator::new()
+        .inject_context(&cx, &mut MetadataInjector(request.metadata_mut()));
+}
+
+/// Export client spans to an OTLP/HTTP collector.
+///
+/// `endpoint` defaults to the OTLP standard `http://localhost:4318/v1/traces`.
+#[pyfunction]
+#[pyo3(signature = (endpoint=None, service_name="triton_client"))]
+pub fn init_tracing(endpoint: Option<&str>, service_name: &str) -> PyResult<()> {
+    let mut builder = opentelemetry_otlp::SpanExporter::builder().with_http();
+    if let Some(endpoint) = endpoint {
+        builder = builder.with_endpoint(endpoint);
+    }
+    let exporter = builder
+        .build()
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(
+            Resource::builder()
+                .with_service_name(service_name.to_string())
+                .build(),
+        )
+        .build();
+    let tracer = provider.tracer("triton_client");
+    opentelemetry::global::set_tracer_provider(provider);
+    let subscriber =
+        tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer));
+    tracing::subscriber::set_global_default(subscriber)
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))
+}
@@ -1,17 +1,98 @@
-pub(crate) fn init_log(level: String) {
+use pyo3::prelude::*;
+use std::sync::{Arc, Mutex};
+use tokio::sync::OnceCell;
+
+static LOG_HANDLE: OnceCell<LogHandle> = OnceCell::const_new();
+
+/// Handle to the module logger, for changing the log level at runtime.
+#[pyclass(module = "triton_client")]
+#[derive(Clone)]
+pub struct LogHandle {
+    handle: flexi_logger::LoggerHandle,
+    /// Log specification currently in effect, e.g. `"INFO"`.
+    level: Arc<Mutex<String>>,
+}
+
+#[pymethods]
+impl LogHandle {
+    /// Replace the log specification, e.g. `"DEBUG"` or `"info,triton_client=debug"`.
+    ///
+    /// Release builds compile out `TRACE` records to keep them off hot paths, so there the most
+    /// verbose effective level is `DEBUG`; a `trace` specification logs a warning.
+    fn set_level(&self, level: &str) -> PyResult<()> {
+        self.handle
+            .parse_new_spec(level)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        let lower = level.to_ascii_lowercase();
+        if log::STATIC_MAX_LEVEL < log::LevelFilter::Trace && lower.contains("trace") {
+            log::warn!("this build compiles out TRACE records, logging DEBUG and above only");
+        }
+        *self.level.lock().unwrap_or_else(|e| e.into_inner()) = level.to_string();
+        Ok(())
+    }
+
+    fn get_level(&self) -> String {
+        self.level.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+/// Get the handle of the logger configured from `LOG_LEVEL` at import.
+#[pyfunction]
+pub fn get_log_handle() -> PyResult<LogHandle> {
+    LOG_HANDLE
+        .get()
+        .cloned()
+        .ok_or_else(|| pyo3::exceptions::PyRuntimeError::new_err("logger is not initialized"))
+}
+
+/// Version of the `triton_client` crate this module was built from.
+#[pyfunction]
+pub fn version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
+}
+
+/// Version of the Triton protobuf schema the bindings were generated from.
+///
+/// The Triton release when the build set `TRITON_PROTO_VERSION`, otherwise a fingerprint of
+/// the `.proto` files.
+#[pyfunction]
+pub fn triton_proto_version() -> &'static str {
+    env!("TRITON_PROTO_VERSION")
+}
+
+/// Positive integer from env var `name`, or `default` if unset or invalid.
+///
+/// Problems are pushed to `warnings`, to be logged once the logger has started.
+fn env_u64(name: &str, default: u64, warnings: &mut Vec<String>) -> u64 {
+    match std::env::var(name) {
+        Ok(value) => match value.trim().parse::<u64>() {
+            Ok(n) if n > 0 => n,
+            _ => {
+                warnings.push(format!("ignoring invalid {}={:?}", name, value));
+                default
+            }
+        },
+        Err(_) => default,
+    }
+}
+
+pub(crate) fn init_log(level: String, format: &str) {
+    fn level_name(level: log::Level) -> &'static str {
+        match level {
+            log::Level::Error => "ERROR",
+            log::Level::Warn => "WARNING",
+            log::Level::Info => "INFO",
+            log::Level::Debug => "DEBUG",
+            log::Level::Trace => "TRACE",
+        }
+    }
     fn detailed_format(
         w: &mut dyn std::io::Write,
         now: &mut flexi_logger::DeferredNow,
         record: &log::Record,
     ) -> crate::Result<(), std::io::Error> {
         let ts = now.format("%Y-%m-%d %H:%M:%S%.3f");
-        let level = match record.level() {
-            log::Level::Error => "ERROR",
-            log::Level::Warn => "WARNING",
-            log::Level::Info => "INFO",
-            log::Level::Debug => "DEBUG",
-            log::Level::Trace => "TRACE",
-        };
+        let level = level_name(record.level());
         let module = record
             .module_path()
             .unwrap_or("<unnamed>")
@@ -26,10 +107,92 @@ pub(crate) fn init_log(level: String) {
             record.args()
         )
     }
-    flexi_logger::Logger::try_with_str(level)
-        .unwrap()
-        .format(detailed_format)
-        .log_to_stdout()
-        .start()
-        .unwrap();
+    /// One JSON object per line, for log aggregators.
+    fn json_format(
+        w: &mut dyn std::io::Write,
+        now: &mut flexi_logger::DeferredNow,
+        record: &log::Record,
+    ) -> crate::Result<(), std::io::Error> {
+        let entry = serde_json::json!({
+            "timestamp": now.format("%Y-%m-%d %H:%M:%S%.3f").to_string(),
+            "level": level_name(record.level()),
+            "module": record.module_path().unwrap_or("<unnamed>").replace("::", "."),
+            "line": record.line().unwrap_or(0),
+            "message": record.args().to_string(),
+        });
+        let entry = serde_json::to_string(&entry).map_err(std::io::Error::other)?;
+        write!(w, "{}", entry)
+    }
+    // Runs at import: configuration problems fall back to defaults and are logged, never panic
+    let mut warnings = vec![];
+    let json = format.eq_ignore_ascii_case("json");
+    let format_fn: flexi_logger::FormatFunction = if json { json_format } else { detailed_format };
+    let (level, spec) = match flexi_logger::LogSpecification::parse(&level) {
+        Ok(spec) => (level, spec),
+        Err(e) => {
+            warnings.push(format!(
+                "invalid LOG_LEVEL {:?}: {}, falling back to INFO",
+                level, e
+            ));
+            ("INFO".to_string(), flexi_logger::LogSpecification::info())
+        }
+    };
+    let logger = || flexi_logger::Logger::with(spec.clone()).format(format_fn);
+    // With `LOG_FILE` set, logs go to a size-rotated file and are duplicated to stdout.
+    let file_logger = match std::env::var("LOG_FILE") {
+        Ok(path) if !path.is_empty() => {
+            let max_size_mb = env_u64("LOG_MAX_SIZE_MB", 100, &mut warnings);
+            let max_files = env_u64("LOG_MAX_FILES", 5, &mut warnings) as usize;
+            match flexi_logger::FileSpec::try_from(&path) {
+                Ok(file_spec) => Some((
+                    path,
+                    logger()
+                        .log_to_file(file_spec.suppress_timestamp())
+                        .rotate(
+                            flexi_logger::Criterion::Size(max_size_mb * 1024 * 1024),
+                            flexi_logger::Naming::Numbers,
+                            flexi_logger::Cleanup::KeepLogFiles(max_files),
+                        )
+                        .duplicate_to_stdout(flexi_logger::Duplicate::All),
+                )),
+                Err(e) => {
+                    warnings.push(format!(
+                        "cannot log to LOG_FILE {:?}: {}, logging to stdout only",
+                        path, e
+                    ));
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+    let started = match file_logger.map(|(path, logger)| (path, logger.start())) {
+        Some((_, Ok(handle))) => Ok(handle),
+        Some((path, Err(e))) => {
+            warnings.push(format!(
+                "cannot log to LOG_FILE {:?}: {}, logging to stdout only",
+                path, e
+            ));
+            logger().log_to_stdout().start()
+        }
+        None => logger().log_to_stdout().start(),
+    };
+    let handle = match started {
+        Ok(handle) => handle,
+        Err(e) => {
+            // No logger to report to; `get_log_handle()` raises `RuntimeError` afterwards
+            eprintln!("triton_client: failed to start logger: {}", e);
+            return;
+        }
+    };
+    let _ = LOG_HANDLE.set(LogHandle {
+        handle,
+        level: Arc::new(Mutex::new(level)),
+    });
+    for warning in warnings {
+        log::warn!("{}", warning);
+    }
+    if !json && !format.eq_ignore_ascii_case("text") {
+        log::warn!("unknown LOG_FORMAT {:?}, falling back to text", format);
+    }
 }
@@ -5,12 +5,14 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields, Type, Variant};
 /// 自动为 protobuf 类型生成 PyO3 构造函数
 ///
 /// 这个宏为生成的 protobuf 类型添加：
-/// - 对于 **struct**: 带所有字段可选参数的 `__new__` 构造函数
+/// - 对于 **struct**: 带所有字段可选参数的 `__new__` 构造函数，以及按关键字更新字段的 `update(**kwargs)`
 /// - 对于 **C-style enum**: 为每个变体生成 staticmethod
 /// - 对于 **oneof enum**: 为每个变体生成接收参数的 classmethod
 ///
 /// 注意：类型转换由 `#[pyo3::pyclass(get_all, set_all)]` 自动处理
 ///
+/// `Option<T>` 字段默认为 `None`；其后若还有必选字段，则从第一个 `Option<T>` 字段起只能按关键字传参
+///
 /// # 示例
 ///
 /// ## Struct:
@@ -21,12 +23,18 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields, Type, Variant};
 /// ## C-style Enum:
 /// ```python
 /// dtype = DataType.type_int32()  # 返回 DataType::TypeInt32
+/// all_dtypes = DataType.values()  # 按声明顺序的所有变体
 /// ```
 ///
 /// ## Oneof Enum:
 /// ```python
 /// policy = PolicyChoice.latest(latest_obj)
 /// ```
+///
+/// 结构体变体（如 `Pair { key: String, value: i64 }`）的字段按关键字传入：
+/// ```python
+/// choice = Choice.pair(key="a", value=1)
+/// ```
 #[proc_macro_derive(ImplPyNew)]
 pub fn triton_pyclass_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -58,18 +66,63 @@ fn generate_struct_impl(name: &syn::Ident, fields: &Fields) -> TokenStream {
     };
 
     // 生成构造函数参数列表
+    // Vec<T> 字段接收任意 Python 对象，在函数体内转换，以便同时支持 list 和 List* 类型
+    // HashMap<String, T> 字段同样在函数体内从 dict 提取
+    // Option<Box<T>> 字段以 Option<T> 接收（pyo3 无法直接提取 Box<T>）
     let param_list = field_info.iter().map(|(name, ty)| {
-        quote! { #name: #ty }
+        if extract_vec_inner_type(ty).is_some() || is_string_map_type(ty) {
+            quote! { #name: &::pyo3::Bound<'_, ::pyo3::PyAny> }
+        } else if let Some(boxed) = extract_option_inner_type(ty).as_ref().and_then(extract_box_inner_type) {
+            quote! { #name: ::std::option::Option<#boxed> }
+        } else {
+            quote! { #name: #ty }
+        }
     });
 
-    // 生成参数名列表（用于 signature）
-    let param_names = field_info.iter().map(|(name, _)| {
-        quote! { #name }
+    // 生成参数名列表（用于 signature），Option<T> 字段默认为 None
+    // Python 不允许必选位置参数出现在可选参数之后：若第一个 Option 字段后还有必选字段，
+    // 则从该字段起改为仅限关键字参数（插入 `*`）
+    let is_optional = |ty: &Type| extract_option_inner_type(ty).is_some();
+    let first_optional = field_info.iter().position(|(_, ty)| is_optional(ty));
+    let keyword_only_from = first_optional
+        .filter(|&i| field_info[i..].iter().any(|(_, ty)| !is_optional(ty)));
+    let param_names = field_info.iter().enumerate().map(|(i, (name, ty))| {
+        let star = (keyword_only_from == Some(i)).then(|| quote! { *, });
+        if is_optional(ty) {
+            quote! { #star #name=None }
+        } else {
+            quote! { #star #name }
+        }
+    });
+
+    // Vec<T> 与 HashMap<String, T> 字段的转换
+    let vec_extract = field_info
+        .iter()
+        .filter(|(_, ty)| extract_vec_inner_type(ty).is_some() || is_string_map_type(ty))
+        .map(|(name, ty)| {
+            let value = extract_field_value(name, ty);
+            quote! {
+                let #name: #ty = #value;
+            }
+        });
+
+    // update(**kwargs)：按字段名匹配赋值
+    let update_arms = field_info.iter().map(|(name, ty)| {
+        let key = name.to_string();
+        let value_ident = syn::Ident::new("value", name.span());
+        let value = extract_field_value(&value_ident, ty);
+        quote! {
+            #key => self.#name = #value,
+        }
     });
 
     // 生成字段初始化代码
-    let field_init = field_info.iter().map(|(name, _ty)| {
-        quote! { #name: #name }
+    let field_init = field_info.iter().map(|(name, ty)| {
+        if extract_option_inner_type(ty).as_ref().and_then(extract_box_inner_type).is_some() {
+            quote! { #name: #name.map(::std::boxed::Box::new) }
+        } else {
+            quote! { #name: #name }
+        }
     });
 
     let expanded = quote! {
@@ -85,10 +138,36 @@ fn generate_struct_impl(name: &syn::Ident, fields: &Fields) -> TokenStream {
                 #[pyo3(signature=(#(#param_names),*))]
                 fn __new__(
                     #(#param_list),*
-                ) -> Self {
-                    Self {
+                ) -> ::pyo3::PyResult<Self> {
+                    #(#vec_extract)*
+                    Ok(Self {
                         #(#field_init),*
+                    })
+                }
+
+                /// 按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+                #[pyo3(signature = (**kwargs))]
+                fn update(
+                    &mut self,
+                    kwargs: ::std::option::Option<&::pyo3::Bound<'_, ::pyo3::types::PyDict>>,
+                ) -> ::pyo3::PyResult<()> {
+                    let Some(kwargs) = kwargs else {
+                        return Ok(());
+                    };
+                    for (key, value) in ::pyo3::types::PyDictMethods::iter(kwargs) {
+                        let key: ::std::string::String = key.extract()?;
+                        match key.as_str() {
+                            #(#update_arms)*
+                            _ => {
+                                return Err(::pyo3::exceptions::PyAttributeError::new_err(format!(
+                                    "{} has no field {:?}",
+                                    stringify!(#name),
+                                    key
+                                )))
+                            }
+                        }
                     }
+                    Ok(())
                 }
             }
         };
@@ -135,6 +214,9 @@ fn generate_simple_enum_impl(
         }
     });
 
+    // 按声明顺序收集所有变体，用于 values()
+    let variant_names = variants.iter().map(|variant| &variant.ident);
+
     let expanded = quote! {
         #[automatically_derived]
         const _: () = {
@@ -144,6 +226,14 @@ fn generate_simple_enum_impl(
             #[::pyo3::pymethods]
             impl #name {
                 #(#variant_constructors)*
+
+                /// 按 proto 声明顺序返回所有变体，如 `list(DataType.values())`
+                ///
+                /// pyo3 无法为类设置元类，因此不支持直接 `list(DataType)`
+                #[staticmethod]
+                fn values() -> ::std::vec::Vec<Self> {
+                    ::std::vec![#(Self::#variant_names),*]
+                }
             }
         };
     };
@@ -175,9 +265,21 @@ fn generate_oneof_enum_impl(
                     }
                 }
             }
-            Fields::Named(_) => {
-                // 命名字段（通常不会出现在 protobuf oneof 中）
-                quote! {}
+            Fields::Named(fields) => {
+                // 命名字段的结构体变体，如 Variant { key: String, value: i64 }
+                // （protobuf oneof 不会生成，自定义类型可能使用），字段按关键字传入
+                let field_names: Vec<_> = fields.named.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+                let field_types = fields.named.iter().map(|f| &f.ty);
+                quote! {
+                    #[classmethod]
+                    #[pyo3(signature = (*, #(#field_names),*))]
+                    fn #method_name(
+                        _cls: &::pyo3::Bound<'_, ::pyo3::types::PyType>,
+                        #(#field_names: #field_types),*
+                    ) -> Self {
+                        Self::#variant_name { #(#field_names),* }
+                    }
+                }
             }
             Fields::Unit => {
                 // 无字段变体
@@ -217,7 +319,7 @@ fn generate_oneof_enum_impl(
 ///
 /// # 示例
 ///
-/// ```rust
+/// ```rust,ignore
 /// #[derive(ImplPyZeroCopy)]
 /// struct MyStruct {
 ///     pub fp32_contents: Vec<f32>,
@@ -245,12 +347,12 @@ pub fn impl_py_zero_copy(input: TokenStream) -> TokenStream {
 /// 自动为包含 Vec<T> 字段的类型生成 getter/setter 方法
 ///
 /// 这个宏会为类型中的每个 `Vec<T>` 字段生成：
-/// - getter: 返回 `VecRef<T>`（只读视图）
+/// - getter: 返回 `VecRef*`（只读视图，如 `VecRefF32`）
 /// - setter: 接受 `List<T>`（可变容器）
 ///
 /// # 示例
 ///
-/// ```rust
+/// ```rust,ignore
 /// #[derive(ImplPyVecAccessors)]
 /// struct MyStruct {
 ///     pub fp32_contents: Vec<f32>,
@@ -258,22 +360,296 @@ pub fn impl_py_zero_copy(input: TokenStream) -> TokenStream {
 /// ```
 ///
 /// 会生成：
-/// - `get_fp32_contents()` - 返回 VecRef<f32>
-/// - `set_fp32_contents(list: List<f32>)` - 设置数据
-#[proc_macro_derive(ImplPyVecAccessors)]
+/// - `Get_fp32_contents()` - 返回 VecRefF32
+/// - `Set_fp32_contents(list: ListF32)` - 设置数据
+/// - `Take_fp32_contents()` / `Replace_fp32_contents(list)` - 转移所有权
+/// - `Len_fp32_contents()` - 返回元素个数
+/// - `IsEmpty_fp32_contents()` - 是否为空
+/// - `Push_fp32_contents(val: f32)` - 追加单个元素
+///
+/// # 命名风格
+///
+/// 默认的 `naming = "PascalCase"` 只生成上面的 `Get_` / `Set_` 等方法（已弃用）。结构体或字段上的
+/// `#[pyclass_accessors(naming = "snake_case")]` 改为生成 `get_fp32_contents` / `set_fp32_contents`
+/// 等方法，并保留旧名称作为调用时发出 `DeprecationWarning` 的别名；字段上的属性优先。
+/// snake_case 方法在 Rust 侧命名为 `accessor_get_fp32_contents` 等，通过 `#[pyo3(name = ...)]` 导出。
+///
+/// `Set_fp32_contents` 没有 snake_case 名称，也不发出警告：pyo3 按 Python 名称生成包装函数，
+/// `set_fp32_contents` 会与 `#[pyclass(set_all)]` 生成的 setter 重名。
+///
+/// 类型自行实现了同名方法时，用 `#[pyclass_accessors(skip = "replace")]`（逗号分隔的 snake_case
+/// 动词）跳过对应的 snake_case 方法；旧别名仍会生成，其弃用说明指向手写的方法。
+#[proc_macro_derive(ImplPyVecAccessors, attributes(pyclass_accessors))]
 pub fn impl_py_vec_accessors(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     
     let name = &input.ident;
     
     match &input.data {
-        Data::Struct(data_struct) => generate_vec_accessors_impl(name, &data_struct.fields),
+        Data::Struct(data_struct) => {
+            generate_vec_accessors_impl(name, &input.attrs, &data_struct.fields)
+                .unwrap_or_else(|e| TokenStream::from(e.to_compile_error()))
+        }
         _ => {
             TokenStream::from(quote! {})
         }
     }
 }
 
+/// `ImplPyVecAccessors` 生成的方法命名风格
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum AccessorNaming {
+    /// 只生成已弃用的 `Get_x`、`Set_x` 等（默认）
+    #[default]
+    PascalCase,
+    /// `get_x`、`set_x` 等，加上发出 `DeprecationWarning` 的 `Get_x`、`Set_x` 别名
+    SnakeCase,
+}
+
+impl AccessorNaming {
+    /// 方法名，如 `("IsEmpty", fp32_contents)` 生成 `IsEmpty_fp32_contents` 或 `is_empty_fp32_contents`
+    fn method_name(self, verb: &str, field: &syn::Ident) -> syn::Ident {
+        let name = match self {
+            Self::PascalCase => format!("{}_{}", verb, field),
+            Self::SnakeCase => format!("{}_{}", to_snake_case(verb), field),
+        };
+        syn::Ident::new(&name, field.span())
+    }
+
+    /// PascalCase 方法的弃用说明，写入 Python docstring
+    fn deprecation_doc(self, verb: &str, field: &syn::Ident) -> String {
+        let snake_case = Self::SnakeCase.method_name(verb, field);
+        match self {
+            Self::PascalCase => format!(
+                "Deprecated: will be renamed to `{}` once snake_case accessor names become the default.",
+                snake_case
+            ),
+            Self::SnakeCase => format!("Deprecated: use `{}` instead.", snake_case),
+        }
+    }
+}
+
+/// `#[pyclass_accessors(naming = "...", skip = "...")]` 选项
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct AccessorOptions {
+    naming: AccessorNaming,
+    /// 不生成的 snake_case 方法动词，如 `replace`（类型已手写 `replace_x`）
+    skip: Vec<String>,
+}
+
+impl AccessorOptions {
+    /// 读取 `#[pyclass_accessors(...)]`，在 `default` 的基础上覆盖 `naming`、追加 `skip`
+    fn from_attrs(attrs: &[syn::Attribute], default: &Self) -> syn::Result<Self> {
+        let mut options = default.clone();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("pyclass_accessors")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("naming") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    options.naming = match value.value().as_str() {
+                        "snake_case" => AccessorNaming::SnakeCase,
+                        "PascalCase" => AccessorNaming::PascalCase,
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                value,
+                                "naming must be \"snake_case\" or \"PascalCase\"",
+                            ))
+                        }
+                    };
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    for verb in value.value().split(',').map(str::trim) {
+                        if !ACCESSOR_VERBS.iter().any(|v| to_snake_case(v) == verb) {
+                            return Err(syn::Error::new_spanned(
+                                &value,
+                                format!("unknown accessor `{}` in skip", verb),
+                            ));
+                        }
+                        options.skip.push(verb.to_string());
+                    }
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "unsupported pyclass_accessors option, expected `naming` or `skip`",
+                    ))
+                }
+            })?;
+        }
+        Ok(options)
+    }
+
+    /// 是否生成 `verb` 的 snake_case 方法
+    fn generates_snake_case(&self, verb: &str) -> bool {
+        self.naming == AccessorNaming::SnakeCase
+            && verb != PASCAL_CASE_ONLY_VERB
+            && !self.skip.contains(&to_snake_case(verb))
+    }
+}
+
+/// `ImplPyVecAccessors` 为每个字段生成的方法，PascalCase 形式
+const ACCESSOR_VERBS: [&str; 7] = ["Get", "Take", "Replace", "Set", "Len", "IsEmpty", "Push"];
+
+/// 没有 snake_case 名称的动词：pyo3 按 Python 名称生成包装函数 `__pymethod_set_x__`，
+/// 与 `#[pyclass(set_all)]` 为字段 `x` 生成的 setter 重名
+const PASCAL_CASE_ONLY_VERB: &str = "Set";
+
+/// 自动为 protobuf message 生成 protobuf 二进制编解码方法
+///
+/// 会生成：
+/// - `from_proto_bytes(data: bytes)` - staticmethod，使用 `prost::Message::decode` 解码
+/// - `to_proto_bytes()` - 使用 `prost::Message::encode_to_vec` 编码，Python 侧为 `bytes`
+///
+/// 可用于从磁盘读取或通过非 gRPC 通道传输的消息回放
+#[proc_macro_derive(ImplPyProtoBytes)]
+pub fn impl_py_proto_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = &input.ident;
+
+    match &input.data {
+        // 只有 struct 是 prost::Message，enum/oneof 不生成
+        Data::Struct(_) => TokenStream::from(quote! {
+            #[automatically_derived]
+            const _: () = {
+                use ::pyo3::prelude::*;
+
+                #[::pyo3::pymethods]
+                impl #name {
+                    #[staticmethod]
+                    fn from_proto_bytes(data: &[u8]) -> ::pyo3::PyResult<Self> {
+                        <Self as ::prost::Message>::decode(data)
+                            .map_err(|e| ::pyo3::exceptions::PyValueError::new_err(e.to_string()))
+                    }
+
+                    fn to_proto_bytes(&self) -> ::std::vec::Vec<u8> {
+                        ::prost::Message::encode_to_vec(self)
+                    }
+                }
+            };
+        }),
+        _ => TokenStream::from(quote! {}),
+    }
+}
+
+/// 为 pyclass 生成 `copy` 模块协议方法
+///
+/// 会生成：
+/// - `__copy__()` - 返回 `self.clone()`
+/// - `__deepcopy__(memo)` - 同样返回 `self.clone()`（Rust 的 Clone 本身就是深拷贝）
+///
+/// 需要类型实现 `Clone`，可与 `ImplPyNew` 组合使用：
+///
+/// ```python
+/// import copy
+/// req2 = copy.deepcopy(req)
+/// ```
+#[proc_macro_derive(ImplPyCopy)]
+pub fn impl_py_copy(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = &input.ident;
+
+    TokenStream::from(quote! {
+        #[automatically_derived]
+        const _: () = {
+            #[::pyo3::pymethods]
+            impl #name {
+                fn __copy__(&self) -> Self {
+                    ::std::clone::Clone::clone(self)
+                }
+
+                fn __deepcopy__(&self, _memo: &::pyo3::Bound<'_, ::pyo3::types::PyDict>) -> Self {
+                    ::std::clone::Clone::clone(self)
+                }
+            }
+        };
+    })
+}
+
+/// 根据 `#[hash = "..."]` 标注生成 `__hash__`
+///
+/// - `#[hash = "immutable"]`: 对 `prost::Message::encode_to_vec` 的结果求哈希，并生成比较同一结果的
+///   `__eq__`，使相等的值可作为同一个 dict 键或 set 成员
+/// - `#[hash = "mutable"]`: `__hash__` 抛出 `TypeError("unhashable type")`，与 Python 可变对象一致
+///
+/// ```rust,ignore
+/// #[derive(ImplPyHash)]
+/// #[hash = "immutable"]
+/// struct ServerLiveResponse { pub live: bool }
+/// ```
+#[proc_macro_derive(ImplPyHash, attributes(hash))]
+pub fn impl_py_hash(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = &input.ident;
+
+    let mode = match hash_mode(&input) {
+        Ok(mode) => mode,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let methods = if mode == "immutable" {
+        quote! {
+            fn __hash__(&self) -> ::pyo3::PyResult<u64> {
+                let mut hasher = ::std::collections::hash_map::DefaultHasher::new();
+                ::std::hash::Hasher::write(&mut hasher, &::prost::Message::encode_to_vec(self));
+                Ok(::std::hash::Hasher::finish(&hasher))
+            }
+
+            // 与 `__hash__` 一致：编码相同即相等；其他类型的参数由 pyo3 返回 NotImplemented
+            fn __eq__(&self, other: ::pyo3::PyRef<'_, Self>) -> bool {
+                ::prost::Message::encode_to_vec(self) == ::prost::Message::encode_to_vec(&*other)
+            }
+        }
+    } else {
+        quote! {
+            fn __hash__(&self) -> ::pyo3::PyResult<u64> {
+                Err(::pyo3::exceptions::PyTypeError::new_err(
+                    concat!("unhashable type: '", stringify!(#name), "'"),
+                ))
+            }
+        }
+    };
+
+    TokenStream::from(quote! {
+        #[automatically_derived]
+        const _: () = {
+            #[::pyo3::pymethods]
+            impl #name {
+                #methods
+            }
+        };
+    })
+}
+
+/// 读取 `#[hash = "immutable" | "mutable"]` 标注
+fn hash_mode(input: &DeriveInput) -> syn::Result<String> {
+    let attr = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("hash"))
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                &input.ident,
+                "ImplPyHash requires #[hash = \"immutable\"] or #[hash = \"mutable\"]",
+            )
+        })?;
+    let value = match &attr.meta.require_name_value()?.value {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(value),
+            ..
+        }) => value.value(),
+        other => return Err(syn::Error::new_spanned(other, "expected a string literal")),
+    };
+    match value.as_str() {
+        "immutable" | "mutable" => Ok(value),
+        _ => Err(syn::Error::new_spanned(
+            attr,
+            "hash must be \"immutable\" or \"mutable\"",
+        )),
+    }
+}
+
 fn generate_zero_copy_impl(name: &syn::Ident, fields: &Fields) -> TokenStream {
     let field_info: Vec<_> = match fields {
         Fields::Named(fields) => fields
@@ -360,8 +736,8 @@ fn generate_zero_copy_impl(name: &syn::Ident, fields: &Fields) -> TokenStream {
                         }
                         Some(src) => src.to_vec()?,
                     };
-                    let contents = ::std::mem::replace(&mut self.#field_name, src);
-                    Ok(#py_array_type::from_vec(py, contents))
+                    let contents = ::std::mem::replace(&mut self.#field_name, src.into());
+                    Ok(#py_array_type::from_vec(py, ::std::vec::Vec::from(contents)))
                 }
             }
         }
@@ -388,9 +764,27 @@ fn generate_zero_copy_impl(name: &syn::Ident, fields: &Fields) -> TokenStream {
 }
 
 /// 从类型中提取 Vec<T> 的 T
+///
+/// 同时支持 `Box<[T]>` 与 `Cow<'_, [T]>`（含 `std::borrow::Cow` 完整路径）
 fn extract_vec_inner_type(ty: &Type) -> Option<Type> {
     if let Type::Path(type_path) = ty {
         let segments = &type_path.path.segments;
+
+        // 检查是否是 Box<[T]> 或 Cow<'_, [T]>：取尖括号中的切片类型 [T] 的 T
+        if let Some(segment) = segments.last() {
+            if segment.ident == "Box" || segment.ident == "Cow" {
+                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                    let slice = args.args.iter().find_map(|arg| match arg {
+                        syn::GenericArgument::Type(Type::Slice(slice)) => Some(slice),
+                        _ => None,
+                    });
+                    if let Some(slice) = slice {
+                        return Some((*slice.elem).clone());
+                    }
+                }
+            }
+        }
+
         
         // 检查是否是 Vec<T>（标准库）
         if segments.len() == 1 {
@@ -405,24 +799,14 @@ fn extract_vec_inner_type(ty: &Type) -> Option<Type> {
             }
         }
         
-        // 检查是否是 prost::alloc::vec::Vec<T>
-        // 路径应该是: prost -> alloc -> vec -> Vec<T>
-        if segments.len() >= 3 {
-            if let Some(segment) = segments.last() {
-                if segment.ident == "Vec" {
-                    // 检查前面的路径段
-                    let prev_segments: Vec<_> = segments.iter().take(segments.len() - 1).collect();
-                    if prev_segments.len() >= 2 {
-                        let last_two: Vec<_> = prev_segments.iter().rev().take(2).collect();
-                        if last_two.len() == 2 {
-                            if last_two[0].ident == "vec" && last_two[1].ident == "alloc" {
-                                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
-                                    if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
-                                        return Some(inner.clone());
-                                    }
-                                }
-                            }
-                        }
+        // 检查是否是带路径的 Vec<T>：prost::alloc::vec::Vec<T>、std::vec::Vec<T>、alloc::vec::Vec<T>
+        // 即最后一段为 Vec、倒数第二段为 vec
+        if segments.len() >= 2 {
+            let segment = &segments[segments.len() - 1];
+            if segment.ident == "Vec" && segments[segments.len() - 2].ident == "vec" {
+                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                    if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+                        return Some(inner.clone());
                     }
                 }
             }
@@ -431,6 +815,87 @@ fn extract_vec_inner_type(ty: &Type) -> Option<Type> {
     None
 }
 
+/// 生成把 Python 对象 `value` 转换为字段类型 `ty` 的表达式（在返回 `PyResult` 的函数中使用）
+///
+/// - Vec<T>: 先按序列提取，失败时逐个元素迭代（List* 类型走这条路径）
+/// - Option<Box<T>>: 以 Option<T> 提取后装箱
+/// - 其他类型（含 HashMap<String, T>，从 dict 提取）: 直接 `extract`
+fn extract_field_value(value: &syn::Ident, ty: &Type) -> proc_macro2::TokenStream {
+    if extract_vec_inner_type(ty).is_some() {
+        quote! {
+            match #value.extract::<#ty>() {
+                Ok(value) => value,
+                Err(_) => #value
+                    .try_iter()?
+                    .map(|item| item?.extract().map_err(::std::convert::Into::into))
+                    .collect::<::pyo3::PyResult<#ty>>()?,
+            }
+        }
+    } else if let Some(boxed) = extract_option_inner_type(ty).as_ref().and_then(extract_box_inner_type) {
+        quote! {
+            #value
+                .extract::<::std::option::Option<#boxed>>()
+                .map_err(::std::convert::Into::<::pyo3::PyErr>::into)?
+                .map(::std::boxed::Box::new)
+        }
+    } else {
+        quote! {
+            #value
+                .extract::<#ty>()
+                .map_err(::std::convert::Into::<::pyo3::PyErr>::into)?
+        }
+    }
+}
+
+/// 检查是否是 HashMap<String, T>（含 ::std::collections::HashMap）
+fn is_string_map_type(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return false;
+    };
+    if segment.ident != "HashMap" {
+        return false;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => matches!(
+            args.args.first(),
+            Some(syn::GenericArgument::Type(Type::Path(key)))
+                if key.path.segments.last().is_some_and(|s| s.ident == "String")
+        ),
+        _ => false,
+    }
+}
+
+/// 提取 Option<T>（含 ::core::option::Option<T>）中的 T
+fn extract_option_inner_type(ty: &Type) -> Option<Type> {
+    extract_single_generic(ty, "Option")
+}
+
+/// 提取 Box<T>（含 ::prost::alloc::boxed::Box<T>）中的 T，不处理 Box<[T]>
+fn extract_box_inner_type(ty: &Type) -> Option<Type> {
+    extract_single_generic(ty, "Box").filter(|inner| !matches!(inner, Type::Slice(_)))
+}
+
+/// 若类型路径最后一段为 `ident<T>`，返回 T
+fn extract_single_generic(ty: &Type, ident: &str) -> Option<Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != ident {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => match args.args.first()? {
+            syn::GenericArgument::Type(inner) => Some(inner.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 /// 检查是否是支持的数值类型
 fn is_supported_numeric_type(ty: &Type) -> bool {
     if let Type::Path(type_path) = ty {
@@ -521,90 +986,153 @@ fn get_numpy_types(ty: &Type) -> (proc_macro2::TokenStream, proc_macro2::TokenSt
 }
 
 /// 生成 Vec 字段的 getter/setter
-fn generate_vec_accessors_impl(name: &syn::Ident, fields: &Fields) -> TokenStream {
-    let field_info: Vec<_> = match fields {
-        Fields::Named(fields) => fields
-            .named
-            .iter()
-            .filter_map(|f| {
-                let field_name = f.ident.as_ref()?;
-                let field_type = &f.ty;
-                
-                // 检查是否是 Vec<T> 类型
-                if let Some(inner_type) = extract_vec_inner_type(field_type) {
-                    // 检查是否是支持的数值类型
-                    if is_supported_numeric_type(&inner_type) {
-                        Some((field_name.clone(), inner_type))
-                    } else {
-                        None
-                    }
-                } else {
-                    None
+fn generate_vec_accessors_impl(
+    name: &syn::Ident,
+    attrs: &[syn::Attribute],
+    fields: &Fields,
+) -> syn::Result<TokenStream> {
+    let struct_options = AccessorOptions::from_attrs(attrs, &AccessorOptions::default())?;
+    let mut field_info = vec![];
+    if let Fields::Named(fields) = fields {
+        for f in &fields.named {
+            let Some(field_name) = f.ident.as_ref() else {
+                continue;
+            };
+            // 检查是否是 Vec<T> 类型，且是支持的数值类型
+            if let Some(inner_type) = extract_vec_inner_type(&f.ty) {
+                if is_supported_numeric_type(&inner_type) {
+                    let options = AccessorOptions::from_attrs(&f.attrs, &struct_options)?;
+                    field_info.push((field_name.clone(), inner_type, options));
                 }
-            })
-            .collect(),
-        _ => vec![],
-    };
+            }
+        }
+    }
     
     // 为每个 Vec<T> 字段生成 getter/setter
-    let accessors: Vec<_> = field_info.iter().map(|(field_name, inner_type)| {
-        // let field_name_str = capitalize_first_letter(field_name.to_string());
-        let getter_name = syn::Ident::new(
-            &format!("Get_{}", field_name),
-            field_name.span(),
-        );
-        let setter_name = syn::Ident::new(
-            &format!("Set_{}", field_name),
-            field_name.span(),
-        );
-
-        let take_name = syn::Ident::new(
-            &format!("Take_{}", field_name),
-            field_name.span(),
-        );
-
-        let repace_name = syn::Ident::new(
-            &format!("Replace_{}", field_name),
-            field_name.span(),
-        );
-
-
+    let accessors: Vec<_> = field_info.iter().map(|(field_name, inner_type, options)| {
         let list_type_name = get_type_info(inner_type);
-        
-        quote! {
-            // get 方法：简单克隆，用于一般访问（性能不敏感）
-            #[allow(non_snake_case)]
-            fn #getter_name(&self, py: Python) -> ::py_vec_types::#list_type_name {
-                ::py_vec_types::#list_type_name::new(self.#field_name.clone())
-            }
+        let vec_ref_type_name = syn::Ident::new(
+            &list_type_name.to_string().replacen("List", "VecRef", 1),
+            list_type_name.span(),
+        );
 
-            // take 方法：转移所有权，用于 model_infer 等性能关键场景
-            #[allow(non_snake_case)]
-            fn #take_name(&mut self, py: Python) -> ::py_vec_types::#list_type_name {
-                let data = std::mem::replace(&mut self.#field_name, vec![]);
-                ::py_vec_types::#list_type_name::new(data)
-            }
+        ACCESSOR_VERBS.iter().map(|&verb| {
+            // (receiver 与参数, 返回类型, 返回 `PyResult<返回类型>` 的方法体)
+            let (params, ret, body) = match verb {
+                // get 方法：简单克隆，返回只读的 VecRef 视图（性能不敏感）
+                "Get" => (
+                    quote! { &self },
+                    quote! { ::py_vec_types::#vec_ref_type_name },
+                    quote! { Ok(::py_vec_types::#vec_ref_type_name::new(self.#field_name.to_vec())) },
+                ),
+                // take 方法：转移所有权，用于 model_infer 等性能关键场景
+                "Take" => (
+                    quote! { &mut self },
+                    quote! { ::py_vec_types::#list_type_name },
+                    quote! {
+                        let data = std::mem::take(&mut self.#field_name);
+                        Ok(::py_vec_types::#list_type_name::new(::std::vec::Vec::from(data)))
+                    },
+                ),
+                // replace 方法：转移所有权替换，用于 model_infer 等性能关键场景
+                // 通过 extract 实现所有权转移，避免数据拷贝
+                "Replace" => (
+                    quote! { &mut self, val: ::pyo3::Bound<'_, ::pyo3::PyAny> },
+                    quote! { ::py_vec_types::#list_type_name },
+                    quote! {
+                        let new_val: ::py_vec_types::#list_type_name = pyo3::types::PyAnyMethods::extract(&val)?;
+                        let data = std::mem::replace(&mut self.#field_name, new_val.into_vec().into());
+                        Ok(::py_vec_types::#list_type_name::new(::std::vec::Vec::from(data)))
+                    },
+                ),
+                // set 方法：简单设置，直接拷贝数据（性能不敏感的场景）
+                // 直接接受 List 类型，不需要 extract，因为普通设置操作拷贝数据也可以接受
+                "Set" => (
+                    quote! { &mut self, list: ::py_vec_types::#list_type_name },
+                    quote! { () },
+                    quote! {
+                        self.#field_name = list.into_vec().into();
+                        Ok(())
+                    },
+                ),
+                // len / is_empty / push：脚本场景下的便捷方法，避免先 get 再操作
+                "Len" => (
+                    quote! { &self },
+                    quote! { usize },
+                    quote! { Ok(self.#field_name.len()) },
+                ),
+                "IsEmpty" => (
+                    quote! { &self },
+                    quote! { bool },
+                    quote! { Ok(self.#field_name.is_empty()) },
+                ),
+                "Push" => (
+                    quote! { &mut self, val: #inner_type },
+                    quote! { () },
+                    quote! {
+                        let mut data = ::std::vec::Vec::from(std::mem::take(&mut self.#field_name));
+                        data.push(val);
+                        self.#field_name = data.into();
+                        Ok(())
+                    },
+                ),
+                _ => unreachable!(),
+            };
 
-            // replace 方法：转移所有权替换，用于 model_infer 等性能关键场景
-            // 通过 extract 实现所有权转移，避免数据拷贝
-            #[allow(non_snake_case)]
-            fn #repace_name(&mut self, py: Python, val: ::pyo3::Bound<'_, ::pyo3::PyAny>) -> pyo3::PyResult<::py_vec_types::#list_type_name> {
-                let new_val: ::py_vec_types::#list_type_name = pyo3::types::PyAnyMethods::extract(&val)?;
-                let data = std::mem::replace(&mut self.#field_name, new_val.into_vec());
-                Ok(::py_vec_types::#list_type_name::new(data))
-            }
-            
-            // set 方法：简单设置，直接拷贝数据（性能不敏感的场景）
-            // 直接接受 List 类型，不需要 extract，因为普通设置操作拷贝数据也可以接受
-            #[allow(non_snake_case)]
-            fn #setter_name(&mut self, list: ::py_vec_types::#list_type_name) {
-                self.#field_name = list.into_vec();
-            }
-        }
+            let snake_case = AccessorNaming::SnakeCase.method_name(verb, field_name);
+            let generated = options.generates_snake_case(verb).then(|| {
+                // Rust 侧加前缀，避免与类型上手写的同名 Rust 方法冲突
+                let python_name = snake_case.to_string();
+                let method = syn::Ident::new(&format!("accessor_{}", snake_case), field_name.span());
+                quote! {
+                    #[pyo3(name = #python_name)]
+                    fn #method(#params) -> ::pyo3::PyResult<#ret> {
+                        #body
+                    }
+                }
+            });
+            let method = AccessorNaming::PascalCase.method_name(verb, field_name);
+            let doc = options.naming.deprecation_doc(verb, field_name);
+            let pascal_case = match options.naming {
+                _ if verb == PASCAL_CASE_ONLY_VERB => quote! {
+                    #[allow(non_snake_case)]
+                    fn #method(#params) -> ::pyo3::PyResult<#ret> {
+                        #body
+                    }
+                },
+                AccessorNaming::PascalCase => quote! {
+                    #[doc = #doc]
+                    #[allow(non_snake_case)]
+                    fn #method(#params) -> ::pyo3::PyResult<#ret> {
+                        #body
+                    }
+                },
+                // 旧名称作为别名保留：调用时发出 DeprecationWarning
+                AccessorNaming::SnakeCase => {
+                    let message = std::ffi::CString::new(format!("{} is deprecated, use {}", method, snake_case))
+                        .expect("identifiers contain no NUL");
+                    let message = syn::LitCStr::new(&message, field_name.span());
+                    quote! {
+                        #[doc = #doc]
+                        #[allow(non_snake_case)]
+                        fn #method(#params, py: Python<'_>) -> ::pyo3::PyResult<#ret> {
+                            ::pyo3::PyErr::warn(
+                                py,
+                                &py.get_type::<::pyo3::exceptions::PyDeprecationWarning>(),
+                                #message,
+                                1,
+                            )?;
+                            #body
+                        }
+                    }
+                }
+            };
+            quote! { #generated #pascal_case }
+        }).collect::<proc_macro2::TokenStream>()
     }).collect();
     
     if accessors.is_empty() {
-        return TokenStream::from(quote! {});
+        return Ok(TokenStream::from(quote! {}));
     }
     
     let expanded = quote! {
@@ -620,7 +1148,7 @@ fn generate_vec_accessors_impl(name: &syn::Ident, fields: &Fields) -> TokenStrea
         };
     };
     
-    TokenStream::from(expanded)
+    Ok(TokenStream::from(expanded))
 }
 
 /// 获取类型信息（PyArray 类型和 List/VecRef 类型名）
@@ -677,15 +1205,19 @@ fn get_type_info(ty: &Type) -> syn::Ident {
 
 /// 将 PascalCase 转换为 snake_case
 fn to_snake_case(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
     let mut result = String::new();
-    let mut chars = s.chars().peekable();
 
-    while let Some(ch) = chars.next() {
+    for (i, &ch) in chars.iter().enumerate() {
         if ch.is_uppercase() {
-            if !result.is_empty() {
+            // 连续大写视为一个缩写：只在缩写开头，以及缩写末字母后接小写时断开
+            // （XMLParser -> xml_parser，FP32 -> fp32）
+            let prev_upper = i > 0 && chars[i - 1].is_uppercase();
+            let next_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            if i > 0 && !result.ends_with('_') && (!prev_upper || next_lower) {
                 result.push('_');
             }
-            result.push(ch.to_lowercase().next().unwrap());
+            result.extend(ch.to_lowercase());
         } else {
             result.push(ch);
         }
@@ -706,3 +1238,128 @@ fn to_snake_case(s: &str) -> String {
 //         _ => s,
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_snake_case_cases() {
+        for (input, expected) in [
+            ("TypeInt32", "type_int32"),
+            ("XMLParser", "xml_parser"),
+            ("FP32", "fp32"),
+            ("BoolParam", "bool_param"),
+            ("CUDAMemory", "cuda_memory"),
+            ("", ""),
+            ("A", "a"),
+            ("a", "a"),
+            ("lowercase", "lowercase"),
+        ] {
+            assert_eq!(to_snake_case(input), expected, "to_snake_case({:?})", input);
+        }
+    }
+
+    fn vec_inner(ty: &str) -> Option<Type> {
+        extract_vec_inner_type(&syn::parse_str::<Type>(ty).unwrap())
+    }
+
+    fn ty(ty: &str) -> Type {
+        syn::parse_str::<Type>(ty).unwrap()
+    }
+
+    #[test]
+    fn extract_vec_inner_type_plain_vec() {
+        assert_eq!(vec_inner("Vec<f32>"), Some(ty("f32")));
+    }
+
+    #[test]
+    fn extract_vec_inner_type_prost_path() {
+        assert_eq!(vec_inner("prost::alloc::vec::Vec<u8>"), Some(ty("u8")));
+        // prost 生成代码使用的绝对路径形式
+        assert_eq!(vec_inner("::prost::alloc::vec::Vec<i32>"), Some(ty("i32")));
+        assert_eq!(
+            vec_inner("::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>"),
+            Some(ty("::prost::alloc::vec::Vec<u8>"))
+        );
+    }
+
+    #[test]
+    fn extract_vec_inner_type_std_path() {
+        assert_eq!(vec_inner("std::vec::Vec<i64>"), Some(ty("i64")));
+    }
+
+    #[test]
+    fn extract_vec_inner_type_boxed_slice() {
+        assert_eq!(vec_inner("Box<[f64]>"), Some(ty("f64")));
+    }
+
+    #[test]
+    fn accessor_naming_method_names() {
+        let field: syn::Ident = syn::parse_str("fp32_contents").unwrap();
+        assert_eq!(
+            AccessorNaming::PascalCase.method_name("IsEmpty", &field),
+            "IsEmpty_fp32_contents"
+        );
+        assert_eq!(
+            AccessorNaming::SnakeCase.method_name("IsEmpty", &field),
+            "is_empty_fp32_contents"
+        );
+        assert_eq!(AccessorNaming::SnakeCase.method_name("Get", &field), "get_fp32_contents");
+        assert!(
+            AccessorNaming::SnakeCase
+                .deprecation_doc("Get", &field)
+                .contains("use `get_fp32_contents`")
+        );
+        assert!(
+            AccessorNaming::PascalCase
+                .deprecation_doc("Get", &field)
+                .contains("renamed to `get_fp32_contents`")
+        );
+    }
+
+    #[test]
+    fn accessor_options_from_attrs() {
+        let default = AccessorOptions::default();
+        let input: DeriveInput = syn::parse_str(
+            r#"#[pyclass_accessors(naming = "snake_case")] struct S { a: Vec<f32> }"#,
+        )
+        .unwrap();
+        let options = AccessorOptions::from_attrs(&input.attrs, &default).unwrap();
+        assert_eq!(options.naming, AccessorNaming::SnakeCase);
+        assert!(options.generates_snake_case("Replace"));
+        assert!(!options.generates_snake_case("Set"));
+
+        let input: DeriveInput = syn::parse_str("struct S { a: Vec<f32> }").unwrap();
+        let options = AccessorOptions::from_attrs(&input.attrs, &default).unwrap();
+        assert_eq!(options, default);
+        // snake_case 需显式选择
+        assert!(!options.generates_snake_case("Get"));
+
+        let input: DeriveInput = syn::parse_str(
+            r#"#[pyclass_accessors(naming = "snake_case", skip = "replace, is_empty")] struct S;"#,
+        )
+        .unwrap();
+        let options = AccessorOptions::from_attrs(&input.attrs, &default).unwrap();
+        assert!(!options.generates_snake_case("Replace"));
+        assert!(!options.generates_snake_case("IsEmpty"));
+        assert!(options.generates_snake_case("Get"));
+
+        for attr in [
+            r#"#[pyclass_accessors(naming = "camelCase")] struct S;"#,
+            r#"#[pyclass_accessors(skip = "Replace")] struct S;"#,
+            r#"#[pyclass_accessors(rename = "x")] struct S;"#,
+        ] {
+            let input: DeriveInput = syn::parse_str(attr).unwrap();
+            assert!(AccessorOptions::from_attrs(&input.attrs, &default).is_err(), "{}", attr);
+        }
+    }
+
+    #[test]
+    fn extract_vec_inner_type_non_vec() {
+        assert_eq!(vec_inner("Option<Vec<bool>>"), None);
+        assert_eq!(vec_inner("String"), None);
+        assert_eq!(vec_inner("Box<f32>"), None);
+        assert_eq!(vec_inner("my::Vec<f32>"), None);
+    }
+}
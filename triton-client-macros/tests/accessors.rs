@@ -0,0 +1,77 @@
+//! 运行期测试：snake_case 访问器与发出 `DeprecationWarning` 的 PascalCase 别名
+use py_vec_types::ListF32;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use triton_client_macros::ImplPyVecAccessors;
+
+#[pyclass(get_all, set_all)]
+#[derive(Clone, Default, ImplPyVecAccessors)]
+#[pyclass_accessors(naming = "snake_case")]
+pub struct Tensor {
+    pub shape: Vec<i64>,
+    #[pyclass_accessors(skip = "replace")]
+    pub fp32_contents: Vec<f32>,
+}
+
+#[pymethods]
+impl Tensor {
+    /// 手写的 `replace_fp32_contents`，与 `skip = "replace"` 对应
+    fn replace_fp32_contents(&mut self, src: Vec<f32>) -> Vec<f32> {
+        std::mem::replace(&mut self.fp32_contents, src)
+    }
+}
+
+/// 未选择 snake_case：只有旧的 PascalCase 方法，不发出警告
+#[pyclass]
+#[derive(Clone, Default, ImplPyVecAccessors)]
+pub struct Shape {
+    pub dims: Vec<i64>,
+}
+
+fn run(py: Python<'_>, locals: &Bound<'_, PyDict>, code: &std::ffi::CStr) {
+    py.run(code, None, Some(locals)).unwrap();
+}
+
+#[test]
+fn snake_case_accessors_and_deprecated_aliases() {
+    Python::initialize();
+    Python::attach(|py| {
+        let locals = PyDict::new(py);
+        locals
+            .set_item("t", Py::new(py, Tensor::default()).unwrap())
+            .unwrap();
+        locals
+            .set_item("s", Py::new(py, Shape::default()).unwrap())
+            .unwrap();
+        // List 实例直接传入，不经过 numpy 转换
+        locals.set_item("a", ListF32::new(vec![1.0, 2.0])).unwrap();
+        locals.set_item("b", ListF32::new(vec![4.0])).unwrap();
+        run(
+            py,
+            &locals,
+            c"import warnings
+t.push_shape(2)
+t.Set_fp32_contents(a)
+assert t.len_shape() == 1 and not t.is_empty_shape()
+assert list(t.get_shape()) == [2]
+assert list(t.take_fp32_contents()) == [1.0, 2.0]
+assert t.replace_fp32_contents([3.0]) == []
+with warnings.catch_warnings(record=True) as caught:
+    warnings.simplefilter('always')
+    t.Push_shape(3)
+    assert t.Len_shape() == 2
+    assert list(t.Replace_fp32_contents(b)) == [3.0]
+assert [w.category for w in caught] == [DeprecationWarning] * 3
+assert 'push_shape' in str(caught[0].message)
+t.shape = [5]
+assert list(t.get_shape()) == [5]
+with warnings.catch_warnings(record=True) as caught:
+    warnings.simplefilter('always')
+    s.Push_dims(1)
+    assert list(s.Get_dims()) == [1]
+    t.Set_fp32_contents(b)
+assert caught == []
+assert not hasattr(s, 'get_dims') and not hasattr(t, 'set_shape')",
+        );
+    });
+}
@@ -0,0 +1,7 @@
+//! 编译期测试：派生宏生成的代码能通过编译（无方法名冲突等）
+#[test]
+fn derive_compiles() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/struct_with_enum_field.rs");
+    t.pass("tests/ui/accessors_with_get_all_set_all.rs");
+}
@@ -0,0 +1,39 @@
+//! 运行期测试：`#[hash = "immutable"]` 的值可作为 set 成员与 dict 键
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use triton_client_macros::ImplPyHash;
+
+#[pyclass]
+#[derive(Clone, PartialEq, prost::Message, ImplPyHash)]
+#[hash = "immutable"]
+pub struct Liveness {
+    #[prost(bool, tag = "1")]
+    pub live: bool,
+}
+
+#[test]
+fn equal_values_share_set_and_dict_entries() {
+    Python::initialize();
+    Python::attach(|py| {
+        let locals = PyDict::new(py);
+        locals
+            .set_item("a", Py::new(py, Liveness { live: true }).unwrap())
+            .unwrap();
+        locals
+            .set_item("b", Py::new(py, Liveness { live: true }).unwrap())
+            .unwrap();
+        locals
+            .set_item("c", Py::new(py, Liveness { live: false }).unwrap())
+            .unwrap();
+        let eval = |code: &std::ffi::CStr| {
+            py.eval(code, None, Some(&locals))
+                .unwrap()
+                .extract::<bool>()
+                .unwrap()
+        };
+        assert!(eval(c"a == b and not a != b and a != c"));
+        assert!(eval(c"len({a, b, c}) == 2"));
+        assert!(eval(c"{a: 1}[b] == 1"));
+        assert!(eval(c"a != 1"));
+    });
+}
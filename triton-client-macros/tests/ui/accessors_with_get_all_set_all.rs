@@ -0,0 +1,19 @@
+//! 与 prost 生成代码形状一致：`get_all, set_all` 生成的 `get_x` / `set_x` 与访问器同时存在
+use triton_client_macros::ImplPyVecAccessors;
+
+#[pyo3::pyclass(get_all, set_all)]
+#[derive(Clone, Default, ImplPyVecAccessors)]
+#[pyclass_accessors(naming = "snake_case")]
+pub struct InferTensorContents {
+    pub int_contents: Vec<i32>,
+    pub fp32_contents: Vec<f32>,
+}
+
+#[pyo3::pyclass(get_all, set_all)]
+#[derive(Clone, Default, ImplPyVecAccessors)]
+pub struct ModelInput {
+    pub name: String,
+    pub dims: Vec<i64>,
+}
+
+fn main() {}
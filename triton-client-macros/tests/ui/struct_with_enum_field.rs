@@ -0,0 +1,48 @@
+//! 与 prost 生成代码形状一致：C-style enum 以 i32 字段存储，oneof 以 Option<enum> 存储
+use triton_client_macros::{ImplPyHash, ImplPyNew};
+
+#[pyo3::pyclass(eq, eq_int)]
+#[derive(Clone, Copy, PartialEq, ImplPyNew)]
+pub enum Kind {
+    KindAuto,
+    KindCPU,
+    KindGPU,
+    XMLParser,
+}
+
+#[pyo3::pyclass]
+#[derive(Clone, ImplPyNew)]
+pub enum Choice {
+    BoolParam(bool),
+    Int64Param(i64),
+    StringParam(String),
+}
+
+#[pyo3::pyclass(get_all, set_all)]
+#[derive(Clone, Default, ImplPyNew)]
+pub struct Group {
+    pub name: String,
+    pub kind: i32,
+    pub choice: Option<Choice>,
+    pub count: i32,
+    pub gpus: Vec<i32>,
+    pub labels: std::collections::HashMap<String, String>,
+}
+
+#[pyo3::pyclass]
+#[derive(Clone, PartialEq, prost::Message, ImplPyHash)]
+#[hash = "immutable"]
+pub struct Liveness {
+    #[prost(bool, tag = "1")]
+    pub live: bool,
+}
+
+#[pyo3::pyclass]
+#[derive(Clone, PartialEq, prost::Message, ImplPyHash)]
+#[hash = "mutable"]
+pub struct Request {
+    #[prost(string, tag = "1")]
+    pub model_name: String,
+}
+
+fn main() {}
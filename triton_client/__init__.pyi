@@ -2,89 +2,309 @@
 High-performance Triton inference client
 """
 from __future__ import annotations
+import typing
 from . import triton_client
-__all__: list = ['__doc__', 'Client', 'ServerLiveResponse', 'ServerReadyResponse', 'ModelReadyRequest', 'ModelReadyResponse', 'ServerMetadataResponse', 'ModelConfig', 'ModelMetadataRequest', 'ModelMetadataResponse', 'ModelInferRequest', 'ModelInferResponse', 'ModelConfigRequest', 'ModelConfigResponse', 'ModelStatisticsRequest', 'ModelStatisticsResponse', 'TraceSettingRequest', 'TraceSettingResponse', 'InferParameter', 'InferTensorContents', 'ModelRepositoryParameter', 'RepositoryIndexRequest', 'RepositoryIndexResponse', 'RepositoryModelLoadRequest', 'RepositoryModelLoadResponse', 'RepositoryModelUnloadRequest', 'RepositoryModelUnloadResponse', 'SystemSharedMemoryStatusRequest', 'SystemSharedMemoryStatusResponse', 'SystemSharedMemoryRegisterRequest', 'SystemSharedMemoryRegisterResponse', 'SystemSharedMemoryUnregisterRequest', 'SystemSharedMemoryUnregisterResponse', 'CudaSharedMemoryStatusRequest', 'CudaSharedMemoryStatusResponse', 'CudaSharedMemoryRegisterRequest', 'CudaSharedMemoryRegisterResponse', 'CudaSharedMemoryUnregisterRequest', 'CudaSharedMemoryUnregisterResponse', 'ParameterChoice', 'TensorMetadata', 'ParameterChoice', 'InferInputTensor', 'RegionStatus', 'InferRequestedOutputTensor', 'InferOutputTensor', 'ModelIndex', 'ListBool', 'ListI8', 'ListI16', 'ListI32', 'ListI64', 'ListU8', 'ListU16', 'ListU32', 'ListU64', 'ListF32', 'ListF64']
+__all__: list = ['__doc__', 'init_runtime', 'Runtime', 'get_log_handle', 'version', 'triton_proto_version', 'LogHandle', 'Client', 'ClientBuilder', 'ModelReadyStream', 'TritonConnectionError', 'TritonBatchError', 'TritonServerError', 'TritonTimeoutError', 'ServerLiveResponse', 'ServerReadyResponse', 'ModelReadyRequest', 'ModelReadyResponse', 'ServerMetadataResponse', 'ModelConfig', 'ModelMetadataRequest', 'ModelMetadataResponse', 'ModelInferRequest', 'ModelInferResponse', 'ModelConfigRequest', 'ModelConfigResponse', 'ModelStatisticsRequest', 'ModelStatisticsResponse', 'TraceSettingRequest', 'TraceSettingResponse', 'InferParameter', 'InferTensorContents', 'ModelRepositoryParameter', 'RepositoryIndexRequest', 'RepositoryIndexResponse', 'RepositoryModelLoadRequest', 'RepositoryModelLoadResponse', 'RepositoryModelUnloadRequest', 'RepositoryModelUnloadResponse', 'SystemSharedMemoryStatusRequest', 'SystemSharedMemoryStatusResponse', 'SystemSharedMemoryRegisterRequest', 'SystemSharedMemoryRegisterResponse', 'SystemSharedMemoryUnregisterRequest', 'SystemSharedMemoryUnregisterResponse', 'CudaSharedMemoryStatusRequest', 'CudaSharedMemoryStatusResponse', 'CudaSharedMemoryRegisterRequest', 'CudaSharedMemoryRegisterResponse', 'CudaSharedMemoryUnregisterRequest', 'CudaSharedMemoryUnregisterResponse', 'DataType', 'ParameterChoice', 'TensorMetadata', 'ParameterChoice', 'InferInputTensor', 'RegionStatus', 'InferRequestedOutputTensor', 'InferOutputTensor', 'ModelIndex', 'StatisticDuration', 'InferStatistics', 'InferResponseStatistics', 'InferBatchStatistics', 'MemoryUsage', 'TraceSettingValue', 'CudaRegionStatus', 'ListBool', 'ListI8', 'ListI16', 'ListI32', 'ListI64', 'ListU8', 'ListU16', 'ListU32', 'ListU64', 'ListF32', 'ListF64', 'VecRefBool', 'VecRefI8', 'VecRefI16', 'VecRefI32', 'VecRefI64', 'VecRefU8', 'VecRefU16', 'VecRefU32', 'VecRefU64', 'VecRefF32', 'VecRefF64']
 class Client:
     """
     Triton Client
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, url, access_token=None, runtime=None):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-    def cuda_shared_memory_register(self, req):
+    @staticmethod
+    def mock(responses):
+        """
+        Create a client answering calls from `responses` instead of a server.
+
+        `responses` maps method names (e.g. `"model_infer"`) to a response object or to a
+        callable receiving the request. Mock clients ignore `close()`.
+        """
+    def __repr__(self):
+        """
+        Return repr(self).
+        """
+    def calls_to(self, method):
+        """
+        Requests received by a mock client for `method`, oldest first.
+        """
+    def close(self):
+        """
+        Close the client and release the underlying gRPC channel.
+        """
+    def cuda_shared_memory_register(self, req, headers=None):
         """
         Register a CUDA-shared-memory region.
         """
-    def cuda_shared_memory_status(self, req):
+    def cuda_shared_memory_status(self, req, headers=None):
         """
         Get the status of all registered CUDA-shared-memory regions.
         """
-    def cuda_shared_memory_unregister(self, req):
+    def cuda_shared_memory_unregister(self, req, headers=None):
         """
         Unregister a CUDA-shared-memory region.
         """
-    def model_config(self, req):
+    def invalidate_metadata_cache(self):
+        """
+        Drop the responses cached by `server_metadata_cached` and `model_metadata_cached`.
+        """
+    def is_closed(self):
+        """
+        Whether the client has been closed.
+        """
+    def list_all_models(self, headers=None):
+        """
+        Names of all models in the repository, whatever their state.
+        """
+    def list_ready_models(self, headers=None):
+        """
+        Names of the models in the repository that are ready for inference.
+        """
+    def model_config(self, req, headers=None):
         """
         Get model configuration.
         """
-    def model_infer(self, req):
+    def model_infer(self, req, headers=None, deadline=None, validate=False, grpc_timeout_ms=None):
         """
         Perform inference using a specific model.
+
+        `deadline` is a Unix timestamp in seconds; a past deadline raises `TritonTimeoutError`
+        without sending the request. With `validate=True` the request is checked by
+        `ModelInferRequest.validate()` first and `ValueError` is raised on problems.
+
+        `grpc_timeout_ms` forwards a relative deadline, e.g. one propagated from an upstream
+        call; tonic sends it as the `grpc-timeout` header so the server enforces it too.
+        With both given, the earlier of the two applies.
+
+        Requests larger than `ClientBuilder.max_request_size_bytes` raise `ValueError`.
+        """
+    def model_infer_many(self, reqs, headers=None):
+        """
+        Perform inference for multiple requests concurrently, returning responses in input order.
+
+        Like other calls, a request failing with `UNAVAILABLE` is retried once on the next server.
         """
-    def model_metadata(self, req):
+    def model_metadata(self, req, headers=None):
         """
         Get model metadata.
         """
-    def model_ready(self, req):
+    def model_metadata_cached(self, model_name, version=..., ttl_secs=60.0, headers=None):
+        """
+        Metadata of a model, fetched at most once per `ttl_secs` for each model and version.
+
+        Cached responses are returned whatever `headers` are passed.
+        """
+    def model_ready(self, req, headers=None):
         """
         Check readiness of a model in the inference server.
         """
-    def model_statistics(self, req):
+    def model_ready_stream(self, model_name, version=..., interval_ms=1000, max_polls=None):
+        """
+        Poll readiness of a model every `interval_ms`, yielding `True` or `False` per check.
+
+        Iterates until the caller stops, or after `max_polls` checks when given.
+        A `ClientBuilder.interceptor` runs once, and its headers are sent with every check.
+        """
+    def model_statistics(self, req, headers=None):
         """
         Get the cumulative inference statistics for a model.
         """
-    def repository_index(self, req):
+    def model_unload_all(self, dry_run=False, headers=None):
+        """
+        Unload every ready model, returning the names of the unloaded models.
+
+        All unloads are attempted; if any fail, `TritonBatchError` holds one optional error
+        per model. With `dry_run=True` the models are only listed.
+        """
+    def ping(self, headers=None):
+        """
+        Whether the server is both live and ready; the two checks run concurrently.
+        """
+    def ping_with_model(self, model_name, version=..., headers=None):
+        """
+        Like `ping`, additionally requiring model `model_name` to be ready.
+        """
+    def repository_index(self, req, headers=None):
         """
         Get the index of model repository contents.
+
+        `models` is a list of `ModelIndex`, e.g. `[m.name for m in resp.models]`.
         """
-    def repository_model_load(self, req):
+    def repository_model_load(self, req, headers=None):
         """
         Load or reload a model from a repository.
         """
-    def repository_model_unload(self, req):
+    def repository_model_unload(self, req, headers=None):
         """
         Unload a model.
         """
-    def server_live(self):
+    def server_live(self, headers=None):
         """
         Check liveness of the inference server.
         """
-    def server_metadata(self):
+    def server_metadata(self, headers=None):
         """
         Get server metadata.
         """
-    def server_ready(self):
+    def server_metadata_cached(self, ttl_secs=60.0, headers=None):
+        """
+        Server metadata, fetched at most once per `ttl_secs` and shared by clones of the client.
+
+        Cached responses are returned whatever `headers` are passed.
+        """
+    def server_ready(self, headers=None):
         """
         Check readiness of the inference server.
         """
-    def system_shared_memory_register(self, req):
+    def system_shared_memory_register(self, req, headers=None):
         """
         Register a system-shared-memory region.
         """
-    def system_shared_memory_status(self, req):
+    def system_shared_memory_status(self, req, headers=None):
         """
         Get the status of all registered system-shared-memory regions.
         """
-    def system_shared_memory_unregister(self, req):
+    def system_shared_memory_unregister(self, req, headers=None):
         """
         Unregister a system-shared-memory region.
         """
-    def trace_setting(self, req):
+    def trace_setting(self, req, headers=None):
         """
         Update and get the trace setting of the Triton server.
         """
+class ClientBuilder:
+    """
+    Builds a [`Client`] over one or more servers.
+
+    ```python
+    client = ClientBuilder().servers(["http://a:8001", "http://b:8001"]).strategy("random").build()
+    ```
+    """
+    @staticmethod
+    def __new__(cls):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    def access_token(self, token=None):
+        """
+        Bearer token sent with every request; enables TLS. An `authorization` entry in the
+        `headers` of a call replaces it for that call.
+        """
+    def build(self):
+        """
+        Connect and build the client.
+
+        A single server is connected eagerly; with several servers connections are made on
+        first use, so an unreachable server does not prevent construction.
+
+        TLS is configured from the `TRITON_TLS_CA_CERT`, `TRITON_TLS_CLIENT_CERT` and
+        `TRITON_TLS_CLIENT_KEY` PEM file env vars when set.
+        """
+    def circuit_breaker(self, failure_threshold, reset_timeout_secs):
+        """
+        Stop calling a server for `reset_timeout_secs` after `failure_threshold` consecutive
+        `UNAVAILABLE` failures; then a single probe call decides whether to resume.
+        """
+    def http2_initial_stream_window_size(self, size):
+        """
+        HTTP/2 flow control window per stream, in bytes. The 64 KiB default throttles large
+        tensors; 16 MiB (`16 * 1024 * 1024`) suits payloads of 1 MB and more.
+        """
+    def http2_keep_alive_interval(self, interval_secs):
+        """
+        Send an HTTP/2 keep-alive ping every `interval_secs` seconds, e.g. below the idle
+        timeout of a proxy; only while calls are in flight unless `http2_keep_alive_while_idle`.
+        """
+    def http2_keep_alive_while_idle(self, enabled=True):
+        """
+        Keep sending HTTP/2 keep-alive pings while no call is in flight, so proxies and
+        load balancers do not drop idle connections. Pings are sent every 30 seconds unless
+        `http2_keep_alive_interval` is set.
+        """
+    def http2_max_frame_size(self, size):
+        """
+        Largest HTTP/2 frame accepted, in bytes, between 16 KiB and 16 MiB - 1; e.g.
+        1 MiB to cut per-frame overhead for large tensors.
+        """
+    def interceptor(self, f):
+        """
+        Call `f(method, metadata)` before each gRPC request, e.g. to refresh auth tokens or
+        sign requests. `method` is the snake_case method name (`"model_infer"`), `metadata`
+        a dict of the request headers; the returned dict is merged into them.
+
+        `f` runs on the calling thread before the request is sent, and exceptions it raises
+        propagate unchanged. `model_ready_stream` calls it once, when the stream is created.
+        """
+    def log_requests(self, enabled=True):
+        """
+        Log every request and response at `DEBUG` level; also enabled by `LOG_REQUESTS=1`.
+        """
+    def max_request_size_bytes(self, size=None):
+        """
+        Reject `model_infer` requests whose `estimated_wire_size()` exceeds `size` bytes with
+        `ValueError` instead of sending them; `None` disables the check.
+        """
+    def runtime(self, runtime):
+        """
+        Runtime running the client's calls, instead of the shared one.
+        """
+    def servers(self, urls):
+        """
+        Servers to send requests to.
+        """
+    def strategy(self, strategy):
+        """
+        Server selection strategy, `"round_robin"` (default) or `"random"`.
+        """
+class CudaRegionStatus:
+    """
+    @@
+    @@  .. cpp:var:: message RegionStatus
+    @@
+    @@     Status for a shared memory region.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, name, device_id, byte_size):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def byte_size(self):
+        """
+        @@    .. cpp:var:: uint64 byte_size
+        @@
+        @@       Size of the shared memory region, in bytes.
+        @@
+        """
+    @property
+    def device_id(self):
+        """
+        @@    .. cpp:var:: uin64 device_id
+        @@
+        @@       The GPU device ID where the cudaIPC handle was created.
+        @@
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@    .. cpp:var:: string name
+        @@
+        @@       The name for the shared memory region.
+        @@
+        """
 class CudaSharedMemoryRegisterRequest:
     """
     @@
@@ -94,18 +314,97 @@ class CudaSharedMemoryRegisterRequest:
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, name, raw_handle, device_id, byte_size):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-    def Get_raw_handle(self):
+    @staticmethod
+    def for_device(name, byte_size, device_id, raw_handle=None):
+        """
+        Register request for `byte_size` bytes of CUDA memory on `device_id`.
+
+        `raw_handle` is the serialized `cudaIpcMemHandle_t` of the allocation.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
         ...
+    def Get_raw_handle(self):
+        """
+        Deprecated: use `get_raw_handle` instead.
+        """
+    def IsEmpty_raw_handle(self):
+        """
+        Deprecated: use `is_empty_raw_handle` instead.
+        """
+    def Len_raw_handle(self):
+        """
+        Deprecated: use `len_raw_handle` instead.
+        """
+    def Push_raw_handle(self, val):
+        """
+        Deprecated: use `push_raw_handle` instead.
+        """
     def Replace_raw_handle(self, val):
-        ...
+        """
+        Deprecated: use `replace_raw_handle` instead.
+        """
     def Set_raw_handle(self, list):
         ...
     def Take_raw_handle(self):
+        """
+        Deprecated: use `take_raw_handle` instead.
+        """
+    def get_raw_handle(self):
+        ...
+    def is_empty_raw_handle(self):
+        ...
+    def len_raw_handle(self):
+        ...
+    def push_raw_handle(self, val):
+        ...
+    def replace_raw_handle(self, val):
+        ...
+    def take_raw_handle(self):
+        ...
+    def to_proto_bytes(self):
         ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def byte_size(self):
+        """
+        @@  .. cpp:var:: uint64 byte_size
+        @@
+        @@     Size of the shared memory block, in bytes.
+        @@
+        """
+    @property
+    def device_id(self):
+        """
+        @@  .. cpp:var:: int64 device_id
+        @@
+        @@     The GPU device ID on which the cudaIPC handle was created.
+        @@
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@  .. cpp:var:: string name
+        @@
+        @@     The name of the region to register.
+        @@
+        """
+    @property
+    def raw_handle(self):
+        """
+        @@  .. cpp:var:: bytes raw_handle
+        @@
+        @@     The raw serialized cudaIPC handle.
+        @@
+        """
 class CudaSharedMemoryRegisterResponse:
     """
     @@
@@ -115,10 +414,19 @@ class CudaSharedMemoryRegisterResponse:
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
 class CudaSharedMemoryStatusRequest:
     """
     @@
@@ -128,10 +436,29 @@ class CudaSharedMemoryStatusRequest:
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, name):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@  .. cpp:var:: string name
+        @@
+        @@     The name of the region to get status for. If empty the
+        @@     status is returned for all registered regions.
+        @@
+        """
 class CudaSharedMemoryStatusResponse:
     """
     @@
@@ -141,10 +468,29 @@ class CudaSharedMemoryStatusResponse:
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, regions):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def regions(self):
+        """
+        @@
+        @@  .. cpp:var:: map\<string,RegionStatus> regions
+        @@
+        @@     Status for each of the registered regions, indexed by
+        @@     region name.
+        @@
+        """
 class CudaSharedMemoryUnregisterRequest:
     """
     @@
@@ -154,10 +500,29 @@ class CudaSharedMemoryUnregisterRequest:
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, name):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@  .. cpp:var:: string name
+        @@
+        @@     The name of the cuda region to unregister. If empty
+        @@     all cuda shared-memory regions are unregistered.
+        @@
+        """
 class CudaSharedMemoryUnregisterResponse:
     """
     @@
@@ -167,593 +532,1254 @@ class CudaSharedMemoryUnregisterResponse:
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class InferInputTensor:
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+class DataType:
     """
     @@
-    @@  .. cpp:var:: message InferInputTensor
+    @@.. cpp:enum:: DataType
     @@
-    @@     An input tensor for an inference request.
+    @@   Data types supported for input and output tensors.
     @@
     """
+    TypeInvalid: typing.ClassVar[DataType]  # value = DataType.TypeInvalid
+    TypeBool: typing.ClassVar[DataType]  # value = DataType.TypeBool
+    TypeUint8: typing.ClassVar[DataType]  # value = DataType.TypeUint8
+    TypeUint16: typing.ClassVar[DataType]  # value = DataType.TypeUint16
+    TypeUint32: typing.ClassVar[DataType]  # value = DataType.TypeUint32
+    TypeUint64: typing.ClassVar[DataType]  # value = DataType.TypeUint64
+    TypeInt8: typing.ClassVar[DataType]  # value = DataType.TypeInt8
+    TypeInt16: typing.ClassVar[DataType]  # value = DataType.TypeInt16
+    TypeInt32: typing.ClassVar[DataType]  # value = DataType.TypeInt32
+    TypeInt64: typing.ClassVar[DataType]  # value = DataType.TypeInt64
+    TypeFp16: typing.ClassVar[DataType]  # value = DataType.TypeFp16
+    TypeFp32: typing.ClassVar[DataType]  # value = DataType.TypeFp32
+    TypeFp64: typing.ClassVar[DataType]  # value = DataType.TypeFp64
+    TypeString: typing.ClassVar[DataType]  # value = DataType.TypeString
+    TypeBf16: typing.ClassVar[DataType]  # value = DataType.TypeBf16
     @staticmethod
     def __new__(type, *args, **kwargs):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-    def Get_shape(self):
+    @staticmethod
+    def from_numpy_dtype(dtype):
+        """
+        `DataType` of a numpy dtype string, e.g. `"float32"`.
+        """
+    @staticmethod
+    def type_bf16():
         ...
-    def Replace_shape(self, val):
+    @staticmethod
+    def type_bool():
         ...
-    def Set_shape(self, list):
+    @staticmethod
+    def type_fp16():
         ...
-    def Take_shape(self):
+    @staticmethod
+    def type_fp32():
         ...
-class InferOutputTensor:
-    """
-    @@
-    @@  .. cpp:var:: message InferOutputTensor
-    @@
-    @@     An output tensor returned for an inference request.
-    @@
-    """
     @staticmethod
-    def __new__(type, *args, **kwargs):
-        """
-        Create and return a new object.  See help(type) for accurate signature.
-        """
-    def Get_shape(self):
+    def type_fp64():
         ...
-    def Replace_shape(self, val):
+    @staticmethod
+    def type_int16():
         ...
-    def Set_shape(self, list):
+    @staticmethod
+    def type_int32():
         ...
-    def Take_shape(self):
+    @staticmethod
+    def type_int64():
         ...
-class InferParameter:
-    """
-    @@
-    @@.. cpp:var:: message InferParameter
-    @@
-    @@   An inference parameter value.
-    @@
-    """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def type_int8():
+        ...
+    @staticmethod
+    def type_invalid():
+        ...
+    @staticmethod
+    def type_string():
+        ...
+    @staticmethod
+    def type_uint16():
+        ...
+    @staticmethod
+    def type_uint32():
+        ...
+    @staticmethod
+    def type_uint64():
+        ...
+    @staticmethod
+    def type_uint8():
+        ...
+    @staticmethod
+    def values():
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        按 proto 声明顺序返回所有变体，如 `list(DataType.values())`
+
+        pyo3 无法为类设置元类，因此不支持直接 `list(DataType)`
         """
-class InferRequestedOutputTensor:
+    def __int__(self):
+        """
+        int(self)
+        """
+    def __repr__(self):
+        """
+        Return repr(self).
+        """
+    def to_numpy_dtype(self):
+        """
+        numpy dtype string, e.g. `"float32"` for `TypeFp32`.
+        """
+class InferBatchStatistics:
     """
     @@
-    @@  .. cpp:var:: message InferRequestedOutputTensor
+    @@.. cpp:var:: message InferBatchStatistics
     @@
-    @@     An output tensor requested for an inference request.
+    @@   Inference batch statistics.
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, batch_size, compute_input=None, compute_infer=None, compute_output=None):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class InferTensorContents:
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def batch_size(self):
+        """
+        @@  .. cpp:var:: uint64 batch_size
+        @@
+        @@     The size of the batch.
+        @@
+        """
+    @property
+    def compute_infer(self):
+        """
+        @@  .. cpp:var:: StatisticDuration compute_infer
+        @@
+        @@     The count and cumulative duration to execute the model with the given
+        @@     batch size.
+        @@
+        """
+    @property
+    def compute_input(self):
+        """
+        @@  .. cpp:var:: StatisticDuration compute_input
+        @@
+        @@     The count and cumulative duration to prepare input tensor data as
+        @@     required by the model framework / backend with the given batch size.
+        @@     For example, this duration should include the time to copy input
+        @@     tensor data to the GPU.
+        @@
+        """
+    @property
+    def compute_output(self):
+        """
+        @@  .. cpp:var:: StatisticDuration compute_output
+        @@
+        @@     The count and cumulative duration to extract output tensor data
+        @@     produced by the model framework / backend with the given batch size.
+        @@     For example, this duration should include the time to copy output
+        @@     tensor data from the GPU.
+        @@
+        """
+class InferInputTensor:
     """
     @@
-    @@.. cpp:var:: message InferTensorContents
+    @@  .. cpp:var:: message InferInputTensor
     @@
-    @@   The data contained in a tensor represented by the repeated type
-    @@   that matches the tensor's data type. Protobuf oneof is not used
-    @@   because oneofs cannot contain repeated fields.
+    @@     An input tensor for an inference request.
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, name, datatype, shape, parameters, contents=None):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-    def Get_bool_contents(self):
+    @staticmethod
+    def from_dict(d):
+        """
+        Build from a dict with the keys produced by `to_dict`; missing keys keep their defaults.
+
+        A `data` numpy array goes through `from_numpy`, and explicit keys override what it sets.
+        """
+    @staticmethod
+    def from_numpy(name, arr):
+        """
+        Build a tensor named `name` from a numpy array, taking `shape` and `datatype` from it.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
         ...
-    def Get_fp32_contents(self):
-        ...
-    def Get_fp64_contents(self):
-        ...
-    def Get_int64_contents(self):
-        ...
-    def Get_int_contents(self):
-        ...
-    def Get_uint64_contents(self):
-        ...
-    def Get_uint_contents(self):
-        ...
-    def Replace_bool_contents(self, val):
-        ...
-    def Replace_fp32_contents(self, val):
-        ...
-    def Replace_fp64_contents(self, val):
-        ...
-    def Replace_int64_contents(self, val):
-        ...
-    def Replace_int_contents(self, val):
-        ...
-    def Replace_uint64_contents(self, val):
-        ...
-    def Replace_uint_contents(self, val):
-        ...
-    def Set_bool_contents(self, list):
-        ...
-    def Set_fp32_contents(self, list):
-        ...
-    def Set_fp64_contents(self, list):
-        ...
-    def Set_int64_contents(self, list):
-        ...
-    def Set_int_contents(self, list):
-        ...
-    def Set_uint64_contents(self, list):
-        ...
-    def Set_uint_contents(self, list):
-        ...
-    def Take_bool_contents(self):
-        ...
-    def Take_fp32_contents(self):
-        ...
-    def Take_fp64_contents(self):
-        ...
-    def Take_int64_contents(self):
-        ...
-    def Take_int_contents(self):
-        ...
-    def Take_uint64_contents(self):
-        ...
-    def Take_uint_contents(self):
-        ...
-class ListBool:
-    """
-    """
-    @staticmethod
-    def __new__(type, *args, **kwargs):
+    def Get_shape(self):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        Deprecated: use `get_shape` instead.
         """
-    @staticmethod
-    def from_array(arr):
+    def IsEmpty_shape(self):
         """
-        from numpy array construct
+        Deprecated: use `is_empty_shape` instead.
         """
-    def __delitem__(self, key):
+    def Len_shape(self):
         """
-        Delete self[key].
+        Deprecated: use `len_shape` instead.
         """
-    def __getitem__(self, key):
+    def Push_shape(self, val):
         """
-        Return self[key].
+        Deprecated: use `push_shape` instead.
         """
-    def __len__(self):
+    def Replace_shape(self, val):
         """
-        Return len(self).
+        Deprecated: use `replace_shape` instead.
         """
-    def __setitem__(self, key, value):
+    def Set_shape(self, list):
+        ...
+    def Take_shape(self):
         """
-        Set self[key] to value.
+        Deprecated: use `take_shape` instead.
         """
-    def append(self, item):
+    def __copy__(self):
+        ...
+    def __deepcopy__(self, _memo):
+        ...
+    def __hash__(self):
         """
-        push item
+        Return hash(self).
         """
-    def clear(self):
+    def get_bytes_content(self):
         """
-        clear
+        The single `BYTES` element set by `set_bytes_content`; raises `ValueError` unless the
+        tensor holds exactly one.
         """
-    def copy(self):
+    def get_shape(self):
+        ...
+    def is_empty_shape(self):
+        ...
+    def len_shape(self):
+        ...
+    def push_shape(self, val):
+        ...
+    def replace_shape(self, val):
+        ...
+    def reshape(self, new_shape):
         """
-        copy
+        Copy of this tensor declaring `new_shape`; contents are kept as is.
+
+        The element count must not change. One dimension may be `-1` to infer it, as in numpy.
         """
-    def insert(self, index, value):
+    def set_bytes_content(self, data):
         """
-        insert item
+        Set a single `BYTES` element from `data`, stored in `contents.bytes_contents`.
+
+        `datatype` becomes `"BYTES"` and an empty `shape` becomes `[1]`. In
+        `ModelInferRequest.raw_input_contents` Triton instead expects `BYTES` tensors serialized
+        as each element's length as a 4-byte little-endian integer followed by its bytes.
         """
-    def into_array(self):
+    def set_shared_memory(self, region, byte_size, offset=0):
         """
-        move to numpy array
+        Read this input from a registered shared memory region instead of `contents`.
         """
-    def remove(self, index):
+    def take_shape(self):
+        ...
+    def to_dict(self):
         """
-        remove item
+        Dict with the same keys accepted by `from_dict`.
         """
-    def to_array(self):
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
         """
-        to numpy array
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
         """
-    def to_list(self):
+    @property
+    def contents(self):
         """
-        to Python list
+        @@    .. cpp:var:: InferTensorContents contents
+        @@
+        @@       The tensor contents using a data-type format. This field
+        @@       must not be specified if tensor contents are being specified
+        @@       in ModelInferRequest.raw_input_contents.
+        @@
         """
-class ListF32:
+    @property
+    def datatype(self):
+        """
+        @@
+        @@    .. cpp:var:: string datatype
+        @@
+        @@       The tensor data type.
+        @@
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@    .. cpp:var:: string name
+        @@
+        @@       The tensor name.
+        @@
+        """
+    @property
+    def parameters(self):
+        """
+        @@    .. cpp:var:: map\<string,InferParameter> parameters
+        @@
+        @@       Optional inference input tensor parameters.
+        @@
+        """
+    @property
+    def shape(self):
+        """
+        @@
+        @@    .. cpp:var:: int64 shape (repeated)
+        @@
+        @@       The tensor shape.
+        @@
+        """
+class InferOutputTensor:
     """
+    @@
+    @@  .. cpp:var:: message InferOutputTensor
+    @@
+    @@     An output tensor returned for an inference request.
+    @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, name, datatype, shape, parameters, contents=None):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
     @staticmethod
-    def from_array(arr):
+    def from_dict(d):
         """
-        from numpy array construct
+        Build from a dict with the keys produced by `to_dict`; missing keys keep their defaults.
         """
-    def __delitem__(self, key):
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def Get_shape(self):
         """
-        Delete self[key].
+        Deprecated: use `get_shape` instead.
         """
-    def __getitem__(self, key):
+    def IsEmpty_shape(self):
         """
-        Return self[key].
+        Deprecated: use `is_empty_shape` instead.
         """
-    def __len__(self):
+    def Len_shape(self):
         """
-        Return len(self).
+        Deprecated: use `len_shape` instead.
         """
-    def __setitem__(self, key, value):
+    def Push_shape(self, val):
         """
-        Set self[key] to value.
+        Deprecated: use `push_shape` instead.
         """
-    def append(self, item):
+    def Replace_shape(self, val):
         """
-        push item
+        Deprecated: use `replace_shape` instead.
         """
-    def clear(self):
+    def Set_shape(self, list):
+        ...
+    def Take_shape(self):
         """
-        clear
+        Deprecated: use `take_shape` instead.
         """
-    def copy(self):
+    def get_shape(self):
+        ...
+    def is_empty_shape(self):
+        ...
+    def len_shape(self):
+        ...
+    def push_shape(self, val):
+        ...
+    def replace_shape(self, val):
+        ...
+    def take_shape(self):
+        ...
+    def to_dict(self):
         """
-        copy
+        Dict with the same keys accepted by `from_dict`.
         """
-    def insert(self, index, value):
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
         """
-        insert item
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
         """
-    def into_array(self):
+    @property
+    def contents(self):
         """
-        move to numpy array
+        @@    .. cpp:var:: InferTensorContents contents
+        @@
+        @@       The tensor contents using a data-type format. This field
+        @@       must not be specified if tensor contents are being specified
+        @@       in ModelInferResponse.raw_output_contents.
+        @@
         """
-    def remove(self, index):
+    @property
+    def datatype(self):
         """
-        remove item
+        @@
+        @@    .. cpp:var:: string datatype
+        @@
+        @@       The tensor data type.
+        @@
         """
-    def to_array(self):
+    @property
+    def name(self):
         """
-        to numpy array
+        @@
+        @@    .. cpp:var:: string name
+        @@
+        @@       The tensor name.
+        @@
         """
-    def to_list(self):
+    @property
+    def parameters(self):
         """
-        to Python list
+        @@    .. cpp:var:: map\<string,InferParameter> parameters
+        @@
+        @@       Optional output tensor parameters.
+        @@
         """
-class ListF64:
+    @property
+    def shape(self):
+        """
+        @@
+        @@    .. cpp:var:: int64 shape (repeated)
+        @@
+        @@       The tensor shape.
+        @@
+        """
+class InferParameter:
     """
+    @@
+    @@.. cpp:var:: message InferParameter
+    @@
+    @@   An inference parameter value.
+    @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, parameter_choice=None):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
     @staticmethod
-    def from_array(arr):
-        """
-        from numpy array construct
-        """
-    def __delitem__(self, key):
-        """
-        Delete self[key].
-        """
-    def __getitem__(self, key):
-        """
-        Return self[key].
-        """
-    def __len__(self):
-        """
-        Return len(self).
-        """
-    def __setitem__(self, key, value):
+    def from_proto_bytes(data):
+        ...
+    @staticmethod
+    def from_value(val):
         """
-        Set self[key] to value.
+        Build a parameter from a Python `bool`, `int`, `float` or `str`.
+
+        Integers beyond the `int64` range are stored as `uint64`. A `float` is stored as
+        `double_param` rather than a formatted `string_param`, so it keeps full precision and
+        `to_value` returns a `float` again.
         """
-    def append(self, item):
+    def __copy__(self):
+        ...
+    def __deepcopy__(self, _memo):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def to_value(self):
         """
-        push item
+        The inner value as a native Python object, or `None` if unset.
         """
-    def clear(self):
+    def update(self, **kwargs):
         """
-        clear
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
         """
-    def copy(self):
+    @property
+    def parameter_choice(self):
         """
-        copy
+        @@  .. cpp:var:: oneof parameter_choice
+        @@
+        @@     The parameter value can be a string, an int64,
+        @@     an uint64, a double, or a boolean
+        @@
+        @@     Note: double and uint64 are currently
+        @@           placeholders for future use and
+        @@           are not supported for custom parameters
+        @@
         """
-    def insert(self, index, value):
+    @parameter_choice.setter
+    def parameter_choice(self, arg0):
+        ...
+class InferRequestedOutputTensor:
+    """
+    @@
+    @@  .. cpp:var:: message InferRequestedOutputTensor
+    @@
+    @@     An output tensor requested for an inference request.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, name, parameters):
         """
-        insert item
+        Create and return a new object.  See help(type) for accurate signature.
         """
-    def into_array(self):
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def __copy__(self):
+        ...
+    def __deepcopy__(self, _memo):
+        ...
+    def __hash__(self):
         """
-        move to numpy array
+        Return hash(self).
         """
-    def remove(self, index):
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
         """
-        remove item
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
         """
-    def to_array(self):
+    @property
+    def name(self):
         """
-        to numpy array
+        @@
+        @@    .. cpp:var:: string name
+        @@
+        @@       The tensor name.
+        @@
         """
-    def to_list(self):
+    @property
+    def parameters(self):
         """
-        to Python list
+        @@    .. cpp:var:: map\<string,InferParameter> parameters
+        @@
+        @@       Optional requested output tensor parameters.
+        @@
         """
-class ListI16:
+class InferResponseStatistics:
     """
+    @@
+    @@.. cpp:var:: message InferResponseStatistics
+    @@
+    @@   Statistics per response.
+    @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, compute_infer=None, compute_output=None, success=None, fail=None, empty_response=None, cancel=None):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
     @staticmethod
-    def from_array(arr):
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
         """
-        from numpy array construct
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
         """
-    def __delitem__(self, key):
+    @property
+    def cancel(self):
         """
-        Delete self[key].
+        @@  .. cpp:var:: StatisticDuration cancel
+        @@
+        @@     The count and cumulative duration, for cleaning up resources held by
+        @@     a cancelled request, for cancelled responses.
+        @@
         """
-    def __getitem__(self, key):
+    @cancel.setter
+    def cancel(self, arg0):
+        ...
+    @property
+    def compute_infer(self):
         """
-        Return self[key].
+        @@  .. cpp:var:: StatisticDuration compute_infer
+        @@
+        @@     The count and cumulative duration to compute a response.
+        @@
         """
-    def __len__(self):
+    @compute_infer.setter
+    def compute_infer(self, arg0):
+        ...
+    @property
+    def compute_output(self):
         """
-        Return len(self).
+        @@  .. cpp:var:: StatisticDuration compute_output
+        @@
+        @@     The count and cumulative duration to extract the output tensors of a
+        @@     response.
+        @@
         """
-    def __setitem__(self, key, value):
+    @compute_output.setter
+    def compute_output(self, arg0):
+        ...
+    @property
+    def empty_response(self):
         """
-        Set self[key] to value.
+        @@  .. cpp:var:: StatisticDuration empty_response
+        @@
+        @@     The count and cumulative duration for empty responses.
+        @@
         """
-    def append(self, item):
+    @empty_response.setter
+    def empty_response(self, arg0):
+        ...
+    @property
+    def fail(self):
         """
-        push item
+        @@  .. cpp:var:: StatisticDuration fail
+        @@
+        @@     The count and cumulative duration for failed responses.
+        @@
         """
-    def clear(self):
+    @fail.setter
+    def fail(self, arg0):
+        ...
+    @property
+    def success(self):
         """
-        clear
+        @@  .. cpp:var:: StatisticDuration success
+        @@
+        @@     The count and cumulative duration for successful responses.
+        @@
         """
-    def copy(self):
+    @success.setter
+    def success(self, arg0):
+        ...
+class InferStatistics:
+    """
+    @@
+    @@.. cpp:var:: message InferStatistics
+    @@
+    @@   Inference statistics.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, success=None, fail=None, queue=None, compute_input=None, compute_infer=None, compute_output=None, cache_hit=None, cache_miss=None):
         """
-        copy
+        Create and return a new object.  See help(type) for accurate signature.
         """
-    def insert(self, index, value):
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def cache_hit(self):
+        """
+        @@  .. cpp:var:: StatisticDuration cache_hit
+        @@
+        @@     The count of response cache hits and cumulative duration to lookup
+        @@     and extract output tensor data from the Response Cache on a cache
+        @@     hit. For example, this duration should include the time to copy
+        @@     output tensor data from the Response Cache to the response object.
+        @@     On cache hits, triton does not need to go to the model/backend
+        @@     for the output tensor data, so the "compute_input", "compute_infer",
+        @@     and "compute_output" fields are not updated. Assuming the response
+        @@     cache is enabled for a given model, a cache hit occurs for a
+        @@     request to that model when the request metadata (model name,
+        @@     model version, model inputs) hashes to an existing entry in the
+        @@     cache. On a cache miss, the request hash and response output tensor
+        @@     data is added to the cache. See response cache docs for more info:
+        @@
+        <https://github.com/triton-inference-server/server/blob/main/docs/response_cache.md>
+        @@
+        """
+    @cache_hit.setter
+    def cache_hit(self, arg0):
+        ...
+    @property
+    def cache_miss(self):
+        """
+        @@  .. cpp:var:: StatisticDuration cache_miss
+        @@
+        @@     The count of response cache misses and cumulative duration to lookup
+        @@     and insert output tensor data from the computed response to the
+        cache.
+        @@     For example, this duration should include the time to copy
+        @@     output tensor data from the response object to the Response Cache.
+        @@     Assuming the response cache is enabled for a given model, a cache
+        @@     miss occurs for a request to that model when the request metadata
+        @@     does NOT hash to an existing entry in the cache. See the response
+        @@     cache docs for more info:
+        @@
+        <https://github.com/triton-inference-server/server/blob/main/docs/response_cache.md>
+        @@
+        """
+    @cache_miss.setter
+    def cache_miss(self, arg0):
+        ...
+    @property
+    def compute_infer(self):
+        """
+        @@  .. cpp:var:: StatisticDuration compute_infer
+        @@
+        @@     The count and cumulative duration to execute the model.
+        @@     The "compute_infer" count and cumulative duration do not account for
+        @@     requests that were a cache hit. See the "cache_hit" field for more
+        @@     info.
+        @@
+        """
+    @compute_infer.setter
+    def compute_infer(self, arg0):
+        ...
+    @property
+    def compute_input(self):
+        """
+        @@  .. cpp:var:: StatisticDuration compute_input
+        @@
+        @@     The count and cumulative duration to prepare input tensor data as
+        @@     required by the model framework / backend. For example, this duration
+        @@     should include the time to copy input tensor data to the GPU.
+        @@     The "compute_input" count and cumulative duration do not account for
+        @@     requests that were a cache hit. See the "cache_hit" field for more
+        @@     info.
+        @@
+        """
+    @compute_input.setter
+    def compute_input(self, arg0):
+        ...
+    @property
+    def compute_output(self):
+        """
+        @@  .. cpp:var:: StatisticDuration compute_output
+        @@
+        @@     The count and cumulative duration to extract output tensor data
+        @@     produced by the model framework / backend. For example, this duration
+        @@     should include the time to copy output tensor data from the GPU.
+        @@     The "compute_output" count and cumulative duration do not account for
+        @@     requests that were a cache hit. See the "cache_hit" field for more
+        @@     info.
+        @@
+        """
+    @compute_output.setter
+    def compute_output(self, arg0):
+        ...
+    @property
+    def fail(self):
         """
-        insert item
+        @@  .. cpp:var:: StatisticDuration fail
+        @@
+        @@     Cumulative count and duration for failed inference
+        @@     request.
+        @@
         """
-    def into_array(self):
+    @fail.setter
+    def fail(self, arg0):
+        ...
+    @property
+    def queue(self):
+        """
+        @@  .. cpp:var:: StatisticDuration queue
+        @@
+        @@     The count and cumulative duration that inference requests wait in
+        @@     scheduling or other queues. The "queue" count and cumulative
+        @@     duration includes cache hits.
+        @@
+        """
+    @queue.setter
+    def queue(self, arg0):
+        ...
+    @property
+    def success(self):
+        """
+        @@  .. cpp:var:: StatisticDuration success
+        @@
+        @@     Cumulative count and duration for successful inference
+        @@     request. The "success" count and cumulative duration includes
+        @@     cache hits.
+        @@
+        """
+    @success.setter
+    def success(self, arg0):
+        ...
+class InferTensorContents:
+    """
+    @@
+    @@.. cpp:var:: message InferTensorContents
+    @@
+    @@   The data contained in a tensor represented by the repeated type
+    @@   that matches the tensor's data type. Protobuf oneof is not used
+    @@   because oneofs cannot contain repeated fields.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, bool_contents, int_contents, int64_contents, uint_contents, uint64_contents, fp32_contents, fp64_contents, bytes_contents):
         """
-        move to numpy array
+        Create and return a new object.  See help(type) for accurate signature.
         """
-    def remove(self, index):
+    @staticmethod
+    def combine(parts):
         """
-        remove item
+        Concatenate per-sample contents into one, e.g. to build a batched request.
+
+        Every non-empty part must populate the same single content field; raises `ValueError`
+        otherwise.
         """
-    def to_array(self):
+    @staticmethod
+    def from_list(dtype, data):
         """
-        to numpy array
+        Build contents from a list or other iterable of Python values, stored in the field
+        matching the Triton datatype `dtype`, e.g. `from_list("FP32", [1.0, 2.0])`.
+
+        `BYTES` elements may be `bytes` or `str`. Raises `ValueError` for datatypes without a
+        content field, such as `FP16`.
         """
-    def to_list(self):
+    @staticmethod
+    def from_numpy_nd(arr):
         """
-        to Python list
+        Build contents from an N-D numpy array, flattening it into the field matching its dtype.
+
+        8/16-bit integers are widened into `int_contents` / `uint_contents` as in the Triton
+        protocol. The shape is not stored; set it on `InferInputTensor`.
         """
-class ListI32:
-    """
-    """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def from_proto_bytes(data):
+        ...
+    def Get_bool_contents(self):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        Deprecated: use `get_bool_contents` instead.
         """
-    @staticmethod
-    def from_array(arr):
+    def Get_fp32_contents(self):
         """
-        from numpy array construct
+        Deprecated: use `get_fp32_contents` instead.
         """
-    def __delitem__(self, key):
+    def Get_fp64_contents(self):
         """
-        Delete self[key].
+        Deprecated: use `get_fp64_contents` instead.
         """
-    def __getitem__(self, key):
+    def Get_int64_contents(self):
         """
-        Return self[key].
+        Deprecated: use `get_int64_contents` instead.
         """
-    def __len__(self):
+    def Get_int_contents(self):
         """
-        Return len(self).
+        Deprecated: use `get_int_contents` instead.
         """
-    def __setitem__(self, key, value):
+    def Get_uint64_contents(self):
         """
-        Set self[key] to value.
+        Deprecated: use `get_uint64_contents` instead.
         """
-    def append(self, item):
+    def Get_uint_contents(self):
         """
-        push item
+        Deprecated: use `get_uint_contents` instead.
         """
-    def clear(self):
+    def IsEmpty_bool_contents(self):
         """
-        clear
+        Deprecated: use `is_empty_bool_contents` instead.
         """
-    def copy(self):
+    def IsEmpty_fp32_contents(self):
         """
-        copy
+        Deprecated: use `is_empty_fp32_contents` instead.
         """
-    def insert(self, index, value):
+    def IsEmpty_fp64_contents(self):
         """
-        insert item
+        Deprecated: use `is_empty_fp64_contents` instead.
         """
-    def into_array(self):
+    def IsEmpty_int64_contents(self):
         """
-        move to numpy array
+        Deprecated: use `is_empty_int64_contents` instead.
         """
-    def remove(self, index):
+    def IsEmpty_int_contents(self):
         """
-        remove item
+        Deprecated: use `is_empty_int_contents` instead.
         """
-    def to_array(self):
+    def IsEmpty_uint64_contents(self):
         """
-        to numpy array
+        Deprecated: use `is_empty_uint64_contents` instead.
         """
-    def to_list(self):
+    def IsEmpty_uint_contents(self):
         """
-        to Python list
+        Deprecated: use `is_empty_uint_contents` instead.
         """
-class ListI64:
-    """
-    """
-    @staticmethod
-    def __new__(type, *args, **kwargs):
+    def Len_bool_contents(self):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        Deprecated: use `len_bool_contents` instead.
         """
-    @staticmethod
-    def from_array(arr):
+    def Len_fp32_contents(self):
         """
-        from numpy array construct
+        Deprecated: use `len_fp32_contents` instead.
         """
-    def __delitem__(self, key):
+    def Len_fp64_contents(self):
         """
-        Delete self[key].
+        Deprecated: use `len_fp64_contents` instead.
         """
-    def __getitem__(self, key):
+    def Len_int64_contents(self):
         """
-        Return self[key].
+        Deprecated: use `len_int64_contents` instead.
         """
-    def __len__(self):
+    def Len_int_contents(self):
         """
-        Return len(self).
+        Deprecated: use `len_int_contents` instead.
         """
-    def __setitem__(self, key, value):
+    def Len_uint64_contents(self):
         """
-        Set self[key] to value.
+        Deprecated: use `len_uint64_contents` instead.
         """
-    def append(self, item):
+    def Len_uint_contents(self):
         """
-        push item
+        Deprecated: use `len_uint_contents` instead.
         """
-    def clear(self):
+    def Push_bool_contents(self, val):
         """
-        clear
+        Deprecated: use `push_bool_contents` instead.
         """
-    def copy(self):
+    def Push_fp32_contents(self, val):
         """
-        copy
+        Deprecated: use `push_fp32_contents` instead.
         """
-    def insert(self, index, value):
+    def Push_fp64_contents(self, val):
         """
-        insert item
+        Deprecated: use `push_fp64_contents` instead.
         """
-    def into_array(self):
+    def Push_int64_contents(self, val):
         """
-        move to numpy array
+        Deprecated: use `push_int64_contents` instead.
         """
-    def remove(self, index):
+    def Push_int_contents(self, val):
         """
-        remove item
+        Deprecated: use `push_int_contents` instead.
         """
-    def to_array(self):
+    def Push_uint64_contents(self, val):
         """
-        to numpy array
+        Deprecated: use `push_uint64_contents` instead.
         """
-    def to_list(self):
+    def Push_uint_contents(self, val):
         """
-        to Python list
+        Deprecated: use `push_uint_contents` instead.
         """
-class ListI8:
-    """
-    """
-    @staticmethod
-    def __new__(type, *args, **kwargs):
+    def Replace_bool_contents(self, val):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        Deprecated: use `replace_bool_contents` instead.
         """
-    @staticmethod
-    def from_array(arr):
+    def Replace_fp32_contents(self, val):
         """
-        from numpy array construct
+        Deprecated: use `replace_fp32_contents` instead.
         """
-    def __delitem__(self, key):
+    def Replace_fp64_contents(self, val):
         """
-        Delete self[key].
+        Deprecated: use `replace_fp64_contents` instead.
         """
-    def __getitem__(self, key):
+    def Replace_int64_contents(self, val):
         """
-        Return self[key].
+        Deprecated: use `replace_int64_contents` instead.
         """
-    def __len__(self):
+    def Replace_int_contents(self, val):
         """
-        Return len(self).
+        Deprecated: use `replace_int_contents` instead.
         """
-    def __setitem__(self, key, value):
+    def Replace_uint64_contents(self, val):
         """
-        Set self[key] to value.
+        Deprecated: use `replace_uint64_contents` instead.
         """
-    def append(self, item):
+    def Replace_uint_contents(self, val):
         """
-        push item
+        Deprecated: use `replace_uint_contents` instead.
         """
-    def clear(self):
+    def Set_bool_contents(self, list):
+        ...
+    def Set_fp32_contents(self, list):
+        ...
+    def Set_fp64_contents(self, list):
+        ...
+    def Set_int64_contents(self, list):
+        ...
+    def Set_int_contents(self, list):
+        ...
+    def Set_uint64_contents(self, list):
+        ...
+    def Set_uint_contents(self, list):
+        ...
+    def Take_bool_contents(self):
         """
-        clear
+        Deprecated: use `take_bool_contents` instead.
         """
-    def copy(self):
+    def Take_fp32_contents(self):
         """
-        copy
+        Deprecated: use `take_fp32_contents` instead.
         """
-    def insert(self, index, value):
+    def Take_fp64_contents(self):
         """
-        insert item
+        Deprecated: use `take_fp64_contents` instead.
         """
-    def into_array(self):
+    def Take_int64_contents(self):
         """
-        move to numpy array
+        Deprecated: use `take_int64_contents` instead.
         """
-    def remove(self, index):
+    def Take_int_contents(self):
         """
-        remove item
+        Deprecated: use `take_int_contents` instead.
         """
-    def to_array(self):
+    def Take_uint64_contents(self):
         """
-        to numpy array
+        Deprecated: use `take_uint64_contents` instead.
         """
-    def to_list(self):
+    def Take_uint_contents(self):
         """
-        to Python list
+        Deprecated: use `take_uint_contents` instead.
         """
-class ListU16:
+    def __copy__(self):
+        ...
+    def __deepcopy__(self, _memo):
+        ...
+    def element_count(self):
+        """
+        Total number of elements across all content fields.
+
+        At most one field should be populated in a well-formed request.
+        """
+    def get_bool_contents(self):
+        ...
+    def get_fp32_contents(self):
+        ...
+    def get_fp64_contents(self):
+        ...
+    def get_int64_contents(self):
+        ...
+    def get_int_contents(self):
+        ...
+    def get_uint64_contents(self):
+        ...
+    def get_uint_contents(self):
+        ...
+    def is_empty(self):
+        """
+        Whether every content field is empty.
+        """
+    def is_empty_bool_contents(self):
+        ...
+    def is_empty_fp32_contents(self):
+        ...
+    def is_empty_fp64_contents(self):
+        ...
+    def is_empty_int64_contents(self):
+        ...
+    def is_empty_int_contents(self):
+        ...
+    def is_empty_uint64_contents(self):
+        ...
+    def is_empty_uint_contents(self):
+        ...
+    def len_bool_contents(self):
+        ...
+    def len_fp32_contents(self):
+        ...
+    def len_fp64_contents(self):
+        ...
+    def len_int64_contents(self):
+        ...
+    def len_int_contents(self):
+        ...
+    def len_uint64_contents(self):
+        ...
+    def len_uint_contents(self):
+        ...
+    def push_bool_contents(self, val):
+        ...
+    def push_fp32_contents(self, val):
+        ...
+    def push_fp64_contents(self, val):
+        ...
+    def push_int64_contents(self, val):
+        ...
+    def push_int_contents(self, val):
+        ...
+    def push_uint64_contents(self, val):
+        ...
+    def push_uint_contents(self, val):
+        ...
+    def replace_bool_contents(self, src=None):
+        ...
+    def replace_bytes_contents(self, src=None):
+        """
+        Accepts either a list of `bytes` or a 2-D `uint8` numpy array (one row per element).
+
+        Returns the old contents as a 2-D `uint8` array, or as a list of `bytes` when the
+        rows differ in length.
+        """
+    def replace_fp32_contents(self, src=None):
+        ...
+    def replace_fp64_contents(self, src=None):
+        ...
+    def replace_int64_contents(self, src=None):
+        ...
+    def replace_int_contents(self, src=None):
+        ...
+    def replace_uint64_contents(self, src=None):
+        ...
+    def replace_uint_contents(self, src=None):
+        ...
+    def take_bool_contents(self):
+        ...
+    def take_fp32_contents(self):
+        ...
+    def take_fp64_contents(self):
+        ...
+    def take_int64_contents(self):
+        ...
+    def take_int_contents(self):
+        ...
+    def take_uint64_contents(self):
+        ...
+    def take_uint_contents(self):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def total_byte_size(self):
+        """
+        Payload size in bytes of the contents as Triton serialises them, e.g. for admission
+        control before sending a request.
+
+        Each `bytes_contents` element counts its 4-byte length prefix.
+        """
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def bool_contents(self):
+        """
+        @@
+        @@  .. cpp:var:: bool bool_contents (repeated)
+        @@
+        @@     Representation for BOOL data type. The size must match what is
+        @@     expected by the tensor's shape. The contents must be the flattened,
+        @@     one-dimensional, row-major order of the tensor elements.
+        @@
+        """
+    @property
+    def bytes_contents(self):
+        """
+        @@
+        @@  .. cpp:var:: bytes bytes_contents (repeated)
+        @@
+        @@     Representation for BYTES data type. The size must match what is
+        @@     expected by the tensor's shape. The contents must be the flattened,
+        @@     one-dimensional, row-major order of the tensor elements.
+        @@
+        """
+    @property
+    def fp32_contents(self):
+        """
+        @@
+        @@  .. cpp:var:: float fp32_contents (repeated)
+        @@
+        @@     Representation for FP32 data type. The size must match what is
+        @@     expected by the tensor's shape. The contents must be the flattened,
+        @@     one-dimensional, row-major order of the tensor elements.
+        @@
+        """
+    @property
+    def fp64_contents(self):
+        """
+        @@
+        @@  .. cpp:var:: double fp64_contents (repeated)
+        @@
+        @@     Representation for FP64 data type. The size must match what is
+        @@     expected by the tensor's shape. The contents must be the flattened,
+        @@     one-dimensional, row-major order of the tensor elements.
+        @@
+        """
+    @property
+    def int64_contents(self):
+        """
+        @@
+        @@  .. cpp:var:: int64 int64_contents (repeated)
+        @@
+        @@     Representation for INT64 data types. The size must match what
+        @@     is expected by the tensor's shape. The contents must be the
+        @@     flattened, one-dimensional, row-major order of the tensor elements.
+        @@
+        """
+    @property
+    def int_contents(self):
+        """
+        @@
+        @@  .. cpp:var:: int32 int_contents (repeated)
+        @@
+        @@     Representation for INT8, INT16, and INT32 data types. The size
+        @@     must match what is expected by the tensor's shape. The contents
+        @@     must be the flattened, one-dimensional, row-major order of the
+        @@     tensor elements.
+        @@
+        """
+    @property
+    def uint64_contents(self):
+        """
+        @@
+        @@  .. cpp:var:: uint64 uint64_contents (repeated)
+        @@
+        @@     Representation for UINT64 data types. The size must match what
+        @@     is expected by the tensor's shape. The contents must be the
+        @@     flattened, one-dimensional, row-major order of the tensor elements.
+        @@
+        """
+    @property
+    def uint_contents(self):
+        """
+        @@
+        @@  .. cpp:var:: uint32 uint_contents (repeated)
+        @@
+        @@     Representation for UINT8, UINT16, and UINT32 data types. The size
+        @@     must match what is expected by the tensor's shape. The contents
+        @@     must be the flattened, one-dimensional, row-major order of the
+        @@     tensor elements.
+        @@
+        """
+class ListBool:
     """
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, obj):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
     @staticmethod
+    def concat(lists):
+        """
+        Concatenate lists into a new one, allocating once; `[]` gives an empty list.
+        """
+    @staticmethod
     def from_array(arr):
         """
         from numpy array construct
         """
+    @staticmethod
+    def from_scalar(value, length):
+        """
+        `length` copies of `value`, e.g. zeros or ones, without a Python list in between.
+        """
+    def __bool__(self):
+        """
+        True if self else False
+        """
     def __delitem__(self, key):
         """
         Delete self[key].
         """
+    def __format__(self, format_spec):
+        """
+        Format each item with `format_spec`, e.g. `f"{lst:.4f}"` gives `[1.0000, 2.5000]`.
+        """
     def __getitem__(self, key):
         """
         Return self[key].
@@ -762,34 +1788,68 @@ class ListU16:
         """
         Return len(self).
         """
+    def __reduce__(self):
+        """
+        Pickle support, also used by cloudpickle (Dask, Ray): rebuilt from a Python list.
+        """
     def __setitem__(self, key, value):
         """
         Set self[key] to value.
         """
+    def all(self):
+        ...
+    def any(self):
+        ...
     def append(self, item):
         """
         push item
         """
+    def capacity(self):
+        """
+        Number of items the list can hold without reallocating.
+        """
     def clear(self):
         """
-        clear
+        clear items
         """
     def copy(self):
         """
-        copy
+        deep copy
         """
+    def count_true(self):
+        ...
     def insert(self, index, value):
         """
         insert item
         """
     def into_array(self):
         """
-        move to numpy array
+        Deprecated alias of `move_to_array`; like it, this leaves the list empty.
+        """
+    def move_to_array(self):
+        """
+        Move the items into a numpy array without copying, leaving this list empty.
+
+        Use `to_array` to keep the list intact.
+        """
+    def pop(self, index=None):
+        """
+        Remove and return the item at `index`, the last one by default.
         """
     def remove(self, index):
         """
         remove item
         """
+    def reserve(self, additional):
+        """
+        Reserve room for at least `additional` more items, e.g. before an `append` loop.
+
+        Capacity is a hint: appending past it still works and reallocates.
+        """
+    def shrink_to_fit(self):
+        """
+        Release capacity beyond the current length.
+        """
     def to_array(self):
         """
         to numpy array
@@ -798,23 +1858,53 @@ class ListU16:
         """
         to Python list
         """
-class ListU32:
+class ListF32:
     """
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, obj):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
     @staticmethod
+    def concat(lists):
+        """
+        Concatenate lists into a new one, allocating once; `[]` gives an empty list.
+        """
+    @staticmethod
     def from_array(arr):
         """
         from numpy array construct
         """
+    @staticmethod
+    def from_numpy_2d(arr):
+        """
+        Copy a 2-D numpy array in row-major order, flattening it.
+        """
+    @staticmethod
+    def from_range(start, stop, step):
+        """
+        Elements `start, start + step, ...` up to but excluding `stop`, like `np.arange`.
+
+        Element `i` is computed as `start + i * step` rather than by accumulation.
+        """
+    @staticmethod
+    def from_scalar(value, length):
+        """
+        `length` copies of `value`, e.g. zeros or ones, without a Python list in between.
+        """
+    def __bool__(self):
+        """
+        True if self else False
+        """
     def __delitem__(self, key):
         """
         Delete self[key].
         """
+    def __format__(self, format_spec):
+        """
+        Format each item with `format_spec`, e.g. `f"{lst:.4f}"` gives `[1.0000, 2.5000]`.
+        """
     def __getitem__(self, key):
         """
         Return self[key].
@@ -823,6 +1913,18 @@ class ListU32:
         """
         Return len(self).
         """
+    def __mul__(self, value):
+        """
+        Return self*value.
+        """
+    def __reduce__(self):
+        """
+        Pickle support, also used by cloudpickle (Dask, Ray): rebuilt from a Python list.
+        """
+    def __rmul__(self, value):
+        """
+        Return value*self.
+        """
     def __setitem__(self, key, value):
         """
         Set self[key] to value.
@@ -831,13 +1933,33 @@ class ListU32:
         """
         push item
         """
-    def clear(self):
+    def capacity(self):
         """
-        clear
+        Number of items the list can hold without reallocating.
+        """
+    def clear(self):
+        """
+        clear items
+        """
+    def clip(self, min, max):
+        """
+        Clamp every element to `[min, max]` in place.
+        """
+    def clip_new(self, min, max):
+        """
+        Clipped copy; the list itself is unchanged.
         """
     def copy(self):
         """
-        copy
+        deep copy
+        """
+    def cosine_similarity(self, other):
+        """
+        Cosine similarity; raises `ValueError` if the lengths differ or either norm is zero.
+        """
+    def dot(self, other):
+        """
+        Dot product; raises `ValueError` if the lengths differ.
         """
     def insert(self, index, value):
         """
@@ -845,12 +1967,50 @@ class ListU32:
         """
     def into_array(self):
         """
-        move to numpy array
+        Deprecated alias of `move_to_array`; like it, this leaves the list empty.
+        """
+    def l2_norm(self):
+        """
+        Euclidean norm.
+        """
+    def move_to_array(self):
+        """
+        Move the items into a numpy array without copying, leaving this list empty.
+
+        Use `to_array` to keep the list intact.
+        """
+    def normalize(self):
+        """
+        Divide every element by the L2 norm in place; raises `ValueError` if it is zero.
+        """
+    def pop(self, index=None):
+        """
+        Remove and return the item at `index`, the last one by default.
+        """
+    def quantize_int8(self, scale, zero_point=0):
+        """
+        Quantize to INT8 as `round(x / scale + zero_point)`, clamped to the `int8` range.
         """
     def remove(self, index):
         """
         remove item
         """
+    def reserve(self, additional):
+        """
+        Reserve room for at least `additional` more items, e.g. before an `append` loop.
+
+        Capacity is a hint: appending past it still works and reallocates.
+        """
+    def shrink_to_fit(self):
+        """
+        Release capacity beyond the current length.
+        """
+    def sort(self, reverse=False, key=None):
+        """
+        Stable in-place sort, like `list.sort`.
+
+        `key` is one of `None`, `"abs"` or `"neg"`; arbitrary callables are not supported.
+        """
     def to_array(self):
         """
         to numpy array
@@ -859,23 +2019,54 @@ class ListU32:
         """
         to Python list
         """
-class ListU64:
+    def to_numpy_2d(self, rows):
+        """
+        Copy into a 2-D numpy array with `rows` rows; the inverse of `from_numpy_2d`.
+
+        Raises `ValueError` if the length is not a multiple of `rows`.
+        """
+class ListF64:
     """
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, obj):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
     @staticmethod
+    def concat(lists):
+        """
+        Concatenate lists into a new one, allocating once; `[]` gives an empty list.
+        """
+    @staticmethod
     def from_array(arr):
         """
         from numpy array construct
         """
+    @staticmethod
+    def from_range(start, stop, step):
+        """
+        Elements `start, start + step, ...` up to but excluding `stop`, like `np.arange`.
+
+        Element `i` is computed as `start + i * step` rather than by accumulation.
+        """
+    @staticmethod
+    def from_scalar(value, length):
+        """
+        `length` copies of `value`, e.g. zeros or ones, without a Python list in between.
+        """
+    def __bool__(self):
+        """
+        True if self else False
+        """
     def __delitem__(self, key):
         """
         Delete self[key].
         """
+    def __format__(self, format_spec):
+        """
+        Format each item with `format_spec`, e.g. `f"{lst:.4f}"` gives `[1.0000, 2.5000]`.
+        """
     def __getitem__(self, key):
         """
         Return self[key].
@@ -884,6 +2075,18 @@ class ListU64:
         """
         Return len(self).
         """
+    def __mul__(self, value):
+        """
+        Return self*value.
+        """
+    def __reduce__(self):
+        """
+        Pickle support, also used by cloudpickle (Dask, Ray): rebuilt from a Python list.
+        """
+    def __rmul__(self, value):
+        """
+        Return value*self.
+        """
     def __setitem__(self, key, value):
         """
         Set self[key] to value.
@@ -892,13 +2095,25 @@ class ListU64:
         """
         push item
         """
+    def capacity(self):
+        """
+        Number of items the list can hold without reallocating.
+        """
     def clear(self):
         """
-        clear
+        clear items
+        """
+    def clip(self, min, max):
+        """
+        Clamp every element to `[min, max]` in place.
+        """
+    def clip_new(self, min, max):
+        """
+        Clipped copy; the list itself is unchanged.
         """
     def copy(self):
         """
-        copy
+        deep copy
         """
     def insert(self, index, value):
         """
@@ -906,12 +2121,47 @@ class ListU64:
         """
     def into_array(self):
         """
-        move to numpy array
+        Deprecated alias of `move_to_array`; like it, this leaves the list empty.
+        """
+    def move_to_array(self):
+        """
+        Move the items into a numpy array without copying, leaving this list empty.
+
+        Use `to_array` to keep the list intact.
+        """
+    def normalize(self):
+        """
+        Divide every element by the L2 norm in place; raises `ValueError` if it is zero.
+        """
+    def pop(self, index=None):
+        """
+        Remove and return the item at `index`, the last one by default.
+        """
+    def quantize_int16(self, scale, zero_point=0):
+        """
+        Quantize to INT16 as `round(x / scale + zero_point)`, clamped to the `int16` range;
+        symmetric with the default `zero_point=0`.
         """
     def remove(self, index):
         """
         remove item
         """
+    def reserve(self, additional):
+        """
+        Reserve room for at least `additional` more items, e.g. before an `append` loop.
+
+        Capacity is a hint: appending past it still works and reallocates.
+        """
+    def shrink_to_fit(self):
+        """
+        Release capacity beyond the current length.
+        """
+    def sort(self, reverse=False, key=None):
+        """
+        Stable in-place sort, like `list.sort`.
+
+        `key` is one of `None`, `"abs"` or `"neg"`; arbitrary callables are not supported.
+        """
     def to_array(self):
         """
         to numpy array
@@ -920,23 +2170,46 @@ class ListU64:
         """
         to Python list
         """
-class ListU8:
+class ListI16:
     """
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, obj):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
     @staticmethod
+    def concat(lists):
+        """
+        Concatenate lists into a new one, allocating once; `[]` gives an empty list.
+        """
+    @staticmethod
     def from_array(arr):
         """
         from numpy array construct
         """
+    @staticmethod
+    def from_range(start, stop, step):
+        """
+        Elements `start, start + step, ...` up to but excluding `stop`, like `np.arange`.
+        """
+    @staticmethod
+    def from_scalar(value, length):
+        """
+        `length` copies of `value`, e.g. zeros or ones, without a Python list in between.
+        """
+    def __bool__(self):
+        """
+        True if self else False
+        """
     def __delitem__(self, key):
         """
         Delete self[key].
         """
+    def __format__(self, format_spec):
+        """
+        Format each item with `format_spec`, e.g. `f"{lst:.4f}"` gives `[1.0000, 2.5000]`.
+        """
     def __getitem__(self, key):
         """
         Return self[key].
@@ -945,6 +2218,18 @@ class ListU8:
         """
         Return len(self).
         """
+    def __mul__(self, value):
+        """
+        Return self*value.
+        """
+    def __reduce__(self):
+        """
+        Pickle support, also used by cloudpickle (Dask, Ray): rebuilt from a Python list.
+        """
+    def __rmul__(self, value):
+        """
+        Return value*self.
+        """
     def __setitem__(self, key, value):
         """
         Set self[key] to value.
@@ -953,13 +2238,29 @@ class ListU8:
         """
         push item
         """
+    def capacity(self):
+        """
+        Number of items the list can hold without reallocating.
+        """
     def clear(self):
         """
-        clear
+        clear items
+        """
+    def clip(self, min, max):
+        """
+        Clamp every element to `[min, max]` in place.
+        """
+    def clip_new(self, min, max):
+        """
+        Clipped copy; the list itself is unchanged.
         """
     def copy(self):
         """
-        copy
+        deep copy
+        """
+    def dequantize_fp64(self, scale, zero_point=0):
+        """
+        Inverse of `ListF64.quantize_int16`: `(q - zero_point) * scale`.
         """
     def insert(self, index, value):
         """
@@ -967,12 +2268,38 @@ class ListU8:
         """
     def into_array(self):
         """
-        move to numpy array
+        Deprecated alias of `move_to_array`; like it, this leaves the list empty.
+        """
+    def move_to_array(self):
+        """
+        Move the items into a numpy array without copying, leaving this list empty.
+
+        Use `to_array` to keep the list intact.
+        """
+    def pop(self, index=None):
+        """
+        Remove and return the item at `index`, the last one by default.
         """
     def remove(self, index):
         """
         remove item
         """
+    def reserve(self, additional):
+        """
+        Reserve room for at least `additional` more items, e.g. before an `append` loop.
+
+        Capacity is a hint: appending past it still works and reallocates.
+        """
+    def shrink_to_fit(self):
+        """
+        Release capacity beyond the current length.
+        """
+    def sort(self, reverse=False, key=None):
+        """
+        Stable in-place sort, like `list.sort`.
+
+        `key` is one of `None`, `"abs"` or `"neg"`; arbitrary callables are not supported.
+        """
     def to_array(self):
         """
         to numpy array
@@ -981,459 +2308,3393 @@ class ListU8:
         """
         to Python list
         """
-class ModelConfig:
+class ListI32:
     """
-    @@
-    @@.. cpp:var:: message ModelConfig
-    @@
-    @@   A model configuration.
-    @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, obj):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class ModelConfigRequest:
-    """
-    @@
-    @@.. cpp:var:: message ModelConfigRequest
-    @@
-    @@   Request message for ModelConfig.
-    @@
-    """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def concat(lists):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        Concatenate lists into a new one, allocating once; `[]` gives an empty list.
         """
-class ModelConfigResponse:
-    """
-    @@
-    @@.. cpp:var:: message ModelConfigResponse
-    @@
-    @@   Response message for ModelConfig.
-    @@
-    """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def from_array(arr):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        from numpy array construct
         """
-class ModelIndex:
-    """
-    @@
-    @@  .. cpp:var:: message ModelIndex
-    @@
-    @@     Index entry for a model.
-    @@
-    """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def from_range(start, stop, step):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        Elements `start, start + step, ...` up to but excluding `stop`, like `np.arange`.
         """
-class ModelInferRequest:
-    """
-    @@
-    @@.. cpp:var:: message ModelInferRequest
-    @@
-    @@   Request message for ModelInfer.
-    @@
-    """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def from_scalar(value, length):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        `length` copies of `value`, e.g. zeros or ones, without a Python list in between.
         """
-class ModelInferResponse:
-    """
-    @@
-    @@.. cpp:var:: message ModelInferResponse
-    @@
-    @@   Response message for ModelInfer.
-    @@
-    """
-    @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __bool__(self):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        True if self else False
         """
-class ModelMetadataRequest:
-    """
-    @@
-    @@.. cpp:var:: message ModelMetadataRequest
-    @@
-    @@   Request message for ModelMetadata.
-    @@
-    """
-    @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __delitem__(self, key):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        Delete self[key].
         """
-class ModelMetadataResponse:
-    """
-    @@
-    @@.. cpp:var:: message ModelMetadataResponse
-    @@
-    @@   Response message for ModelMetadata.
-    @@
-    """
-    @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __format__(self, format_spec):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        Format each item with `format_spec`, e.g. `f"{lst:.4f}"` gives `[1.0000, 2.5000]`.
         """
-class ModelReadyRequest:
-    """
-    @@
-    @@.. cpp:var:: message ModelReadyRequest
-    @@
-    @@   Request message for ModelReady.
-    @@
-    """
-    @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __getitem__(self, key):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        Return self[key].
         """
-class ModelReadyResponse:
-    """
-    @@
-    @@.. cpp:var:: message ModelReadyResponse
-    @@
-    @@   Response message for ModelReady.
-    @@
-    """
-    @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __len__(self):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        Return len(self).
         """
-class ModelRepositoryParameter:
-    """
-    @@
-    @@.. cpp:var:: message ModelRepositoryParameter
-    @@
-    @@   An model repository parameter value.
-    @@
-    """
-    @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __mul__(self, value):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        Return self*value.
         """
-class ModelStatisticsRequest:
-    """
-    @@
-    @@.. cpp:var:: message ModelStatisticsRequest
-    @@
-    @@   Request message for ModelStatistics.
-    @@
-    """
-    @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __reduce__(self):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        Pickle support, also used by cloudpickle (Dask, Ray): rebuilt from a Python list.
         """
-class ModelStatisticsResponse:
-    """
-    @@
-    @@.. cpp:var:: message ModelStatisticsResponse
-    @@
-    @@   Response message for ModelStatistics.
-    @@
-    """
-    @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __rmul__(self, value):
         """
-        Create and return a new object.  See help(type) for accurate signature.
+        Return value*self.
         """
-class ParameterChoice:
+    def __setitem__(self, key, value):
+        """
+        Set self[key] to value.
+        """
+    def append(self, item):
+        """
+        push item
+        """
+    def argmax(self):
+        """
+        Index of the first maximum; raises `ValueError` if empty.
+        """
+    def capacity(self):
+        """
+        Number of items the list can hold without reallocating.
+        """
+    def clear(self):
+        """
+        clear items
+        """
+    def clip(self, min, max):
+        """
+        Clamp every element to `[min, max]` in place.
+        """
+    def clip_new(self, min, max):
+        """
+        Clipped copy; the list itself is unchanged.
+        """
+    def copy(self):
+        """
+        deep copy
+        """
+    def insert(self, index, value):
+        """
+        insert item
+        """
+    def into_array(self):
+        """
+        Deprecated alias of `move_to_array`; like it, this leaves the list empty.
+        """
+    def move_to_array(self):
+        """
+        Move the items into a numpy array without copying, leaving this list empty.
+
+        Use `to_array` to keep the list intact.
+        """
+    def pop(self, index=None):
+        """
+        Remove and return the item at `index`, the last one by default.
+        """
+    def remove(self, index):
+        """
+        remove item
+        """
+    def reserve(self, additional):
+        """
+        Reserve room for at least `additional` more items, e.g. before an `append` loop.
+
+        Capacity is a hint: appending past it still works and reallocates.
+        """
+    def shrink_to_fit(self):
+        """
+        Release capacity beyond the current length.
+        """
+    def sort(self, reverse=False, key=None):
+        """
+        Stable in-place sort, like `list.sort`.
+
+        `key` is one of `None`, `"abs"` or `"neg"`; arbitrary callables are not supported.
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_frozenset(self):
+        """
+        Unique values as a Python `frozenset`.
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+    def to_set(self):
+        """
+        Unique values as a Python `set`, e.g. the predicted classes.
+        """
+    def unique(self):
+        """
+        Unique values in order of first appearance.
+        """
+class ListI64:
+    """
+    """
+    @staticmethod
+    def __new__(cls, obj):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def concat(lists):
+        """
+        Concatenate lists into a new one, allocating once; `[]` gives an empty list.
+        """
+    @staticmethod
+    def from_array(arr):
+        """
+        from numpy array construct
+        """
+    @staticmethod
+    def from_range(start, stop, step):
+        """
+        Elements `start, start + step, ...` up to but excluding `stop`, like `np.arange`.
+        """
+    @staticmethod
+    def from_scalar(value, length):
+        """
+        `length` copies of `value`, e.g. zeros or ones, without a Python list in between.
+        """
+    def __bool__(self):
+        """
+        True if self else False
+        """
+    def __delitem__(self, key):
+        """
+        Delete self[key].
+        """
+    def __format__(self, format_spec):
+        """
+        Format each item with `format_spec`, e.g. `f"{lst:.4f}"` gives `[1.0000, 2.5000]`.
+        """
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def __mul__(self, value):
+        """
+        Return self*value.
+        """
+    def __reduce__(self):
+        """
+        Pickle support, also used by cloudpickle (Dask, Ray): rebuilt from a Python list.
+        """
+    def __rmul__(self, value):
+        """
+        Return value*self.
+        """
+    def __setitem__(self, key, value):
+        """
+        Set self[key] to value.
+        """
+    def append(self, item):
+        """
+        push item
+        """
+    def capacity(self):
+        """
+        Number of items the list can hold without reallocating.
+        """
+    def clear(self):
+        """
+        clear items
+        """
+    def clip(self, min, max):
+        """
+        Clamp every element to `[min, max]` in place.
+        """
+    def clip_new(self, min, max):
+        """
+        Clipped copy; the list itself is unchanged.
+        """
+    def copy(self):
+        """
+        deep copy
+        """
+    def insert(self, index, value):
+        """
+        insert item
+        """
+    def into_array(self):
+        """
+        Deprecated alias of `move_to_array`; like it, this leaves the list empty.
+        """
+    def move_to_array(self):
+        """
+        Move the items into a numpy array without copying, leaving this list empty.
+
+        Use `to_array` to keep the list intact.
+        """
+    def pop(self, index=None):
+        """
+        Remove and return the item at `index`, the last one by default.
+        """
+    def remove(self, index):
+        """
+        remove item
+        """
+    def reserve(self, additional):
+        """
+        Reserve room for at least `additional` more items, e.g. before an `append` loop.
+
+        Capacity is a hint: appending past it still works and reallocates.
+        """
+    def shrink_to_fit(self):
+        """
+        Release capacity beyond the current length.
+        """
+    def sort(self, reverse=False, key=None):
+        """
+        Stable in-place sort, like `list.sort`.
+
+        `key` is one of `None`, `"abs"` or `"neg"`; arbitrary callables are not supported.
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_frozenset(self):
+        """
+        Unique values as a Python `frozenset`.
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+    def to_set(self):
+        """
+        Unique values as a Python `set`, e.g. the predicted classes.
+        """
+class ListI8:
+    """
+    """
+    @staticmethod
+    def __new__(cls, obj):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def concat(lists):
+        """
+        Concatenate lists into a new one, allocating once; `[]` gives an empty list.
+        """
+    @staticmethod
+    def from_array(arr):
+        """
+        from numpy array construct
+        """
+    @staticmethod
+    def from_range(start, stop, step):
+        """
+        Elements `start, start + step, ...` up to but excluding `stop`, like `np.arange`.
+        """
+    @staticmethod
+    def from_scalar(value, length):
+        """
+        `length` copies of `value`, e.g. zeros or ones, without a Python list in between.
+        """
+    def __bool__(self):
+        """
+        True if self else False
+        """
+    def __delitem__(self, key):
+        """
+        Delete self[key].
+        """
+    def __format__(self, format_spec):
+        """
+        Format each item with `format_spec`, e.g. `f"{lst:.4f}"` gives `[1.0000, 2.5000]`.
+        """
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def __mul__(self, value):
+        """
+        Return self*value.
+        """
+    def __reduce__(self):
+        """
+        Pickle support, also used by cloudpickle (Dask, Ray): rebuilt from a Python list.
+        """
+    def __rmul__(self, value):
+        """
+        Return value*self.
+        """
+    def __setitem__(self, key, value):
+        """
+        Set self[key] to value.
+        """
+    def append(self, item):
+        """
+        push item
+        """
+    def capacity(self):
+        """
+        Number of items the list can hold without reallocating.
+        """
+    def clear(self):
+        """
+        clear items
+        """
+    def clip(self, min, max):
+        """
+        Clamp every element to `[min, max]` in place.
+        """
+    def clip_new(self, min, max):
+        """
+        Clipped copy; the list itself is unchanged.
+        """
+    def copy(self):
+        """
+        deep copy
+        """
+    def dequantize_fp32(self, scale, zero_point=0):
+        """
+        Inverse of `ListF32.quantize_int8`: `(q - zero_point) * scale`.
+        """
+    def insert(self, index, value):
+        """
+        insert item
+        """
+    def into_array(self):
+        """
+        Deprecated alias of `move_to_array`; like it, this leaves the list empty.
+        """
+    def move_to_array(self):
+        """
+        Move the items into a numpy array without copying, leaving this list empty.
+
+        Use `to_array` to keep the list intact.
+        """
+    def pop(self, index=None):
+        """
+        Remove and return the item at `index`, the last one by default.
+        """
+    def remove(self, index):
+        """
+        remove item
+        """
+    def reserve(self, additional):
+        """
+        Reserve room for at least `additional` more items, e.g. before an `append` loop.
+
+        Capacity is a hint: appending past it still works and reallocates.
+        """
+    def shrink_to_fit(self):
+        """
+        Release capacity beyond the current length.
+        """
+    def sort(self, reverse=False, key=None):
+        """
+        Stable in-place sort, like `list.sort`.
+
+        `key` is one of `None`, `"abs"` or `"neg"`; arbitrary callables are not supported.
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+class ListU16:
+    """
+    """
+    @staticmethod
+    def __new__(cls, obj):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def concat(lists):
+        """
+        Concatenate lists into a new one, allocating once; `[]` gives an empty list.
+        """
+    @staticmethod
+    def from_array(arr):
+        """
+        from numpy array construct
+        """
+    @staticmethod
+    def from_range(start, stop, step):
+        """
+        Elements `start, start + step, ...` up to but excluding `stop`, like `np.arange`.
+        """
+    @staticmethod
+    def from_scalar(value, length):
+        """
+        `length` copies of `value`, e.g. zeros or ones, without a Python list in between.
+        """
+    def __bool__(self):
+        """
+        True if self else False
+        """
+    def __delitem__(self, key):
+        """
+        Delete self[key].
+        """
+    def __format__(self, format_spec):
+        """
+        Format each item with `format_spec`, e.g. `f"{lst:.4f}"` gives `[1.0000, 2.5000]`.
+        """
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def __mul__(self, value):
+        """
+        Return self*value.
+        """
+    def __reduce__(self):
+        """
+        Pickle support, also used by cloudpickle (Dask, Ray): rebuilt from a Python list.
+        """
+    def __rmul__(self, value):
+        """
+        Return value*self.
+        """
+    def __setitem__(self, key, value):
+        """
+        Set self[key] to value.
+        """
+    def append(self, item):
+        """
+        push item
+        """
+    def capacity(self):
+        """
+        Number of items the list can hold without reallocating.
+        """
+    def clear(self):
+        """
+        clear items
+        """
+    def clip(self, min, max):
+        """
+        Clamp every element to `[min, max]` in place.
+        """
+    def clip_new(self, min, max):
+        """
+        Clipped copy; the list itself is unchanged.
+        """
+    def copy(self):
+        """
+        deep copy
+        """
+    def insert(self, index, value):
+        """
+        insert item
+        """
+    def into_array(self):
+        """
+        Deprecated alias of `move_to_array`; like it, this leaves the list empty.
+        """
+    def move_to_array(self):
+        """
+        Move the items into a numpy array without copying, leaving this list empty.
+
+        Use `to_array` to keep the list intact.
+        """
+    def pop(self, index=None):
+        """
+        Remove and return the item at `index`, the last one by default.
+        """
+    def remove(self, index):
+        """
+        remove item
+        """
+    def reserve(self, additional):
+        """
+        Reserve room for at least `additional` more items, e.g. before an `append` loop.
+
+        Capacity is a hint: appending past it still works and reallocates.
+        """
+    def shrink_to_fit(self):
+        """
+        Release capacity beyond the current length.
+        """
+    def sort(self, reverse=False, key=None):
+        """
+        Stable in-place sort, like `list.sort`.
+
+        `key` is one of `None`, `"abs"` or `"neg"`; arbitrary callables are not supported.
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+class ListU32:
+    """
+    """
+    @staticmethod
+    def __new__(cls, obj):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def concat(lists):
+        """
+        Concatenate lists into a new one, allocating once; `[]` gives an empty list.
+        """
+    @staticmethod
+    def from_array(arr):
+        """
+        from numpy array construct
+        """
+    @staticmethod
+    def from_range(start, stop, step):
+        """
+        Elements `start, start + step, ...` up to but excluding `stop`, like `np.arange`.
+        """
+    @staticmethod
+    def from_scalar(value, length):
+        """
+        `length` copies of `value`, e.g. zeros or ones, without a Python list in between.
+        """
+    def __bool__(self):
+        """
+        True if self else False
+        """
+    def __delitem__(self, key):
+        """
+        Delete self[key].
+        """
+    def __format__(self, format_spec):
+        """
+        Format each item with `format_spec`, e.g. `f"{lst:.4f}"` gives `[1.0000, 2.5000]`.
+        """
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def __mul__(self, value):
+        """
+        Return self*value.
+        """
+    def __reduce__(self):
+        """
+        Pickle support, also used by cloudpickle (Dask, Ray): rebuilt from a Python list.
+        """
+    def __rmul__(self, value):
+        """
+        Return value*self.
+        """
+    def __setitem__(self, key, value):
+        """
+        Set self[key] to value.
+        """
+    def append(self, item):
+        """
+        push item
+        """
+    def capacity(self):
+        """
+        Number of items the list can hold without reallocating.
+        """
+    def clear(self):
+        """
+        clear items
+        """
+    def clip(self, min, max):
+        """
+        Clamp every element to `[min, max]` in place.
+        """
+    def clip_new(self, min, max):
+        """
+        Clipped copy; the list itself is unchanged.
+        """
+    def copy(self):
+        """
+        deep copy
+        """
+    def insert(self, index, value):
+        """
+        insert item
+        """
+    def into_array(self):
+        """
+        Deprecated alias of `move_to_array`; like it, this leaves the list empty.
+        """
+    def move_to_array(self):
+        """
+        Move the items into a numpy array without copying, leaving this list empty.
+
+        Use `to_array` to keep the list intact.
+        """
+    def pop(self, index=None):
+        """
+        Remove and return the item at `index`, the last one by default.
+        """
+    def remove(self, index):
+        """
+        remove item
+        """
+    def reserve(self, additional):
+        """
+        Reserve room for at least `additional` more items, e.g. before an `append` loop.
+
+        Capacity is a hint: appending past it still works and reallocates.
+        """
+    def shrink_to_fit(self):
+        """
+        Release capacity beyond the current length.
+        """
+    def sort(self, reverse=False, key=None):
+        """
+        Stable in-place sort, like `list.sort`.
+
+        `key` is one of `None`, `"abs"` or `"neg"`; arbitrary callables are not supported.
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_frozenset(self):
+        """
+        Unique values as a Python `frozenset`.
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+    def to_set(self):
+        """
+        Unique values as a Python `set`, e.g. the predicted classes.
+        """
+class ListU64:
+    """
+    """
+    @staticmethod
+    def __new__(cls, obj):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def concat(lists):
+        """
+        Concatenate lists into a new one, allocating once; `[]` gives an empty list.
+        """
+    @staticmethod
+    def from_array(arr):
+        """
+        from numpy array construct
+        """
+    @staticmethod
+    def from_range(start, stop, step):
+        """
+        Elements `start, start + step, ...` up to but excluding `stop`, like `np.arange`.
+        """
+    @staticmethod
+    def from_scalar(value, length):
+        """
+        `length` copies of `value`, e.g. zeros or ones, without a Python list in between.
+        """
+    def __bool__(self):
+        """
+        True if self else False
+        """
+    def __delitem__(self, key):
+        """
+        Delete self[key].
+        """
+    def __format__(self, format_spec):
+        """
+        Format each item with `format_spec`, e.g. `f"{lst:.4f}"` gives `[1.0000, 2.5000]`.
+        """
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def __mul__(self, value):
+        """
+        Return self*value.
+        """
+    def __reduce__(self):
+        """
+        Pickle support, also used by cloudpickle (Dask, Ray): rebuilt from a Python list.
+        """
+    def __rmul__(self, value):
+        """
+        Return value*self.
+        """
+    def __setitem__(self, key, value):
+        """
+        Set self[key] to value.
+        """
+    def append(self, item):
+        """
+        push item
+        """
+    def capacity(self):
+        """
+        Number of items the list can hold without reallocating.
+        """
+    def clear(self):
+        """
+        clear items
+        """
+    def clip(self, min, max):
+        """
+        Clamp every element to `[min, max]` in place.
+        """
+    def clip_new(self, min, max):
+        """
+        Clipped copy; the list itself is unchanged.
+        """
+    def copy(self):
+        """
+        deep copy
+        """
+    def insert(self, index, value):
+        """
+        insert item
+        """
+    def into_array(self):
+        """
+        Deprecated alias of `move_to_array`; like it, this leaves the list empty.
+        """
+    def move_to_array(self):
+        """
+        Move the items into a numpy array without copying, leaving this list empty.
+
+        Use `to_array` to keep the list intact.
+        """
+    def pop(self, index=None):
+        """
+        Remove and return the item at `index`, the last one by default.
+        """
+    def remove(self, index):
+        """
+        remove item
+        """
+    def reserve(self, additional):
+        """
+        Reserve room for at least `additional` more items, e.g. before an `append` loop.
+
+        Capacity is a hint: appending past it still works and reallocates.
+        """
+    def shrink_to_fit(self):
+        """
+        Release capacity beyond the current length.
+        """
+    def sort(self, reverse=False, key=None):
+        """
+        Stable in-place sort, like `list.sort`.
+
+        `key` is one of `None`, `"abs"` or `"neg"`; arbitrary callables are not supported.
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_frozenset(self):
+        """
+        Unique values as a Python `frozenset`.
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+    def to_set(self):
+        """
+        Unique values as a Python `set`, e.g. the predicted classes.
+        """
+class ListU8:
+    """
+    """
+    @staticmethod
+    def __new__(cls, obj):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def concat(lists):
+        """
+        Concatenate lists into a new one, allocating once; `[]` gives an empty list.
+        """
+    @staticmethod
+    def from_array(arr):
+        """
+        from numpy array construct
+        """
+    @staticmethod
+    def from_base64(s):
+        ...
+    @staticmethod
+    def from_hex(s):
+        ...
+    @staticmethod
+    def from_range(start, stop, step):
+        """
+        Elements `start, start + step, ...` up to but excluding `stop`, like `np.arange`.
+        """
+    @staticmethod
+    def from_scalar(value, length):
+        """
+        `length` copies of `value`, e.g. zeros or ones, without a Python list in between.
+        """
+    def __bool__(self):
+        """
+        True if self else False
+        """
+    def __delitem__(self, key):
+        """
+        Delete self[key].
+        """
+    def __format__(self, format_spec):
+        """
+        Format each item with `format_spec`, e.g. `f"{lst:.4f}"` gives `[1.0000, 2.5000]`.
+        """
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def __mul__(self, value):
+        """
+        Return self*value.
+        """
+    def __reduce__(self):
+        """
+        Pickle support, also used by cloudpickle (Dask, Ray): rebuilt from a Python list.
+        """
+    def __rmul__(self, value):
+        """
+        Return value*self.
+        """
+    def __setitem__(self, key, value):
+        """
+        Set self[key] to value.
+        """
+    def append(self, item):
+        """
+        push item
+        """
+    def capacity(self):
+        """
+        Number of items the list can hold without reallocating.
+        """
+    def clear(self):
+        """
+        clear items
+        """
+    def clip(self, min, max):
+        """
+        Clamp every element to `[min, max]` in place.
+        """
+    def clip_new(self, min, max):
+        """
+        Clipped copy; the list itself is unchanged.
+        """
+    def copy(self):
+        """
+        deep copy
+        """
+    def insert(self, index, value):
+        """
+        insert item
+        """
+    def into_array(self):
+        """
+        Deprecated alias of `move_to_array`; like it, this leaves the list empty.
+        """
+    def move_to_array(self):
+        """
+        Move the items into a numpy array without copying, leaving this list empty.
+
+        Use `to_array` to keep the list intact.
+        """
+    def pop(self, index=None):
+        """
+        Remove and return the item at `index`, the last one by default.
+        """
+    def remove(self, index):
+        """
+        remove item
+        """
+    def reserve(self, additional):
+        """
+        Reserve room for at least `additional` more items, e.g. before an `append` loop.
+
+        Capacity is a hint: appending past it still works and reallocates.
+        """
+    def shrink_to_fit(self):
+        """
+        Release capacity beyond the current length.
+        """
+    def sort(self, reverse=False, key=None):
+        """
+        Stable in-place sort, like `list.sort`.
+
+        `key` is one of `None`, `"abs"` or `"neg"`; arbitrary callables are not supported.
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_base64(self):
+        """
+        Standard base64 encoding, as used by Triton's HTTP/JSON protocol.
+        """
+    def to_hex(self):
+        """
+        Lowercase hex string, for debugging.
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+class LogHandle:
+    """
+    Handle to the module logger, for changing the log level at runtime.
+    """
+    @staticmethod
+    def __new__(type, *args, **kwargs):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    def get_level(self):
+        ...
+    def set_level(self, level):
+        """
+        Replace the log specification, e.g. `"DEBUG"` or `"info,triton_client=debug"`.
+
+        Release builds compile out `TRACE` records to keep them off hot paths, so there the most
+        verbose effective level is `DEBUG`; a `trace` specification logs a warning.
+        """
+class MemoryUsage:
+    """
+    @@
+    @@.. cpp:var:: message MemoryUsage
+    @@
+    @@   Memory usage.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, type, id, byte_size):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def byte_size(self):
+        """
+        @@  .. cpp:var:: uint64 byte_size
+        @@
+        @@     The byte size of the memory.
+        @@
+        """
+    @property
+    def id(self):
+        """
+        @@  .. cpp:var:: int64 id
+        @@
+        @@     The id of the memory, typically used with "type" to identify
+        @@     a device that hosts the memory.
+        @@
+        """
+    @property
+    def type(self):
+        """
+        @@  .. cpp:var:: string type
+        @@
+        @@     The type of memory, the value can be "CPU", "CPU_PINNED", "GPU".
+        @@
+        """
+class ModelConfig:
+    """
+    @@
+    @@.. cpp:var:: message ModelConfig
+    @@
+    @@   A model configuration.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, name, platform, backend, runtime, *, version_policy=None, max_batch_size, input, output, batch_input, batch_output, optimization=None, instance_group, default_model_filename, cc_model_filenames, metric_tags, parameters, model_warmup, model_operations=None, model_transaction_policy=None, model_repository_agents=None, response_cache=None, model_metrics=None, scheduling_choice=None):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_json(s):
+        """
+        Parse a model configuration previously produced by `to_json`.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def __copy__(self):
+        ...
+    def __deepcopy__(self, _memo):
+        ...
+    def to_json(self):
+        """
+        Serialise the model configuration to JSON.
+        """
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    def validate(self):
+        """
+        Check the configuration for common mistakes.
+
+        Returns a list of error messages; an empty list means the config is valid.
+        """
+    @property
+    def backend(self):
+        """
+        @@  .. cpp:var:: string backend
+        @@
+        @@     The backend used by the model.
+        @@
+        """
+    @property
+    def batch_input(self):
+        """
+        @@  .. cpp:var:: BatchInput batch_input (repeated)
+        @@
+        @@     The model input(s) that the server should use to communicate
+        @@     batch related values to the model.
+        @@
+        """
+    @property
+    def batch_output(self):
+        """
+        @@  .. cpp:var:: BatchOutput batch_output (repeated)
+        @@
+        @@     The outputs produced by the model that requires special handling
+        @@     by the model backend.
+        @@
+        """
+    @property
+    def cc_model_filenames(self):
+        """
+        @@  .. cpp:var:: map\<string,string> cc_model_filenames
+        @@
+        @@     Optional map from CUDA compute capability to the filename of
+        @@     the model that supports that compute capability. The filename
+        @@     refers to a file within the model version directory.
+        @@
+        """
+    @property
+    def default_model_filename(self):
+        """
+        @@  .. cpp:var:: string default_model_filename
+        @@
+        @@     Optional filename of the model file to use if a
+        @@     compute-capability specific model is not specified in
+        @@     :cpp:var:`cc_model_filenames`. If not specified the default name
+        @@     is 'model.graphdef', 'model.savedmodel', 'model.plan' or
+        @@     'model.pt' depending on the model type.
+        @@
+        """
+    @property
+    def input(self):
+        """
+        @@  .. cpp:var:: ModelInput input (repeated)
+        @@
+        @@     The inputs request by the model.
+        @@
+        """
+    @property
+    def instance_group(self):
+        """
+        @@  .. cpp:var:: ModelInstanceGroup instance_group (repeated)
+        @@
+        @@     Instances of this model. If not specified, one instance
+        @@     of the model will be instantiated on each available GPU.
+        @@
+        """
+    @property
+    def max_batch_size(self):
+        """
+        @@  .. cpp:var:: int32 max_batch_size
+        @@
+        @@     Maximum batch size allowed for inference. This can only decrease
+        @@     what is allowed by the model itself. A max_batch_size value of 0
+        @@     indicates that batching is not allowed for the model and the
+        @@     dimension/shape of the input and output tensors must exactly
+        @@     match what is specified in the input and output configuration. A
+        @@     max_batch_size value > 0 indicates that batching is allowed and
+        @@     so the model expects the input tensors to have an additional
+        @@     initial dimension for the batching that is not specified in the
+        @@     input (for example, if the model supports batched inputs of
+        @@     2-dimensional tensors then the model configuration will specify
+        @@     the input shape as \[ X, Y \] but the model will expect the actual
+        @@     input tensors to have shape \[ N, X, Y \]). For max_batch_size > 0
+        @@     returned outputs will also have an additional initial dimension
+        @@     for the batch.
+        @@
+        """
+    @property
+    def metric_tags(self):
+        """
+        @@  .. cpp:var:: map\<string,string> metric_tags
+        @@
+        @@     Optional metric tags. User-specific key-value pairs for metrics
+        @@     reported for this model. These tags are applied to the metrics
+        @@     reported on the HTTP metrics port.
+        @@
+        """
+    @property
+    def model_metrics(self):
+        """
+        @@  .. cpp:var:: ModelMetrics model_metrics
+        @@
+        @@     Optional setting for custom metrics configuration for this model.
+        @@     Application default is applied to metrics that are not specified.
+        @@
+        """
+    @property
+    def model_operations(self):
+        """
+        @@  .. cpp:var:: ModelOperations model_operations
+        @@
+        @@     Optional metadata of the libraries providing custom operations for
+        @@     this model.
+        @@
+        """
+    @property
+    def model_repository_agents(self):
+        """
+        @@  .. cpp:var:: ModelRepositoryAgents model_repository_agents
+        @@
+        @@     Optional specification of the agent(s) that should be invoked
+        @@     with repository actions are performed for this model.
+        @@
+        """
+    @property
+    def model_transaction_policy(self):
+        """
+        @@  .. cpp:var:: ModelTransactionPolicy model_transaction_policy
+        @@
+        @@     Optional specification that describes the nature of transactions
+        @@     to be expected from the model.
+        @@
+        """
+    @property
+    def model_warmup(self):
+        """
+        @@  .. cpp:var:: ModelWarmup model_warmup (repeated)
+        @@
+        @@     Warmup setting of this model. If specified, all instances
+        @@     will be run with the request samples in sequence before
+        @@     serving the model.
+        @@     This field can only be specified if the model is not an ensemble
+        @@     model.
+        @@
+        """
+    @property
+    def name(self):
+        """
+        @@  .. cpp:var:: string name
+        @@
+        @@     The name of the model.
+        @@
+        """
+    @property
+    def optimization(self):
+        """
+        @@  .. cpp:var:: ModelOptimizationPolicy optimization
+        @@
+        @@     Optimization configuration for the model. If not specified
+        @@     then default optimization policy is used.
+        @@
+        """
+    @property
+    def output(self):
+        """
+        @@  .. cpp:var:: ModelOutput output (repeated)
+        @@
+        @@     The outputs produced by the model.
+        @@
+        """
+    @property
+    def parameters(self):
+        """
+        @@  .. cpp:var:: map\<string,ModelParameter> parameters
+        @@
+        @@     Optional model parameters. User-specified parameter values.
+        @@
+        """
+    @property
+    def platform(self):
+        """
+        @@  .. cpp:var:: string platform
+        @@
+        @@     Additional backend-specific configuration for the model.
+        @@     Please refer to the backend documentation on whether this field
+        @@     should be specified.
+        @@
+        """
+    @property
+    def response_cache(self):
+        """
+        @@  .. cpp:var:: ModelResponseCache response_cache
+        @@
+        @@     Optional setting for utilizing the response cache for this
+        @@     model.
+        @@
+        """
+    @property
+    def runtime(self):
+        """
+        @@  .. cpp:var:: string runtime
+        @@
+        @@     The name of the backend library file used by the model.
+        @@
+        """
+    @property
+    def scheduling_choice(self):
+        """
+        @@  .. cpp:var:: oneof scheduling_choice
+        @@
+        @@     The scheduling policy for the model. If not specified the
+        @@     default scheduling policy is used for the model. The default
+        @@     policy is to execute each inference request independently.
+        @@
+        """
+    @property
+    def version_policy(self):
+        """
+        @@  .. cpp:var:: ModelVersionPolicy version_policy
+        @@
+        @@     Policy indicating which version(s) of the model will be served.
+        @@
+        """
+class ModelConfigRequest:
+    """
+    @@
+    @@.. cpp:var:: message ModelConfigRequest
+    @@
+    @@   Request message for ModelConfig.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, name, version):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@  .. cpp:var:: string name
+        @@
+        @@     The name of the model.
+        @@
+        """
+    @property
+    def version(self):
+        """
+        @@  .. cpp:var:: string version
+        @@
+        @@     The version of the model. If not given the model version
+        @@     is selected automatically based on the version policy.
+        @@
+        """
+class ModelConfigResponse:
+    """
+    @@
+    @@.. cpp:var:: message ModelConfigResponse
+    @@
+    @@   Response message for ModelConfig.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, config=None):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def config(self):
+        """
+        @@
+        @@  .. cpp:var:: ModelConfig config
+        @@
+        @@     The model configuration.
+        @@
+        """
+    @config.setter
+    def config(self, arg0):
+        ...
+class ModelIndex:
+    """
+    @@
+    @@  .. cpp:var:: message ModelIndex
+    @@
+    @@     Index entry for a model.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, name, version, state, reason):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@    .. cpp:var:: string name
+        @@
+        @@       The name of the model.
+        @@
+        """
+    @property
+    def reason(self):
+        """
+        @@
+        @@    .. cpp:var:: string reason
+        @@
+        @@       The reason, if any, that the model is in the given state.
+        @@
+        """
+    @property
+    def state(self):
+        """
+        @@
+        @@    .. cpp:var:: string state
+        @@
+        @@       The state of the model.
+        @@
+        """
+    @property
+    def version(self):
+        """
+        @@    .. cpp:var:: string version
+        @@
+        @@       The version of the model.
+        @@
+        """
+class ModelInferRequest:
+    """
+    @@
+    @@.. cpp:var:: message ModelInferRequest
+    @@
+    @@   Request message for ModelInfer.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, model_name, model_version, id, parameters, inputs, outputs, raw_input_contents):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_json(s):
+        """
+        Parse a request previously produced by `to_json`.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def __copy__(self):
+        ...
+    def __deepcopy__(self, _memo):
+        ...
+    def __hash__(self):
+        """
+        Return hash(self).
+        """
+    def add_raw_input_from_numpy(self, index, arr):
+        """
+        Copy the bytes of a C-contiguous numpy array into `raw_input_contents[index]`.
+
+        The array's dtype is not checked against the input: `shape` and `datatype` must already
+        be set on `inputs[index]`. Missing `raw_input_contents` entries before `index` are
+        filled with empty bytes, to be filled in by later calls.
+
+        Triton requires every input of a request using `raw_input_contents` to be raw, so this
+        raises `ValueError` while another input still sets `contents`.
+        """
+    def estimated_wire_size(self):
+        """
+        Size in bytes the request takes on the wire, to check it against gRPC message size
+        limits before sending.
+
+        This is the protobuf encoded length of the inputs, raw contents and all other fields,
+        plus the 5-byte gRPC message prefix.
+        """
+    def parameters_to_dict(self):
+        """
+        `parameters` as a dict of native Python values.
+        """
+    def set_parameters_from_dict(self, params):
+        """
+        Insert every `key: value` of `params` into `parameters`.
+
+        Values go through `InferParameter.from_value`; nothing is inserted if any fails.
+        """
+    def set_priority(self, priority):
+        """
+        Set the `priority` request parameter; 0 means the model's default.
+        """
+    def set_sequence_end(self, v):
+        """
+        Set the `sequence_end` request parameter.
+        """
+    def set_sequence_id(self, id):
+        """
+        Set the `sequence_id` request parameter.
+        """
+    def set_sequence_start(self, v):
+        """
+        Set the `sequence_start` request parameter.
+        """
+    def set_timeout_microseconds(self, timeout):
+        """
+        Set the `timeout` request parameter, in microseconds.
+        """
+    def to_json(self):
+        """
+        Serialise the request to JSON; bytes contents are base64-encoded.
+        """
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    def validate(self):
+        """
+        Check inputs for shape, datatype and content problems before sending.
+
+        Every input needs exactly one data source: `contents`, its `raw_input_contents` entry,
+        or a region set by `InferInputTensor.set_shared_memory`. Triton rejects requests mixing
+        the first two: once `raw_input_contents` is used, no input may set `contents`.
+
+        Returns a list of error messages; an empty list means the request is valid.
+        """
+    @property
+    def id(self):
+        """
+        @@  .. cpp:var:: string id
+        @@
+        @@     Optional identifier for the request. If specified will be
+        @@     returned in the response.
+        @@
+        """
+    @property
+    def inputs(self):
+        """
+        @@
+        @@  .. cpp:var:: InferInputTensor inputs (repeated)
+        @@
+        @@     The input tensors for the inference.
+        @@
+        """
+    @property
+    def model_name(self):
+        """
+        @@  .. cpp:var:: string model_name
+        @@
+        @@     The name of the model to use for inferencing.
+        @@
+        """
+    @property
+    def model_version(self):
+        """
+        @@  .. cpp:var:: string model_version
+        @@
+        @@     The version of the model to use for inference. If not
+        @@     given the latest/most-recent version of the model is used.
+        @@
+        """
+    @property
+    def outputs(self):
+        """
+        @@
+        @@  .. cpp:var:: InferRequestedOutputTensor outputs (repeated)
+        @@
+        @@     The requested output tensors for the inference. Optional, if not
+        @@     specified all outputs specified in the model config will be
+        @@     returned.
+        @@
+        """
+    @property
+    def parameters(self):
+        """
+        @@  .. cpp:var:: map\<string,InferParameter> parameters
+        @@
+        @@     Optional inference parameters.
+        @@
+        """
+    @property
+    def raw_input_contents(self):
+        """
+        @@
+        @@  .. cpp:var:: bytes raw_input_contents
+        @@
+        @@     The data contained in an input tensor can be represented in
+        @@     "raw" bytes form or in the repeated type that matches the
+        @@     tensor's data type. Using the "raw" bytes form will
+        @@     typically allow higher performance due to the way protobuf
+        @@     allocation and reuse interacts with GRPC. For example, see
+        @@     <https://github.com/grpc/grpc/issues/23231.>
+        @@
+        @@     To use the raw representation 'raw_input_contents' must be
+        @@     initialized with data for each tensor in the same order as
+        @@     'inputs'. For each tensor, the size of this content must
+        @@     match what is expected by the tensor's shape and data
+        @@     type. The raw data must be the flattened, one-dimensional,
+        @@     row-major order of the tensor elements without any stride
+        @@     or padding between the elements. Note that the FP16 and BF16 data
+        @@     types must be represented as raw content as there is no
+        @@     specific data type for a 16-bit float type.
+        @@
+        @@     If this field is specified then InferInputTensor::contents
+        @@     must not be specified for any input tensor.
+        @@
+        """
+class ModelInferResponse:
+    """
+    @@
+    @@.. cpp:var:: message ModelInferResponse
+    @@
+    @@   Response message for ModelInfer.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, model_name, model_version, id, parameters, outputs, raw_output_contents):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_json(s):
+        """
+        Parse a response previously produced by `to_json`.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def output_to_numpy(self, name):
+        """
+        Output `name` as a numpy array; raises `KeyError` if there is none.
+        """
+    def output_to_series(self, name):
+        """
+        Output `name` as a `pandas.Series`; raises `KeyError` if there is none.
+        """
+    def to_dataframe(self):
+        """
+        All outputs as a `pandas.DataFrame` with one column per output tensor.
+
+        Multi-dimensional outputs get one row per entry of their first axis.
+        """
+    def to_dict(self):
+        """
+        All outputs as numpy arrays keyed by output name.
+
+        Like `get_response_as_dict()` of the official client, with the tensor data decoded.
+        """
+    def to_json(self):
+        """
+        Serialise the response to JSON; bytes contents are base64-encoded.
+        """
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def id(self):
+        """
+        @@  .. cpp:var:: string id
+        @@
+        @@     The id of the inference request if one was specified.
+        @@
+        """
+    @property
+    def model_name(self):
+        """
+        @@  .. cpp:var:: string model_name
+        @@
+        @@     The name of the model used for inference.
+        @@
+        """
+    @property
+    def model_version(self):
+        """
+        @@  .. cpp:var:: string model_version
+        @@
+        @@     The version of the model used for inference.
+        @@
+        """
+    @property
+    def outputs(self):
+        """
+        @@
+        @@  .. cpp:var:: InferOutputTensor outputs (repeated)
+        @@
+        @@     The output tensors holding inference results.
+        @@
+        """
+    @property
+    def parameters(self):
+        """
+        @@  .. cpp:var:: map\<string,InferParameter> parameters
+        @@
+        @@     Optional inference response parameters.
+        @@
+        """
+    @property
+    def raw_output_contents(self):
+        """
+        @@
+        @@  .. cpp:var:: bytes raw_output_contents
+        @@
+        @@     The data contained in an output tensor can be represented in
+        @@     "raw" bytes form or in the repeated type that matches the
+        @@     tensor's data type. Using the "raw" bytes form will
+        @@     typically allow higher performance due to the way protobuf
+        @@     allocation and reuse interacts with GRPC. For example, see
+        @@     <https://github.com/grpc/grpc/issues/23231.>
+        @@
+        @@     To use the raw representation 'raw_output_contents' must be
+        @@     initialized with data for each tensor in the same order as
+        @@     'outputs'. For each tensor, the size of this content must
+        @@     match what is expected by the tensor's shape and data
+        @@     type. The raw data must be the flattened, one-dimensional,
+        @@     row-major order of the tensor elements without any stride
+        @@     or padding between the elements. Note that the FP16 and BF16 data
+        @@     types must be represented as raw content as there is no
+        @@     specific data type for a 16-bit float type.
+        @@
+        @@     If this field is specified then InferOutputTensor::contents
+        @@     must not be specified for any output tensor.
+        @@
+        """
+class ModelMetadataRequest:
+    """
+    @@
+    @@.. cpp:var:: message ModelMetadataRequest
+    @@
+    @@   Request message for ModelMetadata.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, name, version):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@  .. cpp:var:: string name
+        @@
+        @@     The name of the model.
+        @@
+        """
+    @property
+    def version(self):
+        """
+        @@  .. cpp:var:: string version
+        @@
+        @@     The version of the model to check for readiness. If not
+        @@     given the server will choose a version based on the
+        @@     model and internal policy.
+        @@
+        """
+class ModelMetadataResponse:
+    """
+    @@
+    @@.. cpp:var:: message ModelMetadataResponse
+    @@
+    @@   Response message for ModelMetadata.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, name, versions, platform, inputs, outputs):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def get_input_by_name(self, name):
+        """
+        Input tensor metadata named `name`; raises `KeyError` if there is none.
+        """
+    def get_output_by_name(self, name):
+        """
+        Output tensor metadata named `name`; raises `KeyError` if there is none.
+        """
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def inputs(self):
+        """
+        @@
+        @@  .. cpp:var:: TensorMetadata inputs (repeated)
+        @@
+        @@     The model's inputs.
+        @@
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@  .. cpp:var:: string name
+        @@
+        @@     The model name.
+        @@
+        """
+    @property
+    def outputs(self):
+        """
+        @@
+        @@  .. cpp:var:: TensorMetadata outputs (repeated)
+        @@
+        @@     The model's outputs.
+        @@
+        """
+    @property
+    def platform(self):
+        """
+        @@
+        @@  .. cpp:var:: string platform
+        @@
+        @@     The model's platform.
+        @@
+        """
+    @property
+    def versions(self):
+        """
+        @@
+        @@  .. cpp:var:: string versions (repeated)
+        @@
+        @@     The versions of the model.
+        @@
+        """
+class ModelReadyRequest:
+    """
+    @@
+    @@.. cpp:var:: message ModelReadyRequest
+    @@
+    @@   Request message for ModelReady.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, name, version):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@  .. cpp:var:: string name
+        @@
+        @@     The name of the model to check for readiness.
+        @@
+        """
+    @property
+    def version(self):
+        """
+        @@  .. cpp:var:: string version
+        @@
+        @@     The version of the model to check for readiness. If not given the
+        @@     server will choose a version based on the model and internal policy.
+        @@
+        """
+class ModelReadyResponse:
+    """
+    @@
+    @@.. cpp:var:: message ModelReadyResponse
+    @@
+    @@   Response message for ModelReady.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, ready):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def __eq__(self, value):
+        """
+        Return self==value.
+        """
+    def __hash__(self):
+        """
+        Return hash(self).
+        """
+    def __ne__(self, value):
+        """
+        Return self!=value.
+        """
+    def __repr__(self):
+        """
+        Return repr(self).
+        """
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def ready(self):
+        """
+        @@
+        @@  .. cpp:var:: bool ready
+        @@
+        @@     True if the model is ready, false it not ready.
+        @@
+        """
+class ModelReadyStream:
+    """
+    Iterator returned by [`Client::model_ready_stream`], yielding one readiness result per poll.
+
+    Polling runs in a background task; dropping the stream stops it.
+    """
+    @staticmethod
+    def __new__(type, *args, **kwargs):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    def __iter__(self):
+        """
+        Implement iter(self).
+        """
+    def __next__(self):
+        """
+        Implement next(self).
+        """
+class ModelRepositoryParameter:
+    """
+    @@
+    @@.. cpp:var:: message ModelRepositoryParameter
+    @@
+    @@   An model repository parameter value.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, parameter_choice=None):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_bool(b):
+        ...
+    @staticmethod
+    def from_bytes(b):
+        ...
+    @staticmethod
+    def from_int64(i):
+        ...
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    @staticmethod
+    def from_string(s):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def to_python_value(self):
+        """
+        The inner value as `str`, `bool`, `int` or `bytes`, or `None` if unset.
+        """
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def parameter_choice(self):
+        """
+        @@  .. cpp:var:: oneof parameter_choice
+        @@
+        @@     The parameter value can be a string, an int64 or
+        @@     a boolean
+        @@
+        """
+    @parameter_choice.setter
+    def parameter_choice(self, arg0):
+        ...
+class ModelStatisticsRequest:
+    """
+    @@
+    @@.. cpp:var:: message ModelStatisticsRequest
+    @@
+    @@   Request message for ModelStatistics.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, name, version):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def name(self):
+        """
+        @@  .. cpp:var:: string name
+        @@
+        @@     The name of the model. If not given returns statistics for
+        @@     all models.
+        @@
+        """
+    @property
+    def version(self):
+        """
+        @@  .. cpp:var:: string version
+        @@
+        @@     The version of the model. If not given returns statistics for
+        @@     all model versions.
+        @@
+        """
+class ModelStatisticsResponse:
+    """
+    @@
+    @@.. cpp:var:: message ModelStatisticsResponse
+    @@
+    @@   Response message for ModelStatistics.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, model_stats):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def get_model(self, name, version=""):
+        """
+        Statistics of model `name` at `version`, or of its first listed version if `version`
+        is empty; raises `KeyError` if there are none.
+        """
+    def has_model(self, name, version=""):
+        """
+        Whether statistics of model `name` at `version` (any version if empty) are present.
+        """
+    def model_names(self):
+        """
+        Names of the models with statistics, without duplicates, in response order.
+        """
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def model_stats(self):
+        """
+        @@  .. cpp:var:: ModelStatistics model_stats (repeated)
+        @@
+        @@     Statistics for each requested model.
+        @@
+        """
+class ParameterChoice:
+    """
+    @@  .. cpp:var:: oneof parameter_choice
+    @@
+    @@     The parameter value can be a string, an int64,
+    @@     an uint64, a double, or a boolean
+    @@
+    @@     Note: double and uint64 are currently
+    @@           placeholders for future use and
+    @@           are not supported for custom parameters
+    @@
+    """
+    BoolParam = ParameterChoice_BoolParam
+    Int64Param = ParameterChoice_Int64Param
+    StringParam = ParameterChoice_StringParam
+    DoubleParam = ParameterChoice_DoubleParam
+    Uint64Param = ParameterChoice_Uint64Param
+    @staticmethod
+    def __new__(type, *args, **kwargs):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @classmethod
+    def bool_param(cls, value):
+        ...
+    @classmethod
+    def double_param(cls, value):
+        ...
+    @classmethod
+    def int64_param(cls, value):
+        ...
+    @classmethod
+    def string_param(cls, value):
+        ...
+    @classmethod
+    def uint64_param(cls, value):
+        ...
+    def __copy__(self):
+        ...
+    def __deepcopy__(self, _memo):
+        ...
+class RegionStatus:
+    """
+    @@
+    @@  .. cpp:var:: message RegionStatus
+    @@
+    @@     Status for a shared memory region.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, name, key, offset, byte_size):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def byte_size(self):
+        """
+        @@    .. cpp:var:: uint64 byte_size
+        @@
+        @@       Size of the shared memory region, in bytes.
+        @@
+        """
+    @property
+    def key(self):
+        """
+        @@    .. cpp:var:: string shared_memory_key
+        @@
+        @@       The key of the underlying memory object that contains the
+        @@       shared memory region.
+        @@
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@    .. cpp:var:: string name
+        @@
+        @@       The name for the shared memory region.
+        @@
+        """
+    @property
+    def offset(self):
+        """
+        @@    .. cpp:var:: uint64 offset
+        @@
+        @@       Offset, in bytes, within the underlying memory object to
+        @@       the start of the shared memory region.
+        @@
+        """
+class RepositoryIndexRequest:
+    """
+    @@
+    @@.. cpp:var:: message RepositoryIndexRequest
+    @@
+    @@   Request message for RepositoryIndex.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, repository_name, ready):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def ready(self):
+        """
+        @@  .. cpp:var:: bool ready
+        @@
+        @@     If true returned only models currently ready for inferencing.
+        @@
+        """
+    @property
+    def repository_name(self):
+        """
+        @@  .. cpp:var:: string repository_name
+        @@
+        @@     The name of the repository. If empty the index is returned
+        @@     for all repositories.
+        @@
+        """
+class RepositoryIndexResponse:
+    """
+    @@
+    @@.. cpp:var:: message RepositoryIndexResponse
+    @@
+    @@   Response message for RepositoryIndex.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, models):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def models(self):
+        """
+        @@
+        @@  .. cpp:var:: ModelIndex models (repeated)
+        @@
+        @@     An index entry for each model.
+        @@
+        """
+class RepositoryModelLoadRequest:
+    """
+    @@
+    @@.. cpp:var:: message RepositoryModelLoadRequest
+    @@
+    @@   Request message for RepositoryModelLoad.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, repository_name, model_name, parameters):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def model_name(self):
+        """
+        @@  .. cpp:var:: string repository_name
+        @@
+        @@     The name of the model to load, or reload.
+        @@
+        """
+    @property
+    def parameters(self):
+        """
+        @@  .. cpp:var:: map\<string,ModelRepositoryParameter> parameters
+        @@
+        @@     Optional model repository request parameters.
+        @@
+        """
+    @property
+    def repository_name(self):
+        """
+        @@  .. cpp:var:: string repository_name
+        @@
+        @@     The name of the repository to load from. If empty the model
+        @@     is loaded from any repository.
+        @@
+        """
+class RepositoryModelLoadResponse:
+    """
+    @@
+    @@.. cpp:var:: message RepositoryModelLoadResponse
+    @@
+    @@   Response message for RepositoryModelLoad.
+    @@
+    """
+    @staticmethod
+    def __new__(cls):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+class RepositoryModelUnloadRequest:
+    """
+    @@
+    @@.. cpp:var:: message RepositoryModelUnloadRequest
+    @@
+    @@   Request message for RepositoryModelUnload.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, repository_name, model_name, parameters):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def model_name(self):
+        """
+        @@  .. cpp:var:: string repository_name
+        @@
+        @@     The name of the model to unload.
+        @@
+        """
+    @property
+    def parameters(self):
+        """
+        @@  .. cpp:var:: map\<string,ModelRepositoryParameter> parameters
+        @@
+        @@     Optional model repository request parameters.
+        @@
+        """
+    @property
+    def repository_name(self):
+        """
+        @@  .. cpp:var:: string repository_name
+        @@
+        @@     The name of the repository from which the model was originally
+        @@     loaded. If empty the repository is not considered.
+        @@
+        """
+class RepositoryModelUnloadResponse:
+    """
+    @@
+    @@.. cpp:var:: message RepositoryModelUnloadResponse
+    @@
+    @@   Response message for RepositoryModelUnload.
+    @@
+    """
+    @staticmethod
+    def __new__(cls):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+class Runtime:
+    """
+    A tokio runtime running the gRPC calls of the clients using it; clones share it.
+
+    `init_runtime` configures the shared one used by default. Constructing a `Runtime` creates
+    an isolated one, e.g. per test, that shuts down once no client uses it.
+    """
+    @staticmethod
+    def __new__(cls, num_threads=None, thread_name=None, stack_size_kb=None):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+class ServerLiveResponse:
+    """
+    @@
+    @@.. cpp:var:: message ServerLiveResponse
+    @@
+    @@   Response message for ServerLive.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, live):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def __eq__(self, value):
+        """
+        Return self==value.
+        """
+    def __hash__(self):
+        """
+        Return hash(self).
+        """
+    def __ne__(self, value):
+        """
+        Return self!=value.
+        """
+    def __repr__(self):
+        """
+        Return repr(self).
+        """
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def live(self):
+        """
+        @@
+        @@  .. cpp:var:: bool live
+        @@
+        @@     True if the inference server is live, false it not live.
+        @@
+        """
+class ServerMetadataResponse:
+    """
+    @@
+    @@.. cpp:var:: message ServerMetadataResponse
+    @@
+    @@   Response message for ServerMetadata.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, name, version, extensions):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def __eq__(self, value):
+        """
+        Return self==value.
+        """
+    def __hash__(self):
+        """
+        Return hash(self).
+        """
+    def __ne__(self, value):
+        """
+        Return self!=value.
+        """
+    def __repr__(self):
+        """
+        Return repr(self).
+        """
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def extensions(self):
+        """
+        @@
+        @@  .. cpp:var:: string extensions (repeated)
+        @@
+        @@     The extensions supported by the server.
+        @@
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@  .. cpp:var:: string name
+        @@
+        @@     The server name.
+        @@
+        """
+    @property
+    def version(self):
+        """
+        @@
+        @@  .. cpp:var:: string version
+        @@
+        @@     The server version.
+        @@
+        """
+class ServerReadyResponse:
+    """
+    @@
+    @@.. cpp:var:: message ServerReadyResponse
+    @@
+    @@   Response message for ServerReady.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, ready):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def __eq__(self, value):
+        """
+        Return self==value.
+        """
+    def __hash__(self):
+        """
+        Return hash(self).
+        """
+    def __ne__(self, value):
+        """
+        Return self!=value.
+        """
+    def __repr__(self):
+        """
+        Return repr(self).
+        """
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def ready(self):
+        """
+        @@
+        @@  .. cpp:var:: bool ready
+        @@
+        @@     True if the inference server is ready, false it not ready.
+        @@
+        """
+class StatisticDuration:
     """
-    @@  .. cpp:var:: oneof parameter_choice
     @@
-    @@     The parameter value can be a string, an int64,
-    @@     an uint64, a double, or a boolean
+    @@.. cpp:var:: message StatisticDuration
     @@
-    @@     Note: double and uint64 are currently
-    @@           placeholders for future use and
-    @@           are not supported for custom parameters
+    @@   Statistic recording a cumulative duration metric.
     @@
     """
-    BoolParam = ParameterChoice_BoolParam
-    DoubleParam = ParameterChoice_DoubleParam
-    Int64Param = ParameterChoice_Int64Param
-    StringParam = ParameterChoice_StringParam
-    Uint64Param = ParameterChoice_Uint64Param
-    @classmethod
-    def bool_param(cls, value):
-        ...
-    @classmethod
-    def double_param(cls, value):
+    @staticmethod
+    def __new__(cls, count, ns):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
         ...
-    @classmethod
-    def int64_param(cls, value):
+    def to_proto_bytes(self):
         ...
-    @classmethod
-    def string_param(cls, value):
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def count(self):
+        """
+        @@  .. cpp:var:: uint64 count
+        @@
+        @@     Cumulative number of times this metric occurred.
+        @@
+        """
+    @property
+    def ns(self):
+        """
+        @@  .. cpp:var:: uint64 total_time_ns
+        @@
+        @@     Total collected duration of this metric in nanoseconds.
+        @@
+        """
+class SystemSharedMemoryRegisterRequest:
+    """
+    @@
+    @@.. cpp:var:: message SystemSharedMemoryRegisterRequest
+    @@
+    @@   Request message for SystemSharedMemoryRegister.
+    @@
+    """
+    @staticmethod
+    def __new__(cls, name, key, offset, byte_size):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def for_numpy(name, arr, shm_key, offset=0, byte_size=None):
+        """
+        Register request for a region holding `arr`, sized from `arr.nbytes`.
+
+        If `byte_size` is given it must equal `arr.nbytes`.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
         ...
-    @classmethod
-    def uint64_param(cls, value):
+    def to_proto_bytes(self):
         ...
-class RegionStatus:
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def byte_size(self):
+        """
+        @@  .. cpp:var:: uint64 byte_size
+        @@
+        @@     Size of the shared memory region, in bytes.
+        @@
+        """
+    @property
+    def key(self):
+        """
+        @@  .. cpp:var:: string shared_memory_key
+        @@
+        @@     The key of the underlying memory object that contains the
+        @@     shared memory region.
+        @@
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@  .. cpp:var:: string name
+        @@
+        @@     The name of the region to register.
+        @@
+        """
+    @property
+    def offset(self):
+        """
+        @@  .. cpp:var:: uint64 offset
+        @@
+        @@     Offset, in bytes, within the underlying memory object to
+        @@     the start of the shared memory region.
+        @@
+        """
+class SystemSharedMemoryRegisterResponse:
     """
     @@
-    @@  .. cpp:var:: message RegionStatus
+    @@.. cpp:var:: message SystemSharedMemoryRegisterResponse
     @@
-    @@     Status for a shared memory region.
+    @@   Response message for SystemSharedMemoryRegister.
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class RepositoryIndexRequest:
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+class SystemSharedMemoryStatusRequest:
     """
     @@
-    @@.. cpp:var:: message RepositoryIndexRequest
+    @@.. cpp:var:: message SystemSharedMemoryStatusRequest
     @@
-    @@   Request message for RepositoryIndex.
+    @@   Request message for SystemSharedMemoryStatus.
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, name):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class RepositoryIndexResponse:
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@  .. cpp:var:: string name
+        @@
+        @@     The name of the region to get status for. If empty the
+        @@     status is returned for all registered regions.
+        @@
+        """
+class SystemSharedMemoryStatusResponse:
     """
     @@
-    @@.. cpp:var:: message RepositoryIndexResponse
+    @@.. cpp:var:: message SystemSharedMemoryStatusResponse
     @@
-    @@   Response message for RepositoryIndex.
+    @@   Response message for SystemSharedMemoryStatus.
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, regions):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class RepositoryModelLoadRequest:
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def regions(self):
+        """
+        @@
+        @@  .. cpp:var:: map\<string,RegionStatus> regions
+        @@
+        @@     Status for each of the registered regions, indexed by
+        @@     region name.
+        @@
+        """
+class SystemSharedMemoryUnregisterRequest:
     """
     @@
-    @@.. cpp:var:: message RepositoryModelLoadRequest
+    @@.. cpp:var:: message SystemSharedMemoryUnregisterRequest
     @@
-    @@   Request message for RepositoryModelLoad.
+    @@   Request message for SystemSharedMemoryUnregister.
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, name):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class RepositoryModelLoadResponse:
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@  .. cpp:var:: string name
+        @@
+        @@     The name of the system region to unregister. If empty
+        @@     all system shared-memory regions are unregistered.
+        @@
+        """
+class SystemSharedMemoryUnregisterResponse:
     """
     @@
-    @@.. cpp:var:: message RepositoryModelLoadResponse
+    @@.. cpp:var:: message SystemSharedMemoryUnregisterResponse
     @@
-    @@   Response message for RepositoryModelLoad.
+    @@   Response message for SystemSharedMemoryUnregister.
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class RepositoryModelUnloadRequest:
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+class TensorMetadata:
     """
     @@
-    @@.. cpp:var:: message RepositoryModelUnloadRequest
+    @@  .. cpp:var:: message TensorMetadata
     @@
-    @@   Request message for RepositoryModelUnload.
+    @@     Metadata for a tensor.
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, name, datatype, shape):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class RepositoryModelUnloadResponse:
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def Get_shape(self):
+        """
+        Deprecated: use `get_shape` instead.
+        """
+    def IsEmpty_shape(self):
+        """
+        Deprecated: use `is_empty_shape` instead.
+        """
+    def Len_shape(self):
+        """
+        Deprecated: use `len_shape` instead.
+        """
+    def Push_shape(self, val):
+        """
+        Deprecated: use `push_shape` instead.
+        """
+    def Replace_shape(self, val):
+        """
+        Deprecated: use `replace_shape` instead.
+        """
+    def Set_shape(self, list):
+        ...
+    def Take_shape(self):
+        """
+        Deprecated: use `take_shape` instead.
+        """
+    def get_shape(self):
+        ...
+    def is_dynamic_shape(self):
+        """
+        Whether any dimension is variable-size (`-1`).
+        """
+    def is_empty_shape(self):
+        ...
+    def len_shape(self):
+        ...
+    def num_elements(self):
+        """
+        Number of elements of the tensor; raises `ValueError` if the shape is dynamic.
+        """
+    def push_shape(self, val):
+        ...
+    def replace_shape(self, val):
+        ...
+    def shape_as_list(self):
+        """
+        `shape` as a list; `-1` marks a variable-size dimension.
+        """
+    def shape_as_tuple(self):
+        """
+        `shape` as a tuple; `-1` marks a variable-size dimension.
+        """
+    def take_shape(self):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def datatype(self):
+        """
+        @@
+        @@    .. cpp:var:: string datatype
+        @@
+        @@       The tensor data type.
+        @@
+        """
+    @property
+    def name(self):
+        """
+        @@
+        @@    .. cpp:var:: string name
+        @@
+        @@       The tensor name.
+        @@
+        """
+    @property
+    def shape(self):
+        """
+        @@
+        @@    .. cpp:var:: int64 shape (repeated)
+        @@
+        @@       The tensor shape. A variable-size dimension is represented
+        @@       by a -1 value.
+        @@
+        """
+class TraceSettingRequest:
     """
     @@
-    @@.. cpp:var:: message RepositoryModelUnloadResponse
+    @@.. cpp:var:: message TraceSettingRequest
     @@
-    @@   Response message for RepositoryModelUnload.
+    @@   Request message for TraceSetting.
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, settings, model_name):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class ServerLiveResponse:
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def model_name(self):
+        """
+        @@
+        @@  .. cpp:var:: string model_name
+        @@
+        @@     The name of the model to apply the new trace settings.
+        @@     If not given, the new settings will be applied globally.
+        @@
+        """
+    @property
+    def settings(self):
+        """
+        @@  .. cpp:var:: map\<string,SettingValue> settings
+        @@
+        @@     The new setting values to be updated,
+        @@     settings that are not specified will remain unchanged.
+        @@
+        """
+class TraceSettingResponse:
     """
     @@
-    @@.. cpp:var:: message ServerLiveResponse
+    @@.. cpp:var:: message TraceSettingResponse
     @@
-    @@   Response message for ServerLive.
+    @@   Response message for TraceSetting.
     @@
     """
     @staticmethod
-    def __new__(type, *args, **kwargs):
+    def __new__(cls, settings):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class ServerMetadataResponse:
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def setting_values(self):
+        """
+        @@  .. cpp:var:: map\<string,SettingValue> settings
+        @@
+        @@     The current trace settings, including any changes specified
+        @@     by TraceSettingRequest.
+        @@
+        """
+    @property
+    def settings(self):
+        """
+        Trace settings as a `dict[str, list[str]]`.
+
+        Every value is a list, even with a single element, since a setting may hold several.
+        The `TraceSettingValue` objects are available as `setting_values`.
+        """
+class TraceSettingValue:
     """
     @@
-    @@.. cpp:var:: message ServerMetadataResponse
+    @@  .. cpp:var:: message SettingValue
     @@
-    @@   Response message for ServerMetadata.
+    @@     The values to be associated with a trace setting.
     @@
     """
     @staticmethod
+    def __new__(cls, value):
+        """
+        Create and return a new object.  See help(type) for accurate signature.
+        """
+    @staticmethod
+    def from_proto_bytes(data):
+        ...
+    def to_proto_bytes(self):
+        ...
+    def update(self, **kwargs):
+        """
+        按关键字参数更新字段，如 `req.update(model_name="m", model_version="2")`
+        """
+    @property
+    def value(self):
+        """
+        @@
+        @@    .. cpp:var:: string value (repeated)
+        @@
+        @@       The value.
+        @@
+        """
+class TritonBatchError(Exception):
+    """
+    Raised when some requests of a batch fail; `args[1]` holds one optional exception per
+    request, e.g. a `TritonServerError` with its `status_code`.
+    """
+class TritonConnectionError(Exception):
+    """
+    Raised when the client is closed or the server cannot be reached (gRPC `UNAVAILABLE`).
+    """
+class TritonServerError(Exception):
+    """
+    Raised when the server answers with an error status; `status_code` holds the gRPC code
+    (e.g. 5 for NOT_FOUND), `message` its message and `details` the binary status details.
+    """
+class TritonTimeoutError(Exception):
+    """
+    Raised when a request deadline has passed, before or while sending it.
+    """
+class VecRefBool:
+    """
+    """
+    @staticmethod
     def __new__(type, *args, **kwargs):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class ServerReadyResponse:
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __iter__(self):
+        """
+        Implement iter(self).
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+class VecRefF32:
     """
-    @@
-    @@.. cpp:var:: message ServerReadyResponse
-    @@
-    @@   Response message for ServerReady.
-    @@
     """
     @staticmethod
     def __new__(type, *args, **kwargs):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class SystemSharedMemoryRegisterRequest:
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __iter__(self):
+        """
+        Implement iter(self).
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+class VecRefF64:
     """
-    @@
-    @@.. cpp:var:: message SystemSharedMemoryRegisterRequest
-    @@
-    @@   Request message for SystemSharedMemoryRegister.
-    @@
     """
     @staticmethod
     def __new__(type, *args, **kwargs):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class SystemSharedMemoryRegisterResponse:
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __iter__(self):
+        """
+        Implement iter(self).
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+class VecRefI16:
     """
-    @@
-    @@.. cpp:var:: message SystemSharedMemoryRegisterResponse
-    @@
-    @@   Response message for SystemSharedMemoryRegister.
-    @@
     """
     @staticmethod
     def __new__(type, *args, **kwargs):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class SystemSharedMemoryStatusRequest:
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __iter__(self):
+        """
+        Implement iter(self).
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+class VecRefI32:
     """
-    @@
-    @@.. cpp:var:: message SystemSharedMemoryStatusRequest
-    @@
-    @@   Request message for SystemSharedMemoryStatus.
-    @@
     """
     @staticmethod
     def __new__(type, *args, **kwargs):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class SystemSharedMemoryStatusResponse:
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __iter__(self):
+        """
+        Implement iter(self).
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+class VecRefI64:
     """
-    @@
-    @@.. cpp:var:: message SystemSharedMemoryStatusResponse
-    @@
-    @@   Response message for SystemSharedMemoryStatus.
-    @@
     """
     @staticmethod
     def __new__(type, *args, **kwargs):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class SystemSharedMemoryUnregisterRequest:
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __iter__(self):
+        """
+        Implement iter(self).
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+class VecRefI8:
     """
-    @@
-    @@.. cpp:var:: message SystemSharedMemoryUnregisterRequest
-    @@
-    @@   Request message for SystemSharedMemoryUnregister.
-    @@
     """
     @staticmethod
     def __new__(type, *args, **kwargs):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class SystemSharedMemoryUnregisterResponse:
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __iter__(self):
+        """
+        Implement iter(self).
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+class VecRefU16:
     """
-    @@
-    @@.. cpp:var:: message SystemSharedMemoryUnregisterResponse
-    @@
-    @@   Response message for SystemSharedMemoryUnregister.
-    @@
     """
     @staticmethod
     def __new__(type, *args, **kwargs):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class TensorMetadata:
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __iter__(self):
+        """
+        Implement iter(self).
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+class VecRefU32:
     """
-    @@
-    @@  .. cpp:var:: message TensorMetadata
-    @@
-    @@     Metadata for a tensor.
-    @@
     """
     @staticmethod
     def __new__(type, *args, **kwargs):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-    def Get_shape(self):
-        ...
-    def Replace_shape(self, val):
-        ...
-    def Set_shape(self, list):
-        ...
-    def Take_shape(self):
-        ...
-class TraceSettingRequest:
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __iter__(self):
+        """
+        Implement iter(self).
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+class VecRefU64:
     """
-    @@
-    @@.. cpp:var:: message TraceSettingRequest
-    @@
-    @@   Request message for TraceSetting.
-    @@
     """
     @staticmethod
     def __new__(type, *args, **kwargs):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
-class TraceSettingResponse:
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __iter__(self):
+        """
+        Implement iter(self).
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+class VecRefU8:
     """
-    @@
-    @@.. cpp:var:: message TraceSettingResponse
-    @@
-    @@   Response message for TraceSetting.
-    @@
     """
     @staticmethod
     def __new__(type, *args, **kwargs):
         """
         Create and return a new object.  See help(type) for accurate signature.
         """
+    def __getitem__(self, key):
+        """
+        Return self[key].
+        """
+    def __iter__(self):
+        """
+        Implement iter(self).
+        """
+    def __len__(self):
+        """
+        Return len(self).
+        """
+    def to_array(self):
+        """
+        to numpy array
+        """
+    def to_list(self):
+        """
+        to Python list
+        """
+def get_log_handle():
+    """
+    Get the handle of the logger configured from `LOG_LEVEL` at import.
+    """
+def init_runtime(num_threads=None, thread_name=None, stack_size_kb=None):
+    """
+    Configure the shared tokio runtime and return it.
+
+    Must be called before the first `Client` is constructed; raises `RuntimeError`
+    if the runtime has already been initialised.
+    """
+def triton_proto_version():
+    """
+    Version of the Triton protobuf schema the bindings were generated from.
+
+    The Triton release when the build set `TRITON_PROTO_VERSION`, otherwise a fingerprint of
+    the `.proto` files.
+    """
+def version():
+    """
+    Version of the `triton_client` crate this module was built from.
+    """
@@ -3,16 +3,22 @@ High-performance Triton inference client
 """
 from __future__ import annotations
 from triton_client import Client
+from triton_client import ClientBuilder
+from triton_client import CudaRegionStatus
 from triton_client import CudaSharedMemoryRegisterRequest
 from triton_client import CudaSharedMemoryRegisterResponse
 from triton_client import CudaSharedMemoryStatusRequest
 from triton_client import CudaSharedMemoryStatusResponse
 from triton_client import CudaSharedMemoryUnregisterRequest
 from triton_client import CudaSharedMemoryUnregisterResponse
+from triton_client import DataType
+from triton_client import InferBatchStatistics
 from triton_client import InferInputTensor
 from triton_client import InferOutputTensor
 from triton_client import InferParameter
 from triton_client import InferRequestedOutputTensor
+from triton_client import InferResponseStatistics
+from triton_client import InferStatistics
 from triton_client import InferTensorContents
 from triton_client import ListBool
 from triton_client import ListF32
@@ -25,6 +31,8 @@ from triton_client import ListU16
 from triton_client import ListU32
 from triton_client import ListU64
 from triton_client import ListU8
+from triton_client import LogHandle
+from triton_client import MemoryUsage
 from triton_client import ModelConfig
 from triton_client import ModelConfigRequest
 from triton_client import ModelConfigResponse
@@ -35,6 +43,7 @@ from triton_client import ModelMetadataRequest
 from triton_client import ModelMetadataResponse
 from triton_client import ModelReadyRequest
 from triton_client import ModelReadyResponse
+from triton_client import ModelReadyStream
 from triton_client import ModelRepositoryParameter
 from triton_client import ModelStatisticsRequest
 from triton_client import ModelStatisticsResponse
@@ -46,9 +55,11 @@ from triton_client import RepositoryModelLoadRequest
 from triton_client import RepositoryModelLoadResponse
 from triton_client import RepositoryModelUnloadRequest
 from triton_client import RepositoryModelUnloadResponse
+from triton_client import Runtime
 from triton_client import ServerLiveResponse
 from triton_client import ServerMetadataResponse
 from triton_client import ServerReadyResponse
+from triton_client import StatisticDuration
 from triton_client import SystemSharedMemoryRegisterRequest
 from triton_client import SystemSharedMemoryRegisterResponse
 from triton_client import SystemSharedMemoryStatusRequest
@@ -58,4 +69,24 @@ from triton_client import SystemSharedMemoryUnregisterResponse
 from triton_client import TensorMetadata
 from triton_client import TraceSettingRequest
 from triton_client import TraceSettingResponse
-__all__: list = ['__doc__', 'Client', 'ServerLiveResponse', 'ServerReadyResponse', 'ModelReadyRequest', 'ModelReadyResponse', 'ServerMetadataResponse', 'ModelConfig', 'ModelMetadataRequest', 'ModelMetadataResponse', 'ModelInferRequest', 'ModelInferResponse', 'ModelConfigRequest', 'ModelConfigResponse', 'ModelStatisticsRequest', 'ModelStatisticsResponse', 'TraceSettingRequest', 'TraceSettingResponse', 'InferParameter', 'InferTensorContents', 'ModelRepositoryParameter', 'RepositoryIndexRequest', 'RepositoryIndexResponse', 'RepositoryModelLoadRequest', 'RepositoryModelLoadResponse', 'RepositoryModelUnloadRequest', 'RepositoryModelUnloadResponse', 'SystemSharedMemoryStatusRequest', 'SystemSharedMemoryStatusResponse', 'SystemSharedMemoryRegisterRequest', 'SystemSharedMemoryRegisterResponse', 'SystemSharedMemoryUnregisterRequest', 'SystemSharedMemoryUnregisterResponse', 'CudaSharedMemoryStatusRequest', 'CudaSharedMemoryStatusResponse', 'CudaSharedMemoryRegisterRequest', 'CudaSharedMemoryRegisterResponse', 'CudaSharedMemoryUnregisterRequest', 'CudaSharedMemoryUnregisterResponse', 'ParameterChoice', 'TensorMetadata', 'ParameterChoice', 'InferInputTensor', 'RegionStatus', 'InferRequestedOutputTensor', 'InferOutputTensor', 'ModelIndex', 'ListBool', 'ListI8', 'ListI16', 'ListI32', 'ListI64', 'ListU8', 'ListU16', 'ListU32', 'ListU64', 'ListF32', 'ListF64']
+from triton_client import TraceSettingValue
+from triton_client import TritonBatchError
+from triton_client import TritonConnectionError
+from triton_client import TritonServerError
+from triton_client import TritonTimeoutError
+from triton_client import VecRefBool
+from triton_client import VecRefF32
+from triton_client import VecRefF64
+from triton_client import VecRefI16
+from triton_client import VecRefI32
+from triton_client import VecRefI64
+from triton_client import VecRefI8
+from triton_client import VecRefU16
+from triton_client import VecRefU32
+from triton_client import VecRefU64
+from triton_client import VecRefU8
+from triton_client import get_log_handle
+from triton_client import init_runtime
+from triton_client import triton_proto_version
+from triton_client import version
+__all__: list = ['__doc__', 'init_runtime', 'Runtime', 'get_log_handle', 'version', 'triton_proto_version', 'LogHandle', 'Client', 'ClientBuilder', 'ModelReadyStream', 'TritonConnectionError', 'TritonBatchError', 'TritonServerError', 'TritonTimeoutError', 'ServerLiveResponse', 'ServerReadyResponse', 'ModelReadyRequest', 'ModelReadyResponse', 'ServerMetadataResponse', 'ModelConfig', 'ModelMetadataRequest', 'ModelMetadataResponse', 'ModelInferRequest', 'ModelInferResponse', 'ModelConfigRequest', 'ModelConfigResponse', 'ModelStatisticsRequest', 'ModelStatisticsResponse', 'TraceSettingRequest', 'TraceSettingResponse', 'InferParameter', 'InferTensorContents', 'ModelRepositoryParameter', 'RepositoryIndexRequest', 'RepositoryIndexResponse', 'RepositoryModelLoadRequest', 'RepositoryModelLoadResponse', 'RepositoryModelUnloadRequest', 'RepositoryModelUnloadResponse', 'SystemSharedMemoryStatusRequest', 'SystemSharedMemoryStatusResponse', 'SystemSharedMemoryRegisterRequest', 'SystemSharedMemoryRegisterResponse', 'SystemSharedMemoryUnregisterRequest', 'SystemSharedMemoryUnregisterResponse', 'CudaSharedMemoryStatusRequest', 'CudaSharedMemoryStatusResponse', 'CudaSharedMemoryRegisterRequest', 'CudaSharedMemoryRegisterResponse', 'CudaSharedMemoryUnregisterRequest', 'CudaSharedMemoryUnregisterResponse', 'DataType', 'ParameterChoice', 'TensorMetadata', 'ParameterChoice', 'InferInputTensor', 'RegionStatus', 'InferRequestedOutputTensor', 'InferOutputTensor', 'ModelIndex', 'StatisticDuration', 'InferStatistics', 'InferResponseStatistics', 'InferBatchStatistics', 'MemoryUsage', 'TraceSettingValue', 'CudaRegionStatus', 'ListBool', 'ListI8', 'ListI16', 'ListI32', 'ListI64', 'ListU8', 'ListU16', 'ListU32', 'ListU64', 'ListF32', 'ListF64', 'VecRefBool', 'VecRefI8', 'VecRefI16', 'VecRefI32', 'VecRefI64', 'VecRefU8', 'VecRefU16', 'VecRefU32', 'VecRefU64', 'VecRefF32', 'VecRefF64']